@@ -1,5 +1,5 @@
 #![allow(unused)]
-use std::time::Instant;
+use crate::compsci::rapidlogging::{Logger, spans::Profiler};
 use crate::compsci::compression::huffman::write_to_file;
 use crate::math::constants::SQRT5;
 use crate::math::general::NumTools;
@@ -18,82 +18,82 @@ fn benchmark() {
     let nth_root_run = false;
     let inc_dec_run = true;
     println!("Benchmarks in non-optimised mode.");
+    // Short benchmarks time their whole loop in one span, long ones time every iteration;
+    // the summary at the end reports count, mean, p50 and p95 per span.
+    let logger = Logger::new(1_000, true, false, None);
+    let mut p  = Profiler::new(&logger);
+    p.log_spans = false;
 
     if huffman_run {
         println!("\nHuffman Compression benchmark.");
-        huffman_bench(100);
+        huffman_bench(&p, 100);
     }
 
     if inc_dec_run {
         println!("\nIncrement and Decrement Testing.");
-        inc_dec(24, 100_000_000);
+        inc_dec(&p, 24, 100_000_000);
     }
 
     if nth_root_run {
         println!("\nNth root benchmark.");
-        nth_root_bench(1_000_000);
+        nth_root_bench(&p, 1_000_000);
     }
 
     if fib_run {
         println!("\nFibonacci benchmark.");
-        fibonacci(75, 1_000_000);
+        fibonacci(&p, 75, 1_000_000);
     }
 
     if intersection_run {
         println!("\nIntersection Benchmark.");
-        intersection_bench(10, 1_000_000);
+        intersection_bench(&p, 10, 1_000_000);
     }
     if primes_run {
         println!("\nPrimes Benchmark.");
-        generate_primes_bench(100);
-        big_is_prime_bench(1_000);
-        sieve_is_prime_bench(25);
+        generate_primes_bench(&p, 100);
+        big_is_prime_bench(&p, 1_000);
+        sieve_is_prime_bench(&p, 25);
     }
+    p.flush().unwrap();
 }
-fn nth_root_bench(iterations: u128) {
+/// Times all iterations in a single span, which would otherwise take longer than `f` itself, and prints the mean.
+fn bench_loop<F: FnMut()>(p: &Profiler<Logger>, name: &str, iterations: u128, mut f: F) {
+    let span = p.span(name);
+    for _ in 0..iterations
+    { f(); }
+    println!("{} ns / iteration ({}).", span.elapsed().as_nanos() / iterations, name);
+}
+
+fn nth_root_bench(p: &Profiler<Logger>, iterations: u128) {
     use crate::math::general::nth_root;
 
-    let mut now = Instant::now();
-    for _ in 0..iterations {
+    bench_loop(p, "nth_root/current", iterations, || {
         nth_root(3.0, 3.0);
         nth_root(2.0, 2.0);
-    }
-    let mut el = now.elapsed().as_nanos() / iterations;
-    println!("{} ns / iteration (Current approach).", el);
-    now = Instant::now();
-    for _ in 0..iterations {
+    });
+    bench_loop(p, "nth_root/powf", iterations, || {
         3.0_f64.powf(1.0/3.0);
         2.0_f64.powf(1.0/2.0);
-    }
-    el = now.elapsed().as_nanos() / iterations;
-    println!("{} ns / iteration (New approach).", el);
-
+    });
 }
 
-fn inc_dec(n: u8, iterations: u128) {
+fn inc_dec(p: &Profiler<Logger>, n: u8, iterations: u128) {
     let mut c: u8 = 0;
-    let mut now = Instant::now();
 
-    for _ in 0..iterations {
+    bench_loop(p, "inc_dec/inc", iterations, || {
         c.inc();
         c = 0;
-    }
-
-    let mut el = now.elapsed().as_nanos() / iterations;
-    println!("{} nanoseconds / iteration. (c.inc())", el);
-    for _ in 0..iterations {
+    });
+    bench_loop(p, "inc_dec/inc_by", iterations, || {
         c.inc_by(n);
         c = 0;
-    }
-    el = now.elapsed().as_nanos() / iterations;
-    println!("{} nanoseconds / iteration. (c.inc_by(24))", el);
+    });
 }
 
-fn fibonacci(n: u128, iterations: u128) {
-    let mut now = Instant::now();
+fn fibonacci(p: &Profiler<Logger>, n: u128, iterations: u128) {
     let mut current_number: u128 = 1;
 
-    for _ in 0..iterations {
+    bench_loop(p, "fibonacci/iterative", iterations, || {
         let mut first_number: u128 = 0;
         let mut second_number: u128 = 0;
         current_number = 1;
@@ -106,21 +106,14 @@ fn fibonacci(n: u128, iterations: u128) {
             current_number = first_number + second_number;
             i.inc();
         }
-    }
-    let mut el = now.elapsed().as_nanos() / iterations;
-    println!("{} ns / iteration (iterative approach).", el);
+    });
     println!("{}", &current_number);
     let mut f = 0;
-    now = Instant::now();
-    for _ in 0..iterations {
-        f = nth_fibonacci(n);
-    }
-    el = now.elapsed().as_nanos() / iterations;
-    println!("{} ns / iteration (formula approach).", el);
+    bench_loop(p, "fibonacci/formula", iterations, || f = nth_fibonacci(n));
     println!("{}", f as u128);
 }
 
-fn intersection_bench(iterations: u128, set_size: i32) {
+fn intersection_bench(p: &Profiler<Logger>, iterations: u128, set_size: i32) {
     let _v: Vec<i32> = (0..set_size).collect();
     let _v2: Vec<i32> = (set_size / 2..=set_size * 2).collect();
     let s1 = VecSet::new(&_v);
@@ -128,71 +121,50 @@ fn intersection_bench(iterations: u128, set_size: i32) {
     let mut s3: VecSet<i32>;
 
     println!("[0; {}] ∩ [{}; {}]:", &set_size, &set_size / 2, &set_size * 2);
-    let now = Instant::now();
     for _ in 0..iterations {
-        s3 = s1.intersection(&s2);
+        s3 = p.time("intersection", || s1.intersection(&s2));
     }
-    let el = now.elapsed().as_millis() / iterations;
-    println!("{} milliseconds / iteration.", el);
 }
 
-fn generate_primes_bench(iters: u128) {
+fn generate_primes_bench(p: &Profiler<Logger>, iters: u128) {
     let mut _p: Vec<usize>;
-    println!("generate_primes(1_000_000):");
-    let now = Instant::now();
-    
+
     for _ in 0..iters {
-        _p = generate_primes(1_000_000);
+        _p = p.time("primes/generate_primes(1_000_000)", || generate_primes(1_000_000));
     }
-    let el = now.elapsed().as_millis() / iters;
-    println!("{} milliseconds / iteration.\n", el);
 }
 
-fn big_is_prime_bench(iters: u128) {
-    let mut now = Instant::now();
-
-    let mut el = now.elapsed().as_nanos() / iters;
-    println!("{} nanoseconds / iteration.\n", el);
-    println!("9223372036854775783u64.is_prime():");
-    for _ in 0..iters
-    { 9223372036854775783u64.is_prime(); }
-
-    el = now.elapsed().as_nanos() / iters;
-    println!("{} nanoseconds / iteration.\n", el);
+fn big_is_prime_bench(p: &Profiler<Logger>, iters: u128) {
+    bench_loop(p, "primes/9223372036854775783u64.is_prime()", iters, || { 9223372036854775783u64.is_prime(); });
 }
 
-fn sieve_is_prime_bench(iters: u128) {
-    let mut p: Vec<usize> = Vec::with_capacity(1_000_000);
+fn sieve_is_prime_bench(p: &Profiler<Logger>, iters: u128) {
+    let mut primes: Vec<usize> = Vec::with_capacity(1_000_000);
 
-    println!("is_prime() up to 1_000_000:");
-    let now = Instant::now();
     for _ in 0..iters {
+        let _s = p.span("primes/is_prime() up to 1_000_000");
         for i in 0..1_000_000 {
-            if (i as u64).is_prime() {p.push(i); }
+            if (i as u64).is_prime() {primes.push(i); }
         }
     }
-    let el = now.elapsed().as_millis() as u128 / iters;
-    println!("{} milliseconds / iteration.\n", el);
 }
 
-fn huffman_bench(iters: u128) {
+fn huffman_bench(p: &Profiler<Logger>, iters: u128) {
     use crate::compsci::compression::huffman::{Encode, Decode, get_root};
     use std::fs;
     use std::collections::HashMap;
 
     let mut lorem = String::with_capacity(13_000_000);
     lorem.push_str(&fs::read_to_string("./src/10millionchars-lorem-ipsum.txt").unwrap());
-    let now = Instant::now();
     let mut e = lorem.full_encode();
     let mut d = String::new();
     
     for i in 0..iters as u8 {
+        let _s = p.span("huffman");
         lorem.push(i as char);
-        e = lorem.full_encode();
-        d = e.full_decode();
+        e = p.time("encode", || lorem.full_encode());
+        d = p.time("decode", || e.full_decode());
     }
-    let el = now.elapsed().as_millis() / iters;
     println!("{}", d);
-    println!("{} milliseconds / iteration.\n", el);
     write_to_file("./src/10millionchars-lorem-ipsum".to_owned(), &e.0, &e.1);
 }
\ No newline at end of file
@@ -0,0 +1,103 @@
+//! `rapidlog` - reads, filters and follows log files written by `lib_rapid::compsci::rapidlogging::Logger`.
+//! \
+//! Usage: `rapidlog [OPTIONS] FILE`
+//! * `--format auto|text|json|logfmt` - The format of the file. Default: `auto`.
+//! * `--output text|json|logfmt` - The format of the output. Default: `text`.
+//! * `--precision s|ms|us|ns` - The precision of the written timestamps. Default: `s`.
+//! * `--from TIME`, `--to TIME` - Only records in `[from; to)`, given in RFC3339.
+//! * `--level LEVEL` - Only records of this level or above.
+//! * `--prefix PREFIX` - Only records with this prefix.
+//! * `--follow` - Keep waiting for new lines, like `tail -f`.
+use chrono::{DateTime, Utc};
+use lib_rapid::compsci::rapidlogging::{formatters::{LogFormatter, TextFormatter, JsonFormatter, LogfmtFormatter,
+                                                    TimestampFormat, Clock, Precision},
+                                       reader::{LogReader, Tail, InputFormat, Query, ParseError},
+                                       record::{Level, Record}};
+use std::process::exit;
+
+const USAGE: &str = "Usage: rapidlog [--format auto|text|json|logfmt] [--output text|json|logfmt] \
+                     [--precision s|ms|us|ns] [--from TIME] [--to TIME] [--level LEVEL] [--prefix PREFIX] [--follow] FILE";
+
+fn main() {
+    let mut args   = std::env::args().skip(1);
+    let mut format = InputFormat::Auto;
+    let mut query  = Query::new();
+    let mut follow = false;
+    let mut path: Option<String> = None;
+    let mut output    = String::from("text");
+    let mut precision = Precision::Seconds;
+
+    while let Some(a) = args.next() {
+        match a.as_str() {
+            "--format"    => {
+                format = match value(&mut args, &a).as_str() {
+                    "auto"   => InputFormat::Auto,
+                    "text"   => InputFormat::Text,
+                    "json"   => InputFormat::Json,
+                    "logfmt" => InputFormat::Logfmt,
+                    f        => fail(&format!("Unknown format: {}", f)),
+                };
+            }
+            "--output"    => { output = value(&mut args, &a); }
+            "--precision" => {
+                precision = match value(&mut args, &a).as_str() {
+                    "s"  => Precision::Seconds,
+                    "ms" => Precision::Millis,
+                    "us" => Precision::Micros,
+                    "ns" => Precision::Nanos,
+                    p    => fail(&format!("Unknown precision: {}", p)),
+                };
+            }
+            "--from"      => { query = query.with_from(time(&value(&mut args, &a))); }
+            "--to"        => { query = query.with_to(time(&value(&mut args, &a))); }
+            "--level"     => {
+                let l = value(&mut args, &a);
+                query = query.with_min_level(Level::from_prefix(&l)
+                                                   .unwrap_or_else(|| fail(&format!("Unknown level: {}", l))));
+            }
+            "--prefix"    => { query = query.with_prefix(&value(&mut args, &a)); }
+            "--follow"    => { follow = true; }
+            "--help"      => { println!("{}", USAGE); return; }
+            _ if path.is_none() && !a.starts_with("--") => { path = Some(a); }
+            _             => fail(&format!("Unexpected argument: {}", a)),
+        }
+    }
+    let path      = path.unwrap_or_else(|| fail("Missing FILE."));
+    let timestamp = TimestampFormat::new(Clock::Utc, precision);
+    // Thread names and locations are only written if the parsed record has them.
+    let output: Box<dyn LogFormatter> = match output.as_str() {
+        "text"   => Box::new(TextFormatter::new().with_timestamp(timestamp).with_thread(true).with_location(true)),
+        "json"   => Box::new(JsonFormatter::new().with_timestamp(timestamp).with_thread(true).with_location(true)),
+        "logfmt" => Box::new(LogfmtFormatter::new().with_timestamp(timestamp).with_thread(true).with_location(true)),
+        f        => fail(&format!("Unknown output format: {}", f)),
+    };
+
+    let records: Box<dyn Iterator<Item = Result<Record, ParseError>>> = match follow {
+        true  => Box::new(Tail::open(&path, format, true).unwrap_or_else(|e| fail(&e))
+                                                         .with_query(query)),
+        false => Box::new(LogReader::open(&path, format).unwrap_or_else(|e| fail(&e))
+                                                        .with_query(query)),
+    };
+    for r in records {
+        match r {
+            Ok(r)  => println!("{}", output.format(&r)),
+            Err(e) => eprintln!("{}", e),
+        }
+    }
+}
+
+fn value(args: &mut impl Iterator<Item = String>, name: &str) -> String {
+    args.next().unwrap_or_else(|| fail(&format!("Missing value for {}.", name)))
+}
+
+fn time(s: &str) -> DateTime<Utc> {
+    match DateTime::parse_from_rfc3339(s) {
+        Ok(t)  => t.with_timezone(&Utc),
+        Err(_) => fail(&format!("Invalid time: {}", s)),
+    }
+}
+
+fn fail(msg: &str) -> ! {
+    eprintln!("{}\n{}", msg, USAGE);
+    exit(1)
+}
@@ -1,3 +1,93 @@
 //! Modules for handling colours.
 pub mod cmyk;
-pub mod rgba;
\ No newline at end of file
+pub mod rgba;
+pub mod hsl;
+pub mod hsv;
+pub mod hwb;
+pub mod xyz;
+pub mod lab;
+pub mod oklab;
+pub mod blend;
+pub mod css;
+pub mod gradient;
+pub mod accessibility;
+pub mod profile;
+pub mod palette;
+pub mod image;
+
+/// Decodes a gamma-encoded sRGB channel into linear light.
+/// # Arguments
+/// * `c: f64` - The channel value in `[0; 1]`.
+/// # Returns
+/// A `f64` in `[0; 1]`.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::colours::{srgb_to_linear, linear_to_srgb};
+///
+/// assert!((srgb_to_linear(0.5) - 0.214041).abs() < 1e-6);
+/// assert!((linear_to_srgb(srgb_to_linear(0.5)) - 0.5).abs() < 1e-12);
+/// ```
+#[must_use]
+pub fn srgb_to_linear(c: f64) -> f64 {
+    match c <= 0.04045 {
+        true  => c / 12.92,
+        false => ((c + 0.055) / 1.055).powf(2.4),
+    }
+}
+
+/// Encodes a linear-light channel with the sRGB gamma curve.
+/// # Arguments
+/// * `c: f64` - The channel value in `[0; 1]`.
+/// # Returns
+/// A `f64` in `[0; 1]`.
+#[must_use]
+pub fn linear_to_srgb(c: f64) -> f64 {
+    match c <= 0.0031308 {
+        true  => c * 12.92,
+        false => 1.055 * c.powf(1.0 / 2.4) - 0.055,
+    }
+}
+
+/// Wraps a hue in degrees into `[0; 360)`.
+/// `rem_euclid` alone rounds tiny negative hues up to `360`, which is mapped to `0`.
+pub(crate) fn wrap_hue<T>(hue: T) -> T
+where T: Copy + PartialOrd + From<u16> + std::ops::Rem<Output = T> + std::ops::Add<Output = T> {
+    let (zero, full) = (T::from(0), T::from(360));
+    let h = match hue % full {
+        h if h < zero => h + full,
+        h             => h,
+    };
+    match h >= full {
+        true  => zero,
+        false => h,
+    }
+}
+
+/// Gets the hue in degrees, the maximum and the minimum of normalised RGB values.
+pub(crate) fn hue_max_min(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let d   = max - min;
+    if d == 0.0
+    { return (0.0, max, min); }
+
+    let h = if max == r      { ((g - b) / d).rem_euclid(6.0) }
+            else if max == g { (b - r) / d + 2.0 }
+            else             { (r - g) / d + 4.0 };
+    (wrap_hue(h * 60.0), max, min)
+}
+
+/// Builds normalised RGB values from a hue in degrees, a chroma and the amount added to every channel.
+pub(crate) fn rgb_from_hue(hue: f32, chroma: f32, m: f32) -> (f32, f32, f32) {
+    let h = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h.rem_euclid(2.0) - 1.0).abs());
+    let (r, g, b) = match h as u8 {
+        0 => { (chroma, x, 0.0) },
+        1 => { (x, chroma, 0.0) },
+        2 => { (0.0, chroma, x) },
+        3 => { (0.0, x, chroma) },
+        4 => { (x, 0.0, chroma) },
+        _ => { (chroma, 0.0, x) },
+    };
+    (r + m, g + m, b + m)
+}
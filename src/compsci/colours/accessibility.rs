@@ -0,0 +1,183 @@
+//! In here you'll find the WCAG 2.x contrast checks and the simulation of colour vision deficiencies.
+use crate::compsci::colours::{oklab::OKLab, rgba::RGBa};
+
+/// The WCAG 2.x conformance levels for contrast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WcagLevel {
+    /// The minimum contrast (success criterion 1.4.3).
+    AA,
+    /// The enhanced contrast (success criterion 1.4.6).
+    AAA,
+}
+
+/// The text sizes WCAG distinguishes. Large text is at least 18pt, or 14pt in bold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextSize {
+    /// Text below the large-scale threshold.
+    Normal,
+    /// Large-scale text, which needs less contrast.
+    Large,
+}
+
+/// The forms of dichromacy which can be simulated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColourBlindness {
+    /// Missing L-cones, i.e. red-blindness.
+    Protanopia,
+    /// Missing M-cones, i.e. green-blindness.
+    Deuteranopia,
+    /// Missing S-cones, i.e. blue-blindness.
+    Tritanopia,
+}
+
+impl WcagLevel {
+    /// Gets the minimum contrast ratio `self` requires.
+    /// # Arguments
+    /// * `size: TextSize` - The size of the text.
+    /// # Returns
+    /// A `f64`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::accessibility::{WcagLevel, TextSize};
+    ///
+    /// assert_eq!(4.5, WcagLevel::AA.min_ratio(TextSize::Normal));
+    /// assert_eq!(4.5, WcagLevel::AAA.min_ratio(TextSize::Large));
+    /// ```
+    #[must_use]
+    pub const fn min_ratio(&self, size: TextSize) -> f64 {
+        match (self, size) {
+            (WcagLevel::AA,  TextSize::Normal) => { 4.5 },
+            (WcagLevel::AA,  TextSize::Large)  => { 3.0 },
+            (WcagLevel::AAA, TextSize::Normal) => { 7.0 },
+            (WcagLevel::AAA, TextSize::Large)  => { 4.5 },
+        }
+    }
+}
+
+impl ColourBlindness {
+    /// Gets the simulation matrix of `self` by Machado, Oliveira and Fernandes (2009) for full severity, applied to linear RGB.
+    /// # Returns
+    /// A `[[f64; 3]; 3]`.
+    #[must_use]
+    pub const fn matrix(&self) -> [[f64; 3]; 3] {
+        match self {
+            ColourBlindness::Protanopia   => { [[ 0.152286,  1.052583, -0.204868],
+                                                [ 0.114503,  0.786281,  0.099216],
+                                                [-0.003882, -0.048116,  1.051998]] },
+            ColourBlindness::Deuteranopia => { [[ 0.367322,  0.860646, -0.227968],
+                                                [ 0.280085,  0.672501,  0.047413],
+                                                [-0.011820,  0.042940,  0.968881]] },
+            ColourBlindness::Tritanopia   => { [[ 1.255528, -0.076749, -0.178779],
+                                                [-0.078411,  0.930809,  0.147602],
+                                                [ 0.004733,  0.691367,  0.303900]] },
+        }
+    }
+    /// Simulates how a colour is seen with `self`. The alpha value is kept.
+    /// # Arguments
+    /// * `colour: &RGBa` - The colour.
+    /// # Returns
+    /// A new `RGBa` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{accessibility::ColourBlindness, rgba::RGBa};
+    ///
+    /// // Greys are seen unchanged.
+    /// assert_eq!(RGBa::SOLID_WHITE, ColourBlindness::Deuteranopia.simulate(&RGBa::SOLID_WHITE));
+    /// // Red loses most of its brightness without L-cones.
+    /// assert_eq!(RGBa::new(109, 95, 0, 255), ColourBlindness::Protanopia.simulate(&RGBa::SOLID_RED));
+    /// // A brick red and an olive become hard to tell apart.
+    /// let red   = ColourBlindness::Deuteranopia.simulate(&RGBa::new(200, 50, 50, 255));
+    /// let olive = ColourBlindness::Deuteranopia.simulate(&RGBa::new(110, 110, 50, 255));
+    /// assert!(red.red.abs_diff(olive.red) < 20 && red.green.abs_diff(olive.green) < 20);
+    /// ```
+    #[must_use]
+    pub fn simulate(&self, colour: &RGBa) -> RGBa {
+        let m = self.matrix();
+        let (r, g, b) = colour.to_linear_rgb();
+        RGBa::from_linear_rgb(m[0][0] * r + m[0][1] * g + m[0][2] * b,
+                              m[1][0] * r + m[1][1] * g + m[1][2] * b,
+                              m[2][0] * r + m[2][1] * g + m[2][2] * b,
+                              colour.alpha)
+    }
+}
+
+/// Determines whether text in one colour on a background of another is readable enough. Both colours are treated as opaque.
+/// # Arguments
+/// * `foreground: &RGBa` - The text colour.
+/// * `background: &RGBa` - The background colour.
+/// * `level: WcagLevel` - The level to meet.
+/// * `size: TextSize` - The size of the text.
+/// # Returns
+/// A `bool`.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::colours::{accessibility::{meets_wcag, WcagLevel, TextSize}, rgba::RGBa};
+///
+/// let grey = RGBa::new(118, 118, 118, 255);
+///
+/// assert!(meets_wcag(&grey, &RGBa::SOLID_WHITE, WcagLevel::AA, TextSize::Normal));
+/// assert!(!meets_wcag(&grey, &RGBa::SOLID_WHITE, WcagLevel::AAA, TextSize::Normal));
+/// ```
+#[must_use]
+pub fn meets_wcag(foreground: &RGBa, background: &RGBa, level: WcagLevel, size: TextSize) -> bool {
+    foreground.contrast_ratio(background) >= level.min_ratio(size)
+}
+
+/// Finds the colour closest to `foreground` which meets a WCAG level on `background`.
+/// \
+/// Only the OKLab lightness of `foreground` is changed, so its hue is kept where possible.
+/// Black and white are tried last.
+/// # Arguments
+/// * `foreground: &RGBa` - The text colour.
+/// * `background: &RGBa` - The background colour.
+/// * `level: WcagLevel` - The level to meet.
+/// * `size: TextSize` - The size of the text.
+/// # Returns
+/// An `Option<RGBa>`, `None` if not even black or white meet the level.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::colours::{accessibility::{nearest_passing, meets_wcag, WcagLevel, TextSize}, rgba::RGBa};
+///
+/// let orange = RGBa::new(255, 165, 0, 255);
+/// let fixed  = nearest_passing(&orange, &RGBa::SOLID_WHITE, WcagLevel::AA, TextSize::Normal).unwrap();
+///
+/// assert!(meets_wcag(&fixed, &RGBa::SOLID_WHITE, WcagLevel::AA, TextSize::Normal));
+/// assert!(fixed.red > fixed.green && fixed.green > fixed.blue);
+/// // Mid grey has less than 7:1 contrast to both black and white.
+/// assert_eq!(None, nearest_passing(&orange, &RGBa::new(119, 119, 119, 255), WcagLevel::AAA, TextSize::Normal));
+/// ```
+#[must_use]
+pub fn nearest_passing(foreground: &RGBa, background: &RGBa, level: WcagLevel, size: TextSize) -> Option<RGBa> {
+    if meets_wcag(foreground, background, level, size)
+    { return Some(*foreground); }
+
+    const STEPS: usize = 1000;
+    let lab      = OKLab::from_rgba_struct(foreground);
+    let original = lab.l;
+    let at       = |l: f64| {
+        let mut c = RGBa::from_oklab_struct(&OKLab::new(l, lab.a, lab.b));
+        c.alpha   = foreground.alpha;
+        c
+    };
+    let darker  = (0..=STEPS).map(|i| at(original * (1.0 - i as f64 / STEPS as f64)))
+                             .find(|c| meets_wcag(c, background, level, size));
+    let lighter = (0..=STEPS).map(|i| at(original + (1.0 - original) * i as f64 / STEPS as f64))
+                             .find(|c| meets_wcag(c, background, level, size));
+
+    let dist = |c: &RGBa| OKLab::from_rgba_struct(c).distance(&lab);
+    match (darker, lighter) {
+        (Some(d), Some(l)) => {
+            match dist(&d) <= dist(&l) {
+                true  => Some(d),
+                false => Some(l),
+            }
+        },
+        (Some(c), None) |
+        (None, Some(c))    => { Some(c) },
+        (None, None)       => {
+            [RGBa::SOLID_BLACK, RGBa::SOLID_WHITE].iter()
+                                                  .map(|c| RGBa { alpha: foreground.alpha, ..*c })
+                                                  .find(|c| meets_wcag(c, background, level, size))
+        },
+    }
+}
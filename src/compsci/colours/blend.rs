@@ -0,0 +1,95 @@
+//! In here you'll find the Porter-Duff compositing operators and the blend modes used by `RGBa::composite` and `RGBa::blend`.
+
+/// The Porter-Duff compositing operators, placing a source colour onto a backdrop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompositeOp {
+    /// The source is placed over the backdrop.
+    Over,
+    /// Only the part of the source inside the backdrop is kept.
+    In,
+    /// Only the part of the source outside the backdrop is kept.
+    Out,
+    /// The source is placed over the backdrop, but only where the backdrop is.
+    Atop,
+    /// Only the non-overlapping parts of source and backdrop are kept.
+    Xor,
+}
+
+/// The separable blend modes, mixing the colour of a source with its backdrop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlendMode {
+    /// Uses the source colour only.
+    Normal,
+    /// Multiplies the channels, which always darkens.
+    Multiply,
+    /// Multiplies the inverted channels, which always lightens.
+    Screen,
+    /// Multiplies or screens, depending on the backdrop.
+    Overlay,
+    /// Keeps the darker of both channels.
+    Darken,
+    /// Keeps the lighter of both channels.
+    Lighten,
+    /// Subtracts the darker from the lighter channel.
+    Difference,
+}
+
+impl CompositeOp {
+    /// Gets the Porter-Duff fractions of source and backdrop which contribute to the result.
+    /// # Arguments
+    /// * `alpha_s: f64` - The alpha of the source in `[0; 1]`.
+    /// * `alpha_b: f64` - The alpha of the backdrop in `[0; 1]`.
+    /// # Returns
+    /// A `(f64, f64)` in the order `Fa, Fb`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::blend::CompositeOp;
+    ///
+    /// assert_eq!((1.0, 0.25), CompositeOp::Over.factors(0.75, 1.0));
+    /// assert_eq!((0.0, 0.0), CompositeOp::Xor.factors(1.0, 1.0));
+    /// ```
+    #[must_use]
+    pub fn factors(&self, alpha_s: f64, alpha_b: f64) -> (f64, f64) {
+        match self {
+            CompositeOp::Over => { (1.0, 1.0 - alpha_s) },
+            CompositeOp::In   => { (alpha_b, 0.0) },
+            CompositeOp::Out  => { (1.0 - alpha_b, 0.0) },
+            CompositeOp::Atop => { (alpha_b, 1.0 - alpha_s) },
+            CompositeOp::Xor  => { (1.0 - alpha_b, 1.0 - alpha_s) },
+        }
+    }
+}
+
+impl BlendMode {
+    /// Applies `self` to a single channel.
+    /// # Arguments
+    /// * `backdrop: f64` - The channel of the backdrop in `[0; 1]`.
+    /// * `source: f64` - The channel of the source in `[0; 1]`.
+    /// # Returns
+    /// A `f64` in `[0; 1]`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::blend::BlendMode;
+    ///
+    /// assert_eq!(0.25, BlendMode::Multiply.apply(0.5, 0.5));
+    /// assert_eq!(0.75, BlendMode::Screen.apply(0.5, 0.5));
+    /// assert_eq!(0.5, BlendMode::Difference.apply(0.25, 0.75));
+    /// ```
+    #[must_use]
+    pub fn apply(&self, backdrop: f64, source: f64) -> f64 {
+        match self {
+            BlendMode::Normal     => { source },
+            BlendMode::Multiply   => { backdrop * source },
+            BlendMode::Screen     => { backdrop + source - backdrop * source },
+            BlendMode::Overlay    => {
+                match backdrop <= 0.5 {
+                    true  => BlendMode::Multiply.apply(2.0 * backdrop, source),
+                    false => BlendMode::Screen.apply(2.0 * backdrop - 1.0, source),
+                }
+            },
+            BlendMode::Darken     => { backdrop.min(source) },
+            BlendMode::Lighten    => { backdrop.max(source) },
+            BlendMode::Difference => { (backdrop - source).abs() },
+        }
+    }
+}
@@ -42,7 +42,7 @@ impl CMYK {
 
         CMYK { cyan, magenta, yellow, black }
     }
-    /// Create a new `CMYK` struct from an existing `RGBa` struct.
+    /// Create a new `CMYK` struct from an existing `RGBa` struct with the naive formula. For print, use `profile::CmykProfile::to_cmyk`.
     /// # Arguments
     /// * `rgb: &RGBa` - The `RGBa` struct.
     /// # Returns
@@ -0,0 +1,326 @@
+//! In here you'll find the parser for CSS colour strings and the table of CSS named colours.
+use std::fmt::Display;
+use crate::compsci::colours::{hsl::HSL, rgba::RGBa};
+
+/// The error returned when a CSS colour string could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColourParseError {
+    /// The string was empty.
+    Empty,
+    /// A hex colour had a wrong length or contained a non-hex digit.
+    InvalidHex(String),
+    /// The string was neither a hex colour, a function nor a known colour name.
+    UnknownName(String),
+    /// The function is not one of `rgb`, `rgba`, `hsl` or `hsla`.
+    UnknownFunction(String),
+    /// The function call was not closed with `)`.
+    MissingParenthesis,
+    /// The function did not get 3 or 4 arguments.
+    ArgumentCount(usize),
+    /// An argument was not a number or percentage.
+    InvalidArgument(String),
+}
+
+impl Display for ColourParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColourParseError::Empty              => write!(f, "Empty string."),
+            ColourParseError::InvalidHex(s)      => write!(f, "Invalid hex colour: {}", s),
+            ColourParseError::UnknownName(s)     => write!(f, "Unknown colour name: {}", s),
+            ColourParseError::UnknownFunction(s) => write!(f, "Unknown colour function: {}", s),
+            ColourParseError::MissingParenthesis => write!(f, "Missing closing parenthesis."),
+            ColourParseError::ArgumentCount(n)   => write!(f, "Expected 3 or 4 arguments, found {}.", n),
+            ColourParseError::InvalidArgument(s) => write!(f, "Invalid argument: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for ColourParseError { }
+
+/// The CSS named colours, sorted by name. `transparent` is not included, as it is no opaque colour.
+pub const NAMED_COLOURS: [(&str, RGBa); 148] = [
+    ("aliceblue",            RGBa::new(240, 248, 255, 255)),
+    ("antiquewhite",         RGBa::new(250, 235, 215, 255)),
+    ("aqua",                 RGBa::new(  0, 255, 255, 255)),
+    ("aquamarine",           RGBa::new(127, 255, 212, 255)),
+    ("azure",                RGBa::new(240, 255, 255, 255)),
+    ("beige",                RGBa::new(245, 245, 220, 255)),
+    ("bisque",               RGBa::new(255, 228, 196, 255)),
+    ("black",                RGBa::new(  0,   0,   0, 255)),
+    ("blanchedalmond",       RGBa::new(255, 235, 205, 255)),
+    ("blue",                 RGBa::new(  0,   0, 255, 255)),
+    ("blueviolet",           RGBa::new(138,  43, 226, 255)),
+    ("brown",                RGBa::new(165,  42,  42, 255)),
+    ("burlywood",            RGBa::new(222, 184, 135, 255)),
+    ("cadetblue",            RGBa::new( 95, 158, 160, 255)),
+    ("chartreuse",           RGBa::new(127, 255,   0, 255)),
+    ("chocolate",            RGBa::new(210, 105,  30, 255)),
+    ("coral",                RGBa::new(255, 127,  80, 255)),
+    ("cornflowerblue",       RGBa::new(100, 149, 237, 255)),
+    ("cornsilk",             RGBa::new(255, 248, 220, 255)),
+    ("crimson",              RGBa::new(220,  20,  60, 255)),
+    ("cyan",                 RGBa::new(  0, 255, 255, 255)),
+    ("darkblue",             RGBa::new(  0,   0, 139, 255)),
+    ("darkcyan",             RGBa::new(  0, 139, 139, 255)),
+    ("darkgoldenrod",        RGBa::new(184, 134,  11, 255)),
+    ("darkgray",             RGBa::new(169, 169, 169, 255)),
+    ("darkgreen",            RGBa::new(  0, 100,   0, 255)),
+    ("darkgrey",             RGBa::new(169, 169, 169, 255)),
+    ("darkkhaki",            RGBa::new(189, 183, 107, 255)),
+    ("darkmagenta",          RGBa::new(139,   0, 139, 255)),
+    ("darkolivegreen",       RGBa::new( 85, 107,  47, 255)),
+    ("darkorange",           RGBa::new(255, 140,   0, 255)),
+    ("darkorchid",           RGBa::new(153,  50, 204, 255)),
+    ("darkred",              RGBa::new(139,   0,   0, 255)),
+    ("darksalmon",           RGBa::new(233, 150, 122, 255)),
+    ("darkseagreen",         RGBa::new(143, 188, 143, 255)),
+    ("darkslateblue",        RGBa::new( 72,  61, 139, 255)),
+    ("darkslategray",        RGBa::new( 47,  79,  79, 255)),
+    ("darkslategrey",        RGBa::new( 47,  79,  79, 255)),
+    ("darkturquoise",        RGBa::new(  0, 206, 209, 255)),
+    ("darkviolet",           RGBa::new(148,   0, 211, 255)),
+    ("deeppink",             RGBa::new(255,  20, 147, 255)),
+    ("deepskyblue",          RGBa::new(  0, 191, 255, 255)),
+    ("dimgray",              RGBa::new(105, 105, 105, 255)),
+    ("dimgrey",              RGBa::new(105, 105, 105, 255)),
+    ("dodgerblue",           RGBa::new( 30, 144, 255, 255)),
+    ("firebrick",            RGBa::new(178,  34,  34, 255)),
+    ("floralwhite",          RGBa::new(255, 250, 240, 255)),
+    ("forestgreen",          RGBa::new( 34, 139,  34, 255)),
+    ("fuchsia",              RGBa::new(255,   0, 255, 255)),
+    ("gainsboro",            RGBa::new(220, 220, 220, 255)),
+    ("ghostwhite",           RGBa::new(248, 248, 255, 255)),
+    ("gold",                 RGBa::new(255, 215,   0, 255)),
+    ("goldenrod",            RGBa::new(218, 165,  32, 255)),
+    ("gray",                 RGBa::new(128, 128, 128, 255)),
+    ("green",                RGBa::new(  0, 128,   0, 255)),
+    ("greenyellow",          RGBa::new(173, 255,  47, 255)),
+    ("grey",                 RGBa::new(128, 128, 128, 255)),
+    ("honeydew",             RGBa::new(240, 255, 240, 255)),
+    ("hotpink",              RGBa::new(255, 105, 180, 255)),
+    ("indianred",            RGBa::new(205,  92,  92, 255)),
+    ("indigo",               RGBa::new( 75,   0, 130, 255)),
+    ("ivory",                RGBa::new(255, 255, 240, 255)),
+    ("khaki",                RGBa::new(240, 230, 140, 255)),
+    ("lavender",             RGBa::new(230, 230, 250, 255)),
+    ("lavenderblush",        RGBa::new(255, 240, 245, 255)),
+    ("lawngreen",            RGBa::new(124, 252,   0, 255)),
+    ("lemonchiffon",         RGBa::new(255, 250, 205, 255)),
+    ("lightblue",            RGBa::new(173, 216, 230, 255)),
+    ("lightcoral",           RGBa::new(240, 128, 128, 255)),
+    ("lightcyan",            RGBa::new(224, 255, 255, 255)),
+    ("lightgoldenrodyellow", RGBa::new(250, 250, 210, 255)),
+    ("lightgray",            RGBa::new(211, 211, 211, 255)),
+    ("lightgreen",           RGBa::new(144, 238, 144, 255)),
+    ("lightgrey",            RGBa::new(211, 211, 211, 255)),
+    ("lightpink",            RGBa::new(255, 182, 193, 255)),
+    ("lightsalmon",          RGBa::new(255, 160, 122, 255)),
+    ("lightseagreen",        RGBa::new( 32, 178, 170, 255)),
+    ("lightskyblue",         RGBa::new(135, 206, 250, 255)),
+    ("lightslategray",       RGBa::new(119, 136, 153, 255)),
+    ("lightslategrey",       RGBa::new(119, 136, 153, 255)),
+    ("lightsteelblue",       RGBa::new(176, 196, 222, 255)),
+    ("lightyellow",          RGBa::new(255, 255, 224, 255)),
+    ("lime",                 RGBa::new(  0, 255,   0, 255)),
+    ("limegreen",            RGBa::new( 50, 205,  50, 255)),
+    ("linen",                RGBa::new(250, 240, 230, 255)),
+    ("magenta",              RGBa::new(255,   0, 255, 255)),
+    ("maroon",               RGBa::new(128,   0,   0, 255)),
+    ("mediumaquamarine",     RGBa::new(102, 205, 170, 255)),
+    ("mediumblue",           RGBa::new(  0,   0, 205, 255)),
+    ("mediumorchid",         RGBa::new(186,  85, 211, 255)),
+    ("mediumpurple",         RGBa::new(147, 112, 219, 255)),
+    ("mediumseagreen",       RGBa::new( 60, 179, 113, 255)),
+    ("mediumslateblue",      RGBa::new(123, 104, 238, 255)),
+    ("mediumspringgreen",    RGBa::new(  0, 250, 154, 255)),
+    ("mediumturquoise",      RGBa::new( 72, 209, 204, 255)),
+    ("mediumvioletred",      RGBa::new(199,  21, 133, 255)),
+    ("midnightblue",         RGBa::new( 25,  25, 112, 255)),
+    ("mintcream",            RGBa::new(245, 255, 250, 255)),
+    ("mistyrose",            RGBa::new(255, 228, 225, 255)),
+    ("moccasin",             RGBa::new(255, 228, 181, 255)),
+    ("navajowhite",          RGBa::new(255, 222, 173, 255)),
+    ("navy",                 RGBa::new(  0,   0, 128, 255)),
+    ("oldlace",              RGBa::new(253, 245, 230, 255)),
+    ("olive",                RGBa::new(128, 128,   0, 255)),
+    ("olivedrab",            RGBa::new(107, 142,  35, 255)),
+    ("orange",               RGBa::new(255, 165,   0, 255)),
+    ("orangered",            RGBa::new(255,  69,   0, 255)),
+    ("orchid",               RGBa::new(218, 112, 214, 255)),
+    ("palegoldenrod",        RGBa::new(238, 232, 170, 255)),
+    ("palegreen",            RGBa::new(152, 251, 152, 255)),
+    ("paleturquoise",        RGBa::new(175, 238, 238, 255)),
+    ("palevioletred",        RGBa::new(219, 112, 147, 255)),
+    ("papayawhip",           RGBa::new(255, 239, 213, 255)),
+    ("peachpuff",            RGBa::new(255, 218, 185, 255)),
+    ("peru",                 RGBa::new(205, 133,  63, 255)),
+    ("pink",                 RGBa::new(255, 192, 203, 255)),
+    ("plum",                 RGBa::new(221, 160, 221, 255)),
+    ("powderblue",           RGBa::new(176, 224, 230, 255)),
+    ("purple",               RGBa::new(128,   0, 128, 255)),
+    ("rebeccapurple",        RGBa::new(102,  51, 153, 255)),
+    ("red",                  RGBa::new(255,   0,   0, 255)),
+    ("rosybrown",            RGBa::new(188, 143, 143, 255)),
+    ("royalblue",            RGBa::new( 65, 105, 225, 255)),
+    ("saddlebrown",          RGBa::new(139,  69,  19, 255)),
+    ("salmon",               RGBa::new(250, 128, 114, 255)),
+    ("sandybrown",           RGBa::new(244, 164,  96, 255)),
+    ("seagreen",             RGBa::new( 46, 139,  87, 255)),
+    ("seashell",             RGBa::new(255, 245, 238, 255)),
+    ("sienna",               RGBa::new(160,  82,  45, 255)),
+    ("silver",               RGBa::new(192, 192, 192, 255)),
+    ("skyblue",              RGBa::new(135, 206, 235, 255)),
+    ("slateblue",            RGBa::new(106,  90, 205, 255)),
+    ("slategray",            RGBa::new(112, 128, 144, 255)),
+    ("slategrey",            RGBa::new(112, 128, 144, 255)),
+    ("snow",                 RGBa::new(255, 250, 250, 255)),
+    ("springgreen",          RGBa::new(  0, 255, 127, 255)),
+    ("steelblue",            RGBa::new( 70, 130, 180, 255)),
+    ("tan",                  RGBa::new(210, 180, 140, 255)),
+    ("teal",                 RGBa::new(  0, 128, 128, 255)),
+    ("thistle",              RGBa::new(216, 191, 216, 255)),
+    ("tomato",               RGBa::new(255,  99,  71, 255)),
+    ("turquoise",            RGBa::new( 64, 224, 208, 255)),
+    ("violet",               RGBa::new(238, 130, 238, 255)),
+    ("wheat",                RGBa::new(245, 222, 179, 255)),
+    ("white",                RGBa::new(255, 255, 255, 255)),
+    ("whitesmoke",           RGBa::new(245, 245, 245, 255)),
+    ("yellow",               RGBa::new(255, 255,   0, 255)),
+    ("yellowgreen",          RGBa::new(154, 205,  50, 255)),
+];
+
+/// Looks up a CSS named colour. The lookup ignores ASCII case and also knows `transparent`.
+/// # Arguments
+/// * `name: &str` - The name of the colour.
+/// # Returns
+/// An `Option<RGBa>`.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::colours::{css::named_colour, rgba::RGBa};
+///
+/// assert_eq!(Some(RGBa::new(102, 51, 153, 255)), named_colour("RebeccaPurple"));
+/// assert_eq!(Some(RGBa::new(0, 0, 0, 0)), named_colour("transparent"));
+/// assert_eq!(None, named_colour("blurple"));
+/// ```
+#[must_use]
+pub fn named_colour(name: &str) -> Option<RGBa> {
+    let name = name.to_ascii_lowercase();
+    if name == "transparent"
+    { return Some(RGBa::new(0, 0, 0, 0)); }
+
+    NAMED_COLOURS.binary_search_by(|(n, _)| n.cmp(&name.as_str()))
+                 .ok()
+                 .map(|i| NAMED_COLOURS[i].1)
+}
+
+/// Parses a CSS colour string.
+/// \
+/// Accepted are `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`, the bare `rrggbb` and `rrggbbaa`,
+/// the functions `rgb()`, `rgba()`, `hsl()` and `hsla()` with comma or space separated arguments and the named colours.
+/// Out-of-range arguments are clamped, as in CSS.
+/// # Arguments
+/// * `s: &str` - The string to parse.
+/// # Returns
+/// A `Result<RGBa, ColourParseError>`.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::colours::{css::{parse_css, ColourParseError}, rgba::RGBa};
+///
+/// assert_eq!(Ok(RGBa::new(255, 136, 0, 255)), parse_css("#f80"));
+/// assert_eq!(Ok(RGBa::new(255, 136, 0, 153)), parse_css("#FF880099"));
+/// assert_eq!(Ok(RGBa::new(255, 0, 0, 128)), parse_css("rgba(255, 0, 0, 0.5)"));
+/// assert_eq!(Ok(RGBa::new(255, 0, 0, 128)), parse_css("rgb(100% 0% 0% / 50%)"));
+/// assert_eq!(Ok(RGBa::new(128, 191, 255, 255)), parse_css("hsl(210deg, 100%, 75%)"));
+/// assert_eq!(Ok(RGBa::new(0, 0, 139, 255)), parse_css("DarkBlue"));
+///
+/// assert_eq!(Err(ColourParseError::InvalidHex("#ff00g0".to_owned())), parse_css("#ff00g0"));
+/// assert_eq!(Err(ColourParseError::ArgumentCount(2)), parse_css("rgb(1, 2)"));
+/// assert_eq!(Err(ColourParseError::UnknownFunction("lab".to_owned())), parse_css("lab(50 0 0)"));
+/// ```
+pub fn parse_css(s: &str) -> Result<RGBa, ColourParseError> {
+    let s = s.trim();
+    if s.is_empty()
+    { return Err(ColourParseError::Empty); }
+
+    if let Some(hex) = s.strip_prefix('#') {
+        return parse_hex(hex).ok_or_else(|| ColourParseError::InvalidHex(s.to_owned()));
+    }
+    if let Some(open) = s.find('(') {
+        let inner = s[open + 1..].strip_suffix(')')
+                                 .ok_or(ColourParseError::MissingParenthesis)?;
+        return parse_function(&s[..open].trim().to_ascii_lowercase(), inner);
+    }
+    match named_colour(s) {
+        Some(c) => { Ok(c) },
+        None    => {
+            match s.len() {
+                6 | 8 => { parse_hex(s).ok_or_else(|| ColourParseError::UnknownName(s.to_owned())) },
+                _     => { Err(ColourParseError::UnknownName(s.to_owned())) },
+            }
+        },
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<RGBa> {
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit())
+    { return None; }
+
+    let digit = |i: usize| u8::from_str_radix(&hex[i..=i], 16).unwrap();
+    let pair  = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+    match hex.len() {
+        3 => { Some(RGBa::new(digit(0) * 17, digit(1) * 17, digit(2) * 17, 255)) },
+        4 => { Some(RGBa::new(digit(0) * 17, digit(1) * 17, digit(2) * 17, digit(3) * 17)) },
+        6 => { Some(RGBa::new(pair(0), pair(2), pair(4), 255)) },
+        8 => { Some(RGBa::new(pair(0), pair(2), pair(4), pair(6))) },
+        _ => { None },
+    }
+}
+
+fn parse_function(name: &str, inner: &str) -> Result<RGBa, ColourParseError> {
+    let args: Vec<&str> = match inner.contains(',') {
+        true  => inner.split(',').map(str::trim).collect(),
+        false => {
+            let mut parts = inner.splitn(2, '/');
+            let mut args: Vec<&str> = parts.next().unwrap_or("").split_whitespace().collect();
+            if let Some(alpha) = parts.next()
+            { args.push(alpha.trim()); }
+            args
+        },
+    };
+    if args.len() != 3 && args.len() != 4
+    { return Err(ColourParseError::ArgumentCount(args.len())); }
+
+    let alpha = match args.get(3) {
+        Some(a) => { (parse_fraction(a, 1.0)? * 255.0).round() as u8 },
+        None    => { 255 },
+    };
+    match name {
+        "rgb" | "rgba" => {
+            let channel = |a: &str| parse_fraction(a, 255.0).map(|v| (v * 255.0).round() as u8);
+            Ok(RGBa::new(channel(args[0])?, channel(args[1])?, channel(args[2])?, alpha))
+        },
+        "hsl" | "hsla" => {
+            let hue = args[0].strip_suffix("deg").unwrap_or(args[0]);
+            let hue = hue.parse::<f32>()
+                         .map_err(|_| ColourParseError::InvalidArgument(args[0].to_owned()))?;
+            let s   = parse_fraction(args[1].strip_suffix('%').unwrap_or(args[1]), 100.0)?;
+            let l   = parse_fraction(args[2].strip_suffix('%').unwrap_or(args[2]), 100.0)?;
+            let mut c = RGBa::from_hsl_struct(&HSL::new(hue, s as f32, l as f32));
+            c.alpha   = alpha;
+            Ok(c)
+        },
+        _ => { Err(ColourParseError::UnknownFunction(name.to_owned())) },
+    }
+}
+
+/// Parses a number or percentage into `[0; 1]`, where the plain number `max` is 100%.
+fn parse_fraction(arg: &str, max: f64) -> Result<f64, ColourParseError> {
+    let (num, scale) = match arg.strip_suffix('%') {
+        Some(n) => { (n, 100.0) },
+        None    => { (arg, max) },
+    };
+    match num.trim().parse::<f64>() {
+        Ok(v) if v.is_finite() => { Ok((v / scale).clamp(0.0, 1.0)) },
+        _                      => { Err(ColourParseError::InvalidArgument(arg.to_owned())) },
+    }
+}
@@ -0,0 +1,300 @@
+//! In here you'll find colour gradients, which interpolate between `RGBa` stops, and ready-made scientific colour maps.
+use crate::compsci::colours::{hsl::HSL, oklab::OKLab, rgba::RGBa};
+
+const ARG_ERR: &str = "A gradient needs at least one stop and all positions must range from 0 to 1.";
+
+/// The colour space in which a `Gradient` interpolates between its stops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Interpolates the gamma-encoded sRGB channels, like most image editors and browsers do.
+    Srgb,
+    /// Interpolates in linear light, which keeps the brightness of mixtures physically correct.
+    LinearRgb,
+    /// Interpolates hue, saturation and lightness, taking the shorter way around the hue circle.
+    Hsl,
+    /// Interpolates in the perceptually uniform OKLab space.
+    OKLab,
+}
+
+/// A colour gradient, given by `RGBa` stops at positions in `[0; 1]`.
+/// \
+/// Before the first stop and after the last one, the colour of that stop is used.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    stops:         Vec<(f64, RGBa)>,
+    interpolation: Interpolation,
+}
+
+/// The viridis colour map, sampled at 11 evenly spaced positions.
+pub const VIRIDIS: [RGBa; 11] = [
+    RGBa::new(0x44, 0x01, 0x54, 255),
+    RGBa::new(0x48, 0x25, 0x76, 255),
+    RGBa::new(0x41, 0x44, 0x87, 255),
+    RGBa::new(0x35, 0x60, 0x8d, 255),
+    RGBa::new(0x2a, 0x78, 0x8e, 255),
+    RGBa::new(0x21, 0x90, 0x8c, 255),
+    RGBa::new(0x22, 0xa8, 0x84, 255),
+    RGBa::new(0x43, 0xbf, 0x71, 255),
+    RGBa::new(0x7a, 0xd1, 0x51, 255),
+    RGBa::new(0xbb, 0xdf, 0x27, 255),
+    RGBa::new(0xfd, 0xe7, 0x25, 255),
+];
+/// The magma colour map, sampled at 11 evenly spaced positions.
+pub const MAGMA: [RGBa; 11] = [
+    RGBa::new(0x00, 0x00, 0x04, 255),
+    RGBa::new(0x14, 0x0e, 0x37, 255),
+    RGBa::new(0x3b, 0x0f, 0x70, 255),
+    RGBa::new(0x64, 0x1a, 0x80, 255),
+    RGBa::new(0x8c, 0x29, 0x81, 255),
+    RGBa::new(0xb6, 0x36, 0x79, 255),
+    RGBa::new(0xde, 0x49, 0x68, 255),
+    RGBa::new(0xf6, 0x6f, 0x5c, 255),
+    RGBa::new(0xfe, 0x9f, 0x6d, 255),
+    RGBa::new(0xfe, 0xce, 0x91, 255),
+    RGBa::new(0xfc, 0xfd, 0xbf, 255),
+];
+/// The cividis colour map, optimised for colour vision deficiencies, sampled at 11 evenly spaced positions.
+pub const CIVIDIS: [RGBa; 11] = [
+    RGBa::new(0x00, 0x20, 0x51, 255),
+    RGBa::new(0x0b, 0x32, 0x6a, 255),
+    RGBa::new(0x2b, 0x44, 0x6e, 255),
+    RGBa::new(0x4c, 0x56, 0x6d, 255),
+    RGBa::new(0x69, 0x69, 0x70, 255),
+    RGBa::new(0x80, 0x7c, 0x75, 255),
+    RGBa::new(0x94, 0x8f, 0x78, 255),
+    RGBa::new(0xac, 0xa3, 0x76, 255),
+    RGBa::new(0xca, 0xba, 0x6a, 255),
+    RGBa::new(0xea, 0xd2, 0x56, 255),
+    RGBa::new(0xfd, 0xea, 0x45, 255),
+];
+
+impl Gradient {
+    /// Create a new `Gradient` from stops. The stops are sorted by their positions.
+    /// # Arguments
+    /// * `stops: &[(f64, RGBa)]` - The stops as pairs of position and colour.
+    /// * `interpolation: Interpolation` - The colour space to interpolate in.
+    /// # Returns
+    /// A new `Gradient`.
+    /// # Panics
+    /// Panics if `stops` is empty or a position is not in the interval `[0; 1]`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{gradient::{Gradient, Interpolation}, rgba::RGBa};
+    ///
+    /// let g = Gradient::new(&[(1.0, RGBa::SOLID_WHITE), (0.0, RGBa::SOLID_BLACK)], Interpolation::Srgb);
+    ///
+    /// assert_eq!(RGBa::new(128, 128, 128, 255), g.sample(0.5));
+    /// assert_eq!(RGBa::SOLID_WHITE, g.sample(2.0));
+    /// ```
+    #[must_use]
+    pub fn new(stops: &[(f64, RGBa)], interpolation: Interpolation) -> Gradient {
+        if stops.is_empty() ||
+           stops.iter().any(|(p, _)| !(0.0..=1.0).contains(p))
+        { panic!("{}", ARG_ERR); }
+
+        let mut stops = stops.to_vec();
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Gradient { stops, interpolation }
+    }
+    /// Create a new `Gradient` from colours which are spread evenly over `[0; 1]`.
+    /// # Arguments
+    /// * `colours: &[RGBa]` - The colours.
+    /// * `interpolation: Interpolation` - The colour space to interpolate in.
+    /// # Returns
+    /// A new `Gradient`.
+    /// # Panics
+    /// Panics if `colours` is empty.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{gradient::{Gradient, Interpolation}, rgba::RGBa};
+    ///
+    /// let g = Gradient::from_colours(&[RGBa::SOLID_RED, RGBa::SOLID_BLUE], Interpolation::LinearRgb);
+    ///
+    /// assert_eq!(RGBa::new(188, 0, 188, 255), g.sample(0.5));
+    /// ```
+    #[must_use]
+    pub fn from_colours(colours: &[RGBa], interpolation: Interpolation) -> Gradient {
+        let last  = colours.len().saturating_sub(1).max(1) as f64;
+        let stops: Vec<(f64, RGBa)> = colours.iter()
+                                             .enumerate()
+                                             .map(|(i, c)| (i as f64 / last, *c))
+                                             .collect();
+        Gradient::new(&stops, interpolation)
+    }
+    /// The viridis colour map, interpolated in OKLab.
+    /// # Returns
+    /// A new `Gradient`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{gradient::Gradient, rgba::RGBa};
+    ///
+    /// assert_eq!(RGBa::new(0x44, 0x01, 0x54, 255), Gradient::viridis().sample(0.0));
+    /// assert_eq!(RGBa::new(0x21, 0x90, 0x8c, 255), Gradient::viridis().sample(0.5));
+    /// ```
+    #[must_use]
+    pub fn viridis() -> Gradient {
+        Gradient::from_colours(&VIRIDIS, Interpolation::OKLab)
+    }
+    /// The magma colour map, interpolated in OKLab.
+    /// # Returns
+    /// A new `Gradient`.
+    #[must_use]
+    pub fn magma() -> Gradient {
+        Gradient::from_colours(&MAGMA, Interpolation::OKLab)
+    }
+    /// The cividis colour map, interpolated in OKLab.
+    /// # Returns
+    /// A new `Gradient`.
+    #[must_use]
+    pub fn cividis() -> Gradient {
+        Gradient::from_colours(&CIVIDIS, Interpolation::OKLab)
+    }
+    /// Get the stops of `self`, sorted by position.
+    /// # Returns
+    /// A `&[(f64, RGBa)]`.
+    #[inline]
+    #[must_use]
+    pub fn stops(&self) -> &[(f64, RGBa)] {
+        &self.stops
+    }
+    /// Get the `interpolation` field.
+    /// # Returns
+    /// An `Interpolation`.
+    #[inline]
+    #[must_use]
+    pub const fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+    /// Set the `interpolation` field.
+    /// # Arguments
+    /// * `value: Interpolation` - The new value.
+    #[inline]
+    pub fn set_interpolation(&mut self, value: Interpolation) {
+        self.interpolation = value;
+    }
+    /// Adds a stop to `self`. A stop at the same position as an existing one is placed after it, which makes a hard edge.
+    /// # Arguments
+    /// * `position: f64` - The position in `[0; 1]`.
+    /// * `colour: RGBa` - The colour.
+    /// # Panics
+    /// Panics if `position` is not in the interval `[0; 1]`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{gradient::{Gradient, Interpolation}, rgba::RGBa};
+    /// let mut g = Gradient::from_colours(&[RGBa::SOLID_BLACK, RGBa::SOLID_WHITE], Interpolation::Srgb);
+    ///
+    /// g.add_stop(0.5, RGBa::SOLID_RED);
+    /// assert_eq!(RGBa::SOLID_RED, g.sample(0.5));
+    /// assert_eq!(RGBa::new(128, 0, 0, 255), g.sample(0.25));
+    /// ```
+    pub fn add_stop(&mut self, position: f64, colour: RGBa) {
+        if !(0.0..=1.0).contains(&position)
+        { panic!("{}", ARG_ERR); }
+
+        let i = self.stops.partition_point(|(p, _)| *p <= position);
+        self.stops.insert(i, (position, colour));
+    }
+    /// Samples the colour of `self` at a position. Positions outside of `[0; 1]` are clamped.
+    /// # Arguments
+    /// * `t: f64` - The position.
+    /// # Returns
+    /// A `RGBa`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{gradient::{Gradient, Interpolation}, rgba::RGBa};
+    ///
+    /// let stops = [RGBa::SOLID_RED, RGBa::SOLID_BLUE];
+    ///
+    /// // sRGB goes through a dark purple, HSL takes the short way via magenta.
+    /// assert_eq!(RGBa::new(128, 0, 128, 255), Gradient::from_colours(&stops, Interpolation::Srgb).sample(0.5));
+    /// assert_eq!(RGBa::new(255, 0, 255, 255), Gradient::from_colours(&stops, Interpolation::Hsl).sample(0.5));
+    /// ```
+    #[must_use]
+    pub fn sample(&self, t: f64) -> RGBa {
+        let t = match t.is_nan() {
+            true  => 0.0,
+            false => t.clamp(0.0, 1.0),
+        };
+        let i = self.stops.partition_point(|(p, _)| *p <= t);
+        if i == 0
+        { return self.stops[0].1; }
+        if i == self.stops.len()
+        { return self.stops[i - 1].1; }
+
+        let (p0, c0) = self.stops[i - 1];
+        let (p1, c1) = self.stops[i];
+        interpolate(&c0, &c1, (t - p0) / (p1 - p0), self.interpolation)
+    }
+    /// Samples `n` evenly spaced colours of `self`, including both ends.
+    /// # Arguments
+    /// * `n: usize` - The number of colours.
+    /// # Returns
+    /// A `Vec<RGBa>`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{gradient::Gradient, rgba::RGBa};
+    ///
+    /// let legend = Gradient::magma().samples(5);
+    ///
+    /// assert_eq!(5, legend.len());
+    /// assert_eq!(RGBa::new(0xfc, 0xfd, 0xbf, 255), legend[4]);
+    /// ```
+    #[must_use]
+    pub fn samples(&self, n: usize) -> Vec<RGBa> {
+        let last = n.saturating_sub(1).max(1) as f64;
+        (0..n).map(|i| self.sample(i as f64 / last))
+              .collect()
+    }
+}
+
+/// Interpolates between two colours. `t` is expected in `[0; 1]`.
+/// # Arguments
+/// * `from: &RGBa` - The colour at `t == 0`.
+/// * `to: &RGBa` - The colour at `t == 1`.
+/// * `t: f64` - The position between both colours.
+/// * `interpolation: Interpolation` - The colour space to interpolate in.
+/// # Returns
+/// A new `RGBa`.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::colours::{gradient::{interpolate, Interpolation}, rgba::RGBa};
+///
+/// let grey = interpolate(&RGBa::SOLID_BLACK, &RGBa::SOLID_WHITE, 0.5, Interpolation::OKLab);
+/// assert_eq!(RGBa::new(99, 99, 99, 255), grey);
+/// ```
+#[must_use]
+pub fn interpolate(from: &RGBa, to: &RGBa, t: f64, interpolation: Interpolation) -> RGBa {
+    let lerp  = |a: f64, b: f64| a + (b - a) * t;
+    let alpha = lerp(from.alpha as f64, to.alpha as f64).round() as u8;
+    let mut res = match interpolation {
+        Interpolation::Srgb      => {
+            let ch = |a: u8, b: u8| lerp(a as f64, b as f64).round() as u8;
+            RGBa::new(ch(from.red, to.red), ch(from.green, to.green), ch(from.blue, to.blue), 255)
+        },
+        Interpolation::LinearRgb => {
+            let (r0, g0, b0) = from.to_linear_rgb();
+            let (r1, g1, b1) = to.to_linear_rgb();
+            RGBa::from_linear_rgb(lerp(r0, r1), lerp(g0, g1), lerp(b0, b1), 255)
+        },
+        Interpolation::Hsl       => {
+            let (h0, h1) = (HSL::from_rgba_struct(from), HSL::from_rgba_struct(to));
+            // The hue of a grey is meaningless, so the other colour's hue is kept.
+            let (a, b) = match (h0.saturation() == 0.0, h1.saturation() == 0.0) {
+                (true, false) => { (h1.hue() as f64, h1.hue() as f64) },
+                (false, true) => { (h0.hue() as f64, h0.hue() as f64) },
+                _             => { (h0.hue() as f64, h1.hue() as f64) },
+            };
+            let diff = (b - a + 180.0).rem_euclid(360.0) - 180.0;
+            let ch   = |x: f32, y: f32| lerp(x as f64, y as f64).clamp(0.0, 1.0) as f32;
+            RGBa::from_hsl_struct(&HSL::new((a + diff * t) as f32,
+                                            ch(h0.saturation(), h1.saturation()),
+                                            ch(h0.lightness(), h1.lightness())))
+        },
+        Interpolation::OKLab     => {
+            let (l0, l1) = (OKLab::from_rgba_struct(from), OKLab::from_rgba_struct(to));
+            RGBa::from_oklab_struct(&OKLab::new(lerp(l0.l, l1.l), lerp(l0.a, l1.a), lerp(l0.b, l1.b)))
+        },
+    };
+    res.alpha = alpha;
+    res
+}
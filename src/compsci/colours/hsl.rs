@@ -0,0 +1,207 @@
+//! In here you'll find the struct for the `HSL` colour-definition.
+use crate::{math::general::NumTools, compsci::colours::{rgba::RGBa, hue_max_min, wrap_hue}};
+
+const ARG_ERR: &str = "Saturation and lightness must range from 0 to 1.";
+/// A struct for storing HSL-Values (hue, saturation, lightness).
+/// \
+/// The hue is given in degrees in `[0; 360)`, saturation and lightness in `[0; 1]`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct HSL {
+    hue:        f32,
+    saturation: f32,
+    lightness:  f32
+}
+
+impl HSL {
+    /// Create a new `HSL` struct. The hue is wrapped into `[0; 360)`.
+    /// # Arguments
+    /// * `hue: f32` - The hue in degrees.
+    /// * `saturation: f32` - The saturation.
+    /// * `lightness: f32` - The lightness.
+    /// # Returns
+    /// A new `HSL` struct.
+    /// # Panics
+    /// Panics if `saturation` or `lightness` is not in the interval `[0; 1]`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::hsl::HSL;
+    ///
+    /// let nice_blue = HSL::new(210.0, 1.0, 0.75);
+    /// assert_eq!(150.0, HSL::new(-210.0, 1.0, 0.75).hue());
+    /// assert_eq!(0.0, HSL::new(-1e-6, 0.5, 0.5).hue());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new(hue:        f32,
+               saturation: f32,
+               lightness:  f32) -> HSL {
+        if !saturation.is_in_range(0.0, 1.0) ||
+           !lightness.is_in_range(0.0, 1.0)
+        { panic!("{}", ARG_ERR); }
+
+        HSL { hue: wrap_hue(hue), saturation, lightness }
+    }
+    /// Create a new `HSL` struct from an existing `RGBa` struct. The alpha value is ignored.
+    /// # Arguments
+    /// * `rgb: &RGBa` - The `RGBa` struct.
+    /// # Returns
+    /// A new `HSL` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{hsl::HSL, rgba::RGBa};
+    ///
+    /// let nice_blue_rgba = RGBa::new(128, 191, 255, 255);
+    /// let nice_blue_hsl  = HSL::from_rgba_struct(&nice_blue_rgba);
+    ///
+    /// assert_eq!(210, nice_blue_hsl.hue().round() as u16);
+    /// assert_eq!(RGBa::from_hsl_struct(&nice_blue_hsl), nice_blue_rgba);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_rgba_struct(rgb: &RGBa) -> HSL {
+        let (h, max, min) = hue_max_min(rgb.red as f32 / 255.0,
+                                        rgb.green as f32 / 255.0,
+                                        rgb.blue as f32 / 255.0);
+        let l: f32 = (max + min) / 2.0;
+        let s: f32 = match max == min {
+            true  => 0.0,
+            false => ((max - min) / (1.0 - (2.0 * l - 1.0).abs())).min(1.0),
+        };
+
+        HSL { hue:        h,
+              saturation: s,
+              lightness:  l }
+    }
+    /// Create a new `HSL` struct from `RGB` values.
+    /// # Arguments
+    /// * `red: u8` - The red part.
+    /// * `green: u8` - The green part.
+    /// * `blue: u8` - The blue part.
+    /// # Returns
+    /// A new `HSL` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::hsl::HSL;
+    ///
+    /// assert_eq!(HSL::from_rgb_vals(255, 0, 0), HSL::SOLID_RED);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_rgb_vals(red: u8, green: u8, blue: u8) -> HSL {
+        HSL::from_rgba_struct(&RGBa::new(red, green, blue, 255))
+    }
+    /// Get the `hue` field.
+    /// # Returns
+    /// A `f32`.
+    #[inline]
+    #[must_use]
+    pub const fn hue(&self) -> f32 {
+        self.hue
+    }
+    /// Get the `saturation` field.
+    /// # Returns
+    /// A `f32`.
+    #[inline]
+    #[must_use]
+    pub const fn saturation(&self) -> f32 {
+        self.saturation
+    }
+    /// Get the `lightness` field.
+    /// # Returns
+    /// A `f32`.
+    #[inline]
+    #[must_use]
+    pub const fn lightness(&self) -> f32 {
+        self.lightness
+    }
+    /// Set the `hue` field. The value is wrapped into `[0; 360)`.
+    /// # Arguments
+    /// * `value: f32` - The new value in degrees.
+    #[inline]
+    pub fn set_hue(&mut self, value: f32) {
+        self.hue = wrap_hue(value);
+    }
+    /// Set the `saturation` field.
+    /// # Arguments
+    /// * `value: f32` - The new value.
+    /// # Panics
+    /// Panics if `value` is not in the interval `[0; 1]`.
+    #[inline]
+    pub fn set_saturation(&mut self, value: f32) {
+        if !value.is_in_range(0.0, 1.0)
+        { panic!("{}", ARG_ERR); }
+
+        self.saturation = value;
+    }
+    /// Set the `lightness` field.
+    /// # Arguments
+    /// * `value: f32` - The new value.
+    /// # Panics
+    /// Panics if `value` is not in the interval `[0; 1]`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{hsl::HSL, rgba::RGBa};
+    /// let mut c = HSL::SOLID_RED;
+    ///
+    /// c.set_lightness(0.75);
+    /// assert_eq!(RGBa::new(255, 128, 128, 255), RGBa::from_hsl_struct(&c));
+    /// ```
+    #[inline]
+    pub fn set_lightness(&mut self, value: f32) {
+        if !value.is_in_range(0.0, 1.0)
+        { panic!("{}", ARG_ERR); }
+
+        self.lightness = value;
+    }
+    /// Rotate the hue of `self`. The result is wrapped into `[0; 360)`.
+    /// # Arguments
+    /// * `degrees: f32` - The angle by which the hue is rotated.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::hsl::HSL;
+    /// let mut c = HSL::SOLID_RED;
+    ///
+    /// c.rotate_hue(-120.0);
+    /// assert_eq!(HSL::SOLID_BLUE, c);
+    /// ```
+    #[inline]
+    pub fn rotate_hue(&mut self, degrees: f32) {
+        self.set_hue(self.hue + degrees);
+    }
+    /// The pure colour red, Hex-Code `FF0000`.
+    pub const SOLID_RED: HSL = HSL { hue: 0.0, saturation: 1.0, lightness: 0.5 };
+    /// The pure colour green, Hex-Code `00FF00`.
+    pub const SOLID_GREEN: HSL = HSL { hue: 120.0, saturation: 1.0, lightness: 0.5 };
+    /// The pure colour blue, Hex-Code `0000FF`.
+    pub const SOLID_BLUE: HSL = HSL { hue: 240.0, saturation: 1.0, lightness: 0.5 };
+    /// The pure colour white, Hex-Code `FFFFFF`.
+    pub const SOLID_WHITE: HSL = HSL { hue: 0.0, saturation: 0.0, lightness: 1.0 };
+    /// The pure colour black, Hex-Code `000000`.
+    pub const SOLID_BLACK: HSL = HSL { hue: 0.0, saturation: 0.0, lightness: 0.0 };
+}
+
+impl std::fmt::Display for HSL {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}; {}; {})", self.hue(),
+                                  self.saturation(),
+                                  self.lightness())
+    }
+}
+
+impl std::fmt::Debug for HSL {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(H: {}; S: {}; L: {})", self.hue(),
+                                           self.saturation(),
+                                           self.lightness())
+    }
+}
+/// Iterate over a `HSL` struct in the order `H, S, L`.
+impl IntoIterator for HSL {
+    type Item = f32;
+
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        vec![self.hue(), self.saturation(), self.lightness()].into_iter()
+    }
+}
@@ -0,0 +1,206 @@
+//! In here you'll find the struct for the `HSV` colour-definition.
+use crate::{math::general::NumTools, compsci::colours::{rgba::RGBa, hue_max_min, wrap_hue}};
+
+const ARG_ERR: &str = "Saturation and value must range from 0 to 1.";
+/// A struct for storing HSV-Values (hue, saturation, value).
+/// \
+/// The hue is given in degrees in `[0; 360)`, saturation and value in `[0; 1]`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct HSV {
+    hue:        f32,
+    saturation: f32,
+    value:      f32
+}
+
+impl HSV {
+    /// Create a new `HSV` struct. The hue is wrapped into `[0; 360)`.
+    /// # Arguments
+    /// * `hue: f32` - The hue in degrees.
+    /// * `saturation: f32` - The saturation.
+    /// * `value: f32` - The value.
+    /// # Returns
+    /// A new `HSV` struct.
+    /// # Panics
+    /// Panics if `saturation` or `value` is not in the interval `[0; 1]`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::hsv::HSV;
+    ///
+    /// let nice_blue = HSV::new(210.0, 0.5, 1.0);
+    /// assert_eq!(150.0, HSV::new(-210.0, 0.5, 1.0).hue());
+    /// assert_eq!(0.0, HSV::new(-1e-6, 0.5, 1.0).hue());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new(hue:        f32,
+               saturation: f32,
+               value:      f32) -> HSV {
+        if !saturation.is_in_range(0.0, 1.0) ||
+           !value.is_in_range(0.0, 1.0)
+        { panic!("{}", ARG_ERR); }
+
+        HSV { hue: wrap_hue(hue), saturation, value }
+    }
+    /// Create a new `HSV` struct from an existing `RGBa` struct. The alpha value is ignored.
+    /// # Arguments
+    /// * `rgb: &RGBa` - The `RGBa` struct.
+    /// # Returns
+    /// A new `HSV` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{hsv::HSV, rgba::RGBa};
+    ///
+    /// let nice_blue_rgba = RGBa::new(128, 191, 255, 255);
+    /// let nice_blue_hsv  = HSV::from_rgba_struct(&nice_blue_rgba);
+    ///
+    /// assert_eq!(210, nice_blue_hsv.hue().round() as u16);
+    /// assert_eq!(RGBa::from_hsv_struct(&nice_blue_hsv), nice_blue_rgba);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_rgba_struct(rgb: &RGBa) -> HSV {
+        let (h, max, min) = hue_max_min(rgb.red as f32 / 255.0,
+                                        rgb.green as f32 / 255.0,
+                                        rgb.blue as f32 / 255.0);
+        let s: f32 = match max == 0.0 {
+            true  => 0.0,
+            false => (max - min) / max,
+        };
+
+        HSV { hue:        h,
+              saturation: s,
+              value:      max }
+    }
+    /// Create a new `HSV` struct from `RGB` values.
+    /// # Arguments
+    /// * `red: u8` - The red part.
+    /// * `green: u8` - The green part.
+    /// * `blue: u8` - The blue part.
+    /// # Returns
+    /// A new `HSV` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::hsv::HSV;
+    ///
+    /// assert_eq!(HSV::from_rgb_vals(255, 0, 0), HSV::SOLID_RED);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_rgb_vals(red: u8, green: u8, blue: u8) -> HSV {
+        HSV::from_rgba_struct(&RGBa::new(red, green, blue, 255))
+    }
+    /// Get the `hue` field.
+    /// # Returns
+    /// A `f32`.
+    #[inline]
+    #[must_use]
+    pub const fn hue(&self) -> f32 {
+        self.hue
+    }
+    /// Get the `saturation` field.
+    /// # Returns
+    /// A `f32`.
+    #[inline]
+    #[must_use]
+    pub const fn saturation(&self) -> f32 {
+        self.saturation
+    }
+    /// Get the `value` field.
+    /// # Returns
+    /// A `f32`.
+    #[inline]
+    #[must_use]
+    pub const fn value(&self) -> f32 {
+        self.value
+    }
+    /// Set the `hue` field. The value is wrapped into `[0; 360)`.
+    /// # Arguments
+    /// * `value: f32` - The new value in degrees.
+    #[inline]
+    pub fn set_hue(&mut self, value: f32) {
+        self.hue = wrap_hue(value);
+    }
+    /// Set the `saturation` field.
+    /// # Arguments
+    /// * `value: f32` - The new value.
+    /// # Panics
+    /// Panics if `value` is not in the interval `[0; 1]`.
+    #[inline]
+    pub fn set_saturation(&mut self, value: f32) {
+        if !value.is_in_range(0.0, 1.0)
+        { panic!("{}", ARG_ERR); }
+
+        self.saturation = value;
+    }
+    /// Set the `value` field.
+    /// # Arguments
+    /// * `value: f32` - The new value.
+    /// # Panics
+    /// Panics if `value` is not in the interval `[0; 1]`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{hsv::HSV, rgba::RGBa};
+    /// let mut c = HSV::SOLID_RED;
+    /// 
+    /// c.set_value(0.5);
+    /// assert_eq!(RGBa::new(128, 0, 0, 255), RGBa::from_hsv_struct(&c));
+    /// ```
+    #[inline]
+    pub fn set_value(&mut self, value: f32) {
+        if !value.is_in_range(0.0, 1.0)
+        { panic!("{}", ARG_ERR); }
+
+        self.value = value;
+    }
+    /// Rotate the hue of `self`. The result is wrapped into `[0; 360)`.
+    /// # Arguments
+    /// * `degrees: f32` - The angle by which the hue is rotated.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::hsv::HSV;
+    /// let mut c = HSV::SOLID_RED;
+    ///
+    /// c.rotate_hue(-120.0);
+    /// assert_eq!(HSV::SOLID_BLUE, c);
+    /// ```
+    #[inline]
+    pub fn rotate_hue(&mut self, degrees: f32) {
+        self.set_hue(self.hue + degrees);
+    }
+    /// The pure colour red, Hex-Code `FF0000`.
+    pub const SOLID_RED: HSV = HSV { hue: 0.0, saturation: 1.0, value: 1.0 };
+    /// The pure colour green, Hex-Code `00FF00`.
+    pub const SOLID_GREEN: HSV = HSV { hue: 120.0, saturation: 1.0, value: 1.0 };
+    /// The pure colour blue, Hex-Code `0000FF`.
+    pub const SOLID_BLUE: HSV = HSV { hue: 240.0, saturation: 1.0, value: 1.0 };
+    /// The pure colour white, Hex-Code `FFFFFF`.
+    pub const SOLID_WHITE: HSV = HSV { hue: 0.0, saturation: 0.0, value: 1.0 };
+    /// The pure colour black, Hex-Code `000000`.
+    pub const SOLID_BLACK: HSV = HSV { hue: 0.0, saturation: 0.0, value: 0.0 };
+}
+
+impl std::fmt::Display for HSV {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}; {}; {})", self.hue(),
+                                  self.saturation(),
+                                  self.value())
+    }
+}
+
+impl std::fmt::Debug for HSV {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(H: {}; S: {}; V: {})", self.hue(),
+                                           self.saturation(),
+                                           self.value())
+    }
+}
+/// Iterate over a `HSV` struct in the order `H, S, V`.
+impl IntoIterator for HSV {
+    type Item = f32;
+
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        vec![self.hue(), self.saturation(), self.value()].into_iter()
+    }
+}
@@ -0,0 +1,202 @@
+//! In here you'll find the struct for the `HWB` colour-definition.
+use crate::{math::general::NumTools, compsci::colours::{rgba::RGBa, hue_max_min, wrap_hue}};
+
+const ARG_ERR: &str = "Whiteness and blackness must range from 0 to 1.";
+/// A struct for storing HWB-Values (hue, whiteness, blackness).
+/// \
+/// The hue is given in degrees in `[0; 360)`, whiteness and blackness in `[0; 1]`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct HWB {
+    hue:       f32,
+    whiteness: f32,
+    blackness: f32
+}
+
+impl HWB {
+    /// Create a new `HWB` struct. The hue is wrapped into `[0; 360)`.
+    /// # Arguments
+    /// * `hue: f32` - The hue in degrees.
+    /// * `whiteness: f32` - The whiteness.
+    /// * `blackness: f32` - The blackness.
+    /// # Returns
+    /// A new `HWB` struct.
+    /// # Panics
+    /// Panics if `whiteness` or `blackness` is not in the interval `[0; 1]`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::hwb::HWB;
+    ///
+    /// let nice_blue = HWB::new(210.0, 0.5, 0.0);
+    /// assert_eq!(150.0, HWB::new(-210.0, 0.5, 0.0).hue());
+    /// assert_eq!(0.0, HWB::new(-1e-6, 0.5, 0.0).hue());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new(hue:       f32,
+               whiteness: f32,
+               blackness: f32) -> HWB {
+        if !whiteness.is_in_range(0.0, 1.0) ||
+           !blackness.is_in_range(0.0, 1.0)
+        { panic!("{}", ARG_ERR); }
+
+        HWB { hue: wrap_hue(hue), whiteness, blackness }
+    }
+    /// Create a new `HWB` struct from an existing `RGBa` struct. The alpha value is ignored.
+    /// # Arguments
+    /// * `rgb: &RGBa` - The `RGBa` struct.
+    /// # Returns
+    /// A new `HWB` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{hwb::HWB, rgba::RGBa};
+    ///
+    /// let nice_blue_rgba = RGBa::new(128, 191, 255, 255);
+    /// let nice_blue_hwb  = HWB::from_rgba_struct(&nice_blue_rgba);
+    ///
+    /// assert_eq!(210, nice_blue_hwb.hue().round() as u16);
+    /// assert_eq!(RGBa::from_hwb_struct(&nice_blue_hwb), nice_blue_rgba);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_rgba_struct(rgb: &RGBa) -> HWB {
+        let (h, max, min) = hue_max_min(rgb.red as f32 / 255.0,
+                                        rgb.green as f32 / 255.0,
+                                        rgb.blue as f32 / 255.0);
+
+        HWB { hue:       h,
+              whiteness: min,
+              blackness: 1.0 - max }
+    }
+    /// Create a new `HWB` struct from `RGB` values.
+    /// # Arguments
+    /// * `red: u8` - The red part.
+    /// * `green: u8` - The green part.
+    /// * `blue: u8` - The blue part.
+    /// # Returns
+    /// A new `HWB` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::hwb::HWB;
+    ///
+    /// assert_eq!(HWB::from_rgb_vals(255, 0, 0), HWB::SOLID_RED);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_rgb_vals(red: u8, green: u8, blue: u8) -> HWB {
+        HWB::from_rgba_struct(&RGBa::new(red, green, blue, 255))
+    }
+    /// Get the `hue` field.
+    /// # Returns
+    /// A `f32`.
+    #[inline]
+    #[must_use]
+    pub const fn hue(&self) -> f32 {
+        self.hue
+    }
+    /// Get the `whiteness` field.
+    /// # Returns
+    /// A `f32`.
+    #[inline]
+    #[must_use]
+    pub const fn whiteness(&self) -> f32 {
+        self.whiteness
+    }
+    /// Get the `blackness` field.
+    /// # Returns
+    /// A `f32`.
+    #[inline]
+    #[must_use]
+    pub const fn blackness(&self) -> f32 {
+        self.blackness
+    }
+    /// Set the `hue` field. The value is wrapped into `[0; 360)`.
+    /// # Arguments
+    /// * `value: f32` - The new value in degrees.
+    #[inline]
+    pub fn set_hue(&mut self, value: f32) {
+        self.hue = wrap_hue(value);
+    }
+    /// Set the `whiteness` field.
+    /// # Arguments
+    /// * `value: f32` - The new value.
+    /// # Panics
+    /// Panics if `value` is not in the interval `[0; 1]`.
+    #[inline]
+    pub fn set_whiteness(&mut self, value: f32) {
+        if !value.is_in_range(0.0, 1.0)
+        { panic!("{}", ARG_ERR); }
+
+        self.whiteness = value;
+    }
+    /// Set the `blackness` field.
+    /// # Arguments
+    /// * `value: f32` - The new value.
+    /// # Panics
+    /// Panics if `value` is not in the interval `[0; 1]`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{hwb::HWB, rgba::RGBa};
+    /// let mut c = HWB::SOLID_RED;
+    /// 
+    /// c.set_blackness(0.5);
+    /// assert_eq!(RGBa::new(128, 0, 0, 255), RGBa::from_hwb_struct(&c));
+    /// ```
+    #[inline]
+    pub fn set_blackness(&mut self, value: f32) {
+        if !value.is_in_range(0.0, 1.0)
+        { panic!("{}", ARG_ERR); }
+
+        self.blackness = value;
+    }
+    /// Rotate the hue of `self`. The result is wrapped into `[0; 360)`.
+    /// # Arguments
+    /// * `degrees: f32` - The angle by which the hue is rotated.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::hwb::HWB;
+    /// let mut c = HWB::SOLID_RED;
+    ///
+    /// c.rotate_hue(-120.0);
+    /// assert_eq!(HWB::SOLID_BLUE, c);
+    /// ```
+    #[inline]
+    pub fn rotate_hue(&mut self, degrees: f32) {
+        self.set_hue(self.hue + degrees);
+    }
+    /// The pure colour red, Hex-Code `FF0000`.
+    pub const SOLID_RED: HWB = HWB { hue: 0.0, whiteness: 0.0, blackness: 0.0 };
+    /// The pure colour green, Hex-Code `00FF00`.
+    pub const SOLID_GREEN: HWB = HWB { hue: 120.0, whiteness: 0.0, blackness: 0.0 };
+    /// The pure colour blue, Hex-Code `0000FF`.
+    pub const SOLID_BLUE: HWB = HWB { hue: 240.0, whiteness: 0.0, blackness: 0.0 };
+    /// The pure colour white, Hex-Code `FFFFFF`.
+    pub const SOLID_WHITE: HWB = HWB { hue: 0.0, whiteness: 1.0, blackness: 0.0 };
+    /// The pure colour black, Hex-Code `000000`.
+    pub const SOLID_BLACK: HWB = HWB { hue: 0.0, whiteness: 0.0, blackness: 1.0 };
+}
+
+impl std::fmt::Display for HWB {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}; {}; {})", self.hue(),
+                                  self.whiteness(),
+                                  self.blackness())
+    }
+}
+
+impl std::fmt::Debug for HWB {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(H: {}; W: {}; B: {})", self.hue(),
+                                           self.whiteness(),
+                                           self.blackness())
+    }
+}
+/// Iterate over a `HWB` struct in the order `H, W, B`.
+impl IntoIterator for HWB {
+    type Item = f32;
+
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        vec![self.hue(), self.whiteness(), self.blackness()].into_iter()
+    }
+}
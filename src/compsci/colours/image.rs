@@ -0,0 +1,702 @@
+//! In here you'll find a simple image buffer of `RGBa` pixels, which can be read from and written to PNM (PPM/PGM/PBM/PAM), BMP and QOI files.
+use std::fmt::Display;
+use crate::compsci::colours::rgba::RGBa;
+
+/// The file formats an `Image` can be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// The binary portable pixmap (`P6`). The alpha values are dropped.
+    Ppm,
+    /// The portable arbitrary map (`P7`) with `RGB_ALPHA` tuples.
+    Pam,
+    /// The uncompressed Windows bitmap, with 24 bits per pixel if opaque, otherwise with 32.
+    Bmp,
+    /// The "Quite OK Image" format.
+    Qoi,
+}
+
+/// The error returned when an image could not be read or written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageError {
+    /// The file could not be read or written.
+    Io(String),
+    /// The data is in none of the supported formats.
+    UnknownFormat,
+    /// The data uses a feature of its format which is not supported, e.g. compression.
+    Unsupported(String),
+    /// The data is damaged or truncated.
+    Malformed(String),
+}
+
+impl Display for ImageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImageError::Io(s)          => write!(f, "I/O error: {}", s),
+            ImageError::UnknownFormat  => write!(f, "Unknown image format."),
+            ImageError::Unsupported(s) => write!(f, "Unsupported: {}", s),
+            ImageError::Malformed(s)   => write!(f, "Malformed image: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for ImageError { }
+
+impl ImageFormat {
+    /// Guesses the format from the extension of a path.
+    /// # Arguments
+    /// * `path: &str` - The path.
+    /// # Returns
+    /// An `Option<ImageFormat>`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::image::ImageFormat;
+    ///
+    /// assert_eq!(Some(ImageFormat::Qoi), ImageFormat::from_path("out/heatmap.QOI"));
+    /// assert_eq!(None, ImageFormat::from_path("heatmap.png"));
+    /// ```
+    #[must_use]
+    pub fn from_path(path: &str) -> Option<ImageFormat> {
+        let ext = path.rsplit_once('.')?.1.to_ascii_lowercase();
+        match ext.as_str() {
+            "ppm" | "pnm" => { Some(ImageFormat::Ppm) },
+            "pam"         => { Some(ImageFormat::Pam) },
+            "bmp"         => { Some(ImageFormat::Bmp) },
+            "qoi"         => { Some(ImageFormat::Qoi) },
+            _             => { None },
+        }
+    }
+}
+
+/// An image as buffer of `RGBa` pixels, stored row by row from the top left.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    width:  usize,
+    height: usize,
+    pixels: Vec<RGBa>,
+}
+
+impl Image {
+    /// Create a new `Image` filled with one colour.
+    /// # Arguments
+    /// * `width: usize` - The width.
+    /// * `height: usize` - The height.
+    /// * `fill: RGBa` - The colour of all pixels.
+    /// # Returns
+    /// A new `Image`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{image::Image, rgba::RGBa};
+    ///
+    /// let img = Image::new(4, 2, RGBa::SOLID_WHITE);
+    /// assert_eq!(8, img.pixels().len());
+    /// ```
+    #[must_use]
+    pub fn new(width: usize, height: usize, fill: RGBa) -> Image {
+        Image { width, height, pixels: vec![fill; width * height] }
+    }
+    /// Create a new `Image` from pixels.
+    /// # Arguments
+    /// * `width: usize` - The width.
+    /// * `height: usize` - The height.
+    /// * `pixels: Vec<RGBa>` - The pixels, row by row.
+    /// # Returns
+    /// A new `Image`.
+    /// # Panics
+    /// Panics if the number of pixels is not `width * height`.
+    #[must_use]
+    pub fn from_pixels(width: usize, height: usize, pixels: Vec<RGBa>) -> Image {
+        if pixels.len() != width * height
+        { panic!("Expected {} pixels, got {}.", width * height, pixels.len()); }
+
+        Image { width, height, pixels }
+    }
+    /// Get the `width` field.
+    /// # Returns
+    /// A `usize`.
+    #[inline]
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+    /// Get the `height` field.
+    /// # Returns
+    /// A `usize`.
+    #[inline]
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+    /// Get the pixels, row by row.
+    /// # Returns
+    /// A `&[RGBa]`.
+    #[inline]
+    #[must_use]
+    pub fn pixels(&self) -> &[RGBa] {
+        &self.pixels
+    }
+    /// Get the pixels mutably, row by row.
+    /// # Returns
+    /// A `&mut [RGBa]`.
+    #[inline]
+    #[must_use]
+    pub fn pixels_mut(&mut self) -> &mut [RGBa] {
+        &mut self.pixels
+    }
+    /// Get a pixel.
+    /// # Arguments
+    /// * `x: usize` - The column.
+    /// * `y: usize` - The row.
+    /// # Returns
+    /// An `Option<RGBa>`, `None` if the position is outside of `self`.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> Option<RGBa> {
+        match x < self.width && y < self.height {
+            true  => Some(self.pixels[y * self.width + x]),
+            false => None,
+        }
+    }
+    /// Set a pixel.
+    /// # Arguments
+    /// * `x: usize` - The column.
+    /// * `y: usize` - The row.
+    /// * `colour: RGBa` - The new colour.
+    /// # Panics
+    /// Panics if the position is outside of `self`.
+    #[inline]
+    pub fn set(&mut self, x: usize, y: usize, colour: RGBa) {
+        if x >= self.width || y >= self.height
+        { panic!("Pixel ({}, {}) is outside of the {}x{} image.", x, y, self.width, self.height); }
+
+        self.pixels[y * self.width + x] = colour;
+    }
+    /// Applies a function to every pixel.
+    /// # Arguments
+    /// * `f: F` - The function.
+    /// # Returns
+    /// A new `Image`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{image::Image, rgba::RGBa, accessibility::ColourBlindness};
+    ///
+    /// let img = Image::new(2, 2, RGBa::SOLID_RED);
+    /// let sim = img.map(|c| ColourBlindness::Protanopia.simulate(c));
+    /// assert_eq!(Some(RGBa::new(109, 95, 0, 255)), sim.get(1, 1));
+    /// ```
+    #[must_use]
+    pub fn map<F: FnMut(&RGBa) -> RGBa>(&self, f: F) -> Image {
+        Image { width:  self.width,
+                height: self.height,
+                pixels: self.pixels.iter().map(f).collect() }
+    }
+    /// Reads an image file. The format is detected from the content.
+    /// # Arguments
+    /// * `path: &str` - The path of the file.
+    /// # Returns
+    /// A `Result<Image, ImageError>`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{image::{Image, ImageError}, rgba::RGBa};
+    ///
+    /// // The fixtures were written by another encoder.
+    /// let dir   = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/images/");
+    /// let pixel = |x: usize, y: usize, alpha: bool| {
+    ///     RGBa::new((x * 60) as u8, (y * 100) as u8, ((x + y) * 30) as u8,
+    ///               if alpha { 255 - x as u8 * 40 } else { 255 })
+    /// };
+    /// for (file, alpha) in [("sample.qoi", true), ("sample.pam", true), ("sample.bmp", true),
+    ///                       ("sample_rgb.bmp", false), ("sample.ppm", false), ("sample_ascii.ppm", false)] {
+    ///     let img = Image::read(&format!("{}{}", dir, file)).unwrap();
+    ///     assert_eq!((5, 3), (img.width(), img.height()));
+    ///     for y in 0..3 {
+    ///         for x in 0..5 {
+    ///             assert_eq!(Some(pixel(x, y, alpha)), img.get(x, y), "{} at ({}, {})", file, x, y);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// // Header values are not trusted.
+    /// assert!(matches!(Image::decode(b"P6\n4000000000 4000000000\n255\n"), Err(ImageError::Malformed(_))));
+    /// assert!(matches!(Image::decode(b"P4\n30000 30000\n"), Err(ImageError::Malformed(_))));
+    /// let mut bmp = std::fs::read(format!("{}sample.bmp", dir)).unwrap();
+    /// bmp[54..58].copy_from_slice(&u32::MAX.to_le_bytes()); // A 32-bit wide red mask.
+    /// assert_eq!(254, Image::decode(&bmp).unwrap().get(0, 0).unwrap().red);
+    /// bmp[18..22].copy_from_slice(&i32::MAX.to_le_bytes());
+    /// assert!(matches!(Image::decode(&bmp), Err(ImageError::Malformed(_))));
+    /// ```
+    pub fn read(path: &str) -> Result<Image, ImageError> {
+        let bytes = std::fs::read(path).map_err(|e| ImageError::Io(format!("{:?}", e)))?;
+        Image::decode(&bytes)
+    }
+    /// Writes `self` into a file. The format is chosen by the extension of `path`.
+    /// # Arguments
+    /// * `path: &str` - The path of the file.
+    /// # Returns
+    /// A `Result<(), ImageError>`.
+    pub fn write(&self, path: &str) -> Result<(), ImageError> {
+        let format = ImageFormat::from_path(path).ok_or(ImageError::UnknownFormat)?;
+        std::fs::write(path, self.encode(format)).map_err(|e| ImageError::Io(format!("{:?}", e)))
+    }
+    /// Decodes an image. The format is detected from the content.
+    /// \
+    /// Supported are all PNM variants `P1` to `P7` with up to 16 bits per sample,
+    /// uncompressed BMP with 1, 4, 8, 16, 24 or 32 bits per pixel and QOI.
+    /// # Arguments
+    /// * `bytes: &[u8]` - The content of the file.
+    /// # Returns
+    /// A `Result<Image, ImageError>`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{image::{Image, ImageError}, rgba::RGBa};
+    ///
+    /// let img = Image::decode(b"P3\n# A comment.\n2 1\n255\n255 0 0  0 0 255\n").unwrap();
+    /// assert_eq!(&[RGBa::SOLID_RED, RGBa::SOLID_BLUE], img.pixels());
+    ///
+    /// assert_eq!(Err(ImageError::UnknownFormat), Image::decode(b"GIF89a"));
+    /// ```
+    pub fn decode(bytes: &[u8]) -> Result<Image, ImageError> {
+        match bytes {
+            [b'P', b'1'..=b'7', ..] => { decode_pnm(bytes) },
+            [b'B', b'M', ..]        => { decode_bmp(bytes) },
+            [b'q', b'o', b'i', b'f', ..] => { decode_qoi(bytes) },
+            _                       => { Err(ImageError::UnknownFormat) },
+        }
+    }
+    /// Encodes `self` in a format.
+    /// # Arguments
+    /// * `format: ImageFormat` - The format.
+    /// # Returns
+    /// A `Vec<u8>` with the content of the file.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{image::{Image, ImageFormat}, gradient::Gradient, rgba::RGBa};
+    ///
+    /// let mut img = Image::new(7, 3, RGBa::SOLID_BLACK);
+    /// let colours = Gradient::viridis().samples(7);
+    /// for (x, c) in colours.iter().enumerate() {
+    ///     img.set(x, 0, *c);
+    ///     img.set(x, 2, RGBa::new(c.red, c.green, c.blue, 40 * x as u8));
+    /// }
+    /// for format in [ImageFormat::Pam, ImageFormat::Bmp, ImageFormat::Qoi] {
+    ///     assert_eq!(img, Image::decode(&img.encode(format)).unwrap());
+    /// }
+    /// // PPM has no alpha channel.
+    /// let ppm = Image::decode(&img.encode(ImageFormat::Ppm)).unwrap();
+    /// assert!(ppm.pixels().iter().all(|c| c.is_opaque()));
+    /// ```
+    #[must_use]
+    pub fn encode(&self, format: ImageFormat) -> Vec<u8> {
+        match format {
+            ImageFormat::Ppm => { self.encode_ppm() },
+            ImageFormat::Pam => { self.encode_pam() },
+            ImageFormat::Bmp => { self.encode_bmp() },
+            ImageFormat::Qoi => { self.encode_qoi() },
+        }
+    }
+
+    fn encode_ppm(&self) -> Vec<u8> {
+        let mut res = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        res.reserve(self.pixels.len() * 3);
+        for c in &self.pixels {
+            res.extend_from_slice(&[c.red, c.green, c.blue]);
+        }
+        res
+    }
+
+    fn encode_pam(&self) -> Vec<u8> {
+        let mut res = format!("P7\nWIDTH {}\nHEIGHT {}\nDEPTH 4\nMAXVAL 255\nTUPLTYPE RGB_ALPHA\nENDHDR\n",
+                              self.width, self.height).into_bytes();
+        res.reserve(self.pixels.len() * 4);
+        for c in &self.pixels {
+            res.extend_from_slice(&[c.red, c.green, c.blue, c.alpha]);
+        }
+        res
+    }
+
+    fn encode_bmp(&self) -> Vec<u8> {
+        let alpha       = self.pixels.iter().any(|c| !c.is_opaque());
+        let header_size = match alpha {
+            true  => 108,
+            false => 40,
+        };
+        let bytes_pp    = match alpha {
+            true  => 4,
+            false => 3,
+        };
+        let row_size    = (self.width * bytes_pp + 3) & !3;
+        let offset      = 14 + header_size;
+        let size        = offset + row_size * self.height;
+
+        let mut res = Vec::with_capacity(size);
+        res.extend_from_slice(b"BM");
+        res.extend_from_slice(&(size as u32).to_le_bytes());
+        res.extend_from_slice(&[0; 4]);
+        res.extend_from_slice(&(offset as u32).to_le_bytes());
+        res.extend_from_slice(&(header_size as u32).to_le_bytes());
+        res.extend_from_slice(&(self.width as i32).to_le_bytes());
+        res.extend_from_slice(&(self.height as i32).to_le_bytes());
+        res.extend_from_slice(&1u16.to_le_bytes());
+        res.extend_from_slice(&(bytes_pp as u16 * 8).to_le_bytes());
+        res.extend_from_slice(&(if alpha { 3u32 } else { 0 }).to_le_bytes());
+        res.extend_from_slice(&((row_size * self.height) as u32).to_le_bytes());
+        // 2835 pixels per metre are 72 DPI.
+        res.extend_from_slice(&2835u32.to_le_bytes());
+        res.extend_from_slice(&2835u32.to_le_bytes());
+        res.extend_from_slice(&[0; 8]);
+        if alpha {
+            for mask in [0x00ff_0000u32, 0x0000_ff00, 0x0000_00ff, 0xff00_0000] {
+                res.extend_from_slice(&mask.to_le_bytes());
+            }
+            res.extend_from_slice(b"BGRs");
+            res.extend_from_slice(&[0; 48]);
+        }
+        for row in self.pixels.chunks(self.width.max(1)).rev() {
+            let start = res.len();
+            for c in row {
+                res.extend_from_slice(&[c.blue, c.green, c.red]);
+                if alpha
+                { res.push(c.alpha); }
+            }
+            res.resize(start + row_size, 0);
+        }
+        res
+    }
+
+    fn encode_qoi(&self) -> Vec<u8> {
+        let mut res = Vec::with_capacity(14 + self.pixels.len() * 2 + 8);
+        res.extend_from_slice(b"qoif");
+        res.extend_from_slice(&(self.width as u32).to_be_bytes());
+        res.extend_from_slice(&(self.height as u32).to_be_bytes());
+        res.push(match self.pixels.iter().all(RGBa::is_opaque) {
+            true  => 3,
+            false => 4,
+        });
+        res.push(0);
+
+        let mut index = [RGBa::new(0, 0, 0, 0); 64];
+        let mut prev  = RGBa::new(0, 0, 0, 255);
+        let mut run   = 0u8;
+        for (i, px) in self.pixels.iter().enumerate() {
+            if *px == prev {
+                run += 1;
+                if run == 62 || i == self.pixels.len() - 1
+                { res.push(0xc0 | (run - 1)); run = 0; }
+                continue;
+            }
+            if run > 0
+            { res.push(0xc0 | (run - 1)); run = 0; }
+
+            let h = qoi_hash(px);
+            if index[h] == *px {
+                res.push(h as u8);
+            }
+            else {
+                index[h] = *px;
+                let dr = px.red.wrapping_sub(prev.red) as i8;
+                let dg = px.green.wrapping_sub(prev.green) as i8;
+                let db = px.blue.wrapping_sub(prev.blue) as i8;
+                let (dr_dg, db_dg) = (dr.wrapping_sub(dg), db.wrapping_sub(dg));
+                match px.alpha == prev.alpha {
+                    true if (-2..2).contains(&dr) && (-2..2).contains(&dg) && (-2..2).contains(&db) => {
+                        res.push(0x40 | ((dr + 2) as u8) << 4 | ((dg + 2) as u8) << 2 | (db + 2) as u8);
+                    },
+                    true if (-32..32).contains(&dg) && (-8..8).contains(&dr_dg) && (-8..8).contains(&db_dg) => {
+                        res.push(0x80 | (dg + 32) as u8);
+                        res.push(((dr_dg + 8) as u8) << 4 | (db_dg + 8) as u8);
+                    },
+                    true  => { res.extend_from_slice(&[0xfe, px.red, px.green, px.blue]); },
+                    false => { res.extend_from_slice(&[0xff, px.red, px.green, px.blue, px.alpha]); },
+                }
+            }
+            prev = *px;
+        }
+        res.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 1]);
+        res
+    }
+}
+
+fn malformed(s: &str) -> ImageError {
+    ImageError::Malformed(s.to_owned())
+}
+
+/// Scales a sample with the maximum value `max` to 8 bits.
+fn scale(v: u32, max: u32) -> u8 {
+    match max {
+        255 => { v.min(255) as u8 },
+        _   => { ((v.min(max) as u64 * 255 + max as u64 / 2) / max as u64) as u8 },
+    }
+}
+
+/// Reads whitespace separated header tokens of a PNM file, skipping comments.
+struct PnmHeader<'a> {
+    bytes: &'a [u8],
+    pos:   usize,
+}
+
+impl<'a> PnmHeader<'a> {
+    fn token(&mut self) -> Result<&'a str, ImageError> {
+        loop {
+            match self.bytes.get(self.pos) {
+                Some(b'#')                    => {
+                    while !matches!(self.bytes.get(self.pos), Some(b'\n') | None)
+                    { self.pos += 1; }
+                },
+                Some(b) if b.is_ascii_whitespace() => { self.pos += 1; },
+                Some(_)                       => { break; },
+                None                          => { return Err(malformed("Unexpected end of header.")); },
+            }
+        }
+        let start = self.pos;
+        while self.bytes.get(self.pos).is_some_and(|b| !b.is_ascii_whitespace())
+        { self.pos += 1; }
+        std::str::from_utf8(&self.bytes[start..self.pos]).map_err(|_| malformed("Invalid header."))
+    }
+
+    fn number(&mut self) -> Result<u32, ImageError> {
+        let t = self.token()?;
+        t.parse().map_err(|_| ImageError::Malformed(format!("Invalid number in header: {}", t)))
+    }
+}
+
+fn decode_pnm(bytes: &[u8]) -> Result<Image, ImageError> {
+    let kind = bytes[1];
+    let mut h = PnmHeader { bytes, pos: 2 };
+    let (width, height, depth, max) = match kind {
+        b'7' => {
+            let (mut w, mut ht, mut d, mut m) = (None, None, None, None);
+            loop {
+                match h.token()? {
+                    "WIDTH"    => { w  = Some(h.number()?); },
+                    "HEIGHT"   => { ht = Some(h.number()?); },
+                    "DEPTH"    => { d  = Some(h.number()?); },
+                    "MAXVAL"   => { m  = Some(h.number()?); },
+                    "TUPLTYPE" => { h.token()?; },
+                    "ENDHDR"   => { break; },
+                    t          => { return Err(ImageError::Malformed(format!("Unknown header field: {}", t))); },
+                }
+            }
+            match (w, ht, d, m) {
+                (Some(w), Some(ht), Some(d @ 1..=4), Some(m)) => { (w, ht, d, m) },
+                (_, _, Some(d), _)                           => { return Err(ImageError::Unsupported(format!("Depth {}.", d))); },
+                _                                             => { return Err(malformed("Incomplete header.")); },
+            }
+        },
+        _ => {
+            let (w, ht) = (h.number()?, h.number()?);
+            let m = match kind {
+                b'1' | b'4' => { 1 },
+                _           => { h.number()? },
+            };
+            let depth = match kind {
+                b'3' | b'6' => { 3 },
+                _           => { 1 },
+            };
+            (w, ht, depth, m)
+        },
+    };
+    if max == 0 || max > 65535
+    { return Err(ImageError::Unsupported(format!("Maximum value {}.", max))); }
+    // Exactly one whitespace character separates the header from binary data.
+    h.pos += 1;
+
+    let (width, height, depth) = (width as usize, height as usize, depth as usize);
+    let too_large = || malformed("Image too large.");
+    let count     = width.checked_mul(height).ok_or_else(too_large)?;
+    count.checked_mul(depth).ok_or_else(too_large)?;
+    let data      = bytes.get(h.pos..).unwrap_or(&[]);
+    let samples: Vec<u32> = match kind {
+        b'1'        => {
+            data.iter().filter(|b| matches!(b, b'0' | b'1')).take(count).map(|b| (b'1' - b) as u32).collect()
+        },
+        b'2' | b'3' => {
+            let text = std::str::from_utf8(data).map_err(|_| malformed("Invalid text data."))?;
+            text.split_whitespace()
+                .take(count * depth)
+                .map(|t| t.parse::<u32>().map_err(|_| ImageError::Malformed(format!("Invalid sample: {}", t))))
+                .collect::<Result<_, _>>()?
+        },
+        b'4'        => {
+            let row = width.div_ceil(8);
+            if row.checked_mul(height).is_none_or(|n| data.len() < n)
+            { return Err(malformed("Not enough pixel data.")); }
+            (0..count).filter_map(|i| data.get((i / width) * row + (i % width) / 8)
+                                          .map(|b| (((b >> (7 - (i % width) % 8)) & 1) ^ 1) as u32))
+                      .collect()
+        },
+        _           => {
+            match max < 256 {
+                true  => data.iter().take(count * depth).map(|b| *b as u32).collect(),
+                false => data.chunks_exact(2).take(count * depth).map(|b| u16::from_be_bytes([b[0], b[1]]) as u32).collect(),
+            }
+        },
+    };
+    if samples.len() < count * depth
+    { return Err(malformed("Not enough pixel data.")); }
+
+    let pixels = samples.chunks_exact(depth)
+                        .map(|s| {
+                            let v = |i: usize| scale(s[i], max);
+                            match depth {
+                                1 => { RGBa::new(v(0), v(0), v(0), 255) },
+                                2 => { RGBa::new(v(0), v(0), v(0), v(1)) },
+                                3 => { RGBa::new(v(0), v(1), v(2), 255) },
+                                _ => { RGBa::new(v(0), v(1), v(2), v(3)) },
+                            }
+                        })
+                        .collect();
+    Ok(Image { width, height, pixels })
+}
+
+fn decode_bmp(bytes: &[u8]) -> Result<Image, ImageError> {
+    let u16_at = |i: usize| bytes.get(i..i + 2).map(|b| u16::from_le_bytes([b[0], b[1]]) as u32);
+    let u32_at = |i: usize| bytes.get(i..i + 4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]));
+    let short  = || malformed("Truncated header.");
+
+    let offset      = u32_at(10).ok_or_else(short)? as usize;
+    let header_size = u32_at(14).ok_or_else(short)? as usize;
+    let (width, height, bpp, compression) = match header_size {
+        12 => { (u16_at(18).ok_or_else(short)? as i32, u16_at(20).ok_or_else(short)? as i32, u16_at(24).ok_or_else(short)?, 0) },
+        _  => { (u32_at(18).ok_or_else(short)? as i32, u32_at(22).ok_or_else(short)? as i32,
+                 u16_at(28).ok_or_else(short)?, u32_at(30).ok_or_else(short)?) },
+    };
+    if width < 0
+    { return Err(malformed("Negative width.")); }
+    let top_down = height < 0;
+    let (width, height) = (width as usize, height.unsigned_abs() as usize);
+
+    let masks = match (compression, bpp) {
+        (0, 16)    => { [0x7c00, 0x03e0, 0x001f, 0] },
+        (0, 32)    => { [0x00ff_0000, 0x0000_ff00, 0x0000_00ff, 0] },
+        (3 | 6, _) => {
+            // The masks follow a BITMAPINFOHEADER or are part of the later header versions.
+            let m = |i: usize| u32_at(14 + 40 + 4 * i).unwrap_or(0);
+            let alpha = match header_size >= 56 || compression == 6 {
+                true  => m(3),
+                false => 0,
+            };
+            [m(0), m(1), m(2), alpha]
+        },
+        (0, _)     => { [0; 4] },
+        (c, _)     => { return Err(ImageError::Unsupported(format!("Compression {}.", c))); },
+    };
+
+    let palette: Vec<RGBa> = match bpp {
+        1 | 4 | 8 => {
+            let entry = match header_size {
+                12 => { 3 },
+                _  => { 4 },
+            };
+            let used  = match u32_at(46) {
+                Some(n) if header_size > 12 && n > 0 => { (n as usize).min(1 << bpp) },
+                _                                   => { 1 << bpp },
+            };
+            let start = 14 + header_size + match compression {
+                3 if header_size == 40 => { 12 },
+                6 if header_size == 40 => { 16 },
+                _                      => { 0 },
+            };
+            (0..used).filter_map(|i| bytes.get(start + i * entry..start + i * entry + 3))
+                     .map(|b| RGBa::new(b[2], b[1], b[0], 255))
+                     .collect()
+        },
+        16 | 24 | 32 => { Vec::new() },
+        _            => { return Err(ImageError::Unsupported(format!("{} bits per pixel.", bpp))); },
+    };
+
+    let row_size = (width * bpp as usize).div_ceil(32) * 4;
+    if row_size.checked_mul(height).and_then(|n| n.checked_add(offset)).is_none_or(|n| bytes.len() < n)
+    { return Err(malformed("Not enough pixel data.")); }
+
+    let channel = |v: u32, mask: u32| -> u8 {
+        match mask {
+            0 => { 255 },
+            _ => {
+                let bits = mask.count_ones();
+                scale((v & mask) >> mask.trailing_zeros(), (1u64 << bits).saturating_sub(1) as u32)
+            },
+        }
+    };
+    let mut pixels = Vec::with_capacity(width * height);
+    for y in 0..height {
+        let row_index = match top_down {
+            true  => y,
+            false => height - 1 - y,
+        };
+        let row = &bytes[offset + row_index * row_size..offset + (row_index + 1) * row_size];
+        for x in 0..width {
+            let c = match bpp {
+                1 | 4 | 8 => {
+                    let bit = x * bpp as usize;
+                    let i   = (row[bit / 8] >> (8 - bpp as usize - bit % 8)) & ((1u16 << bpp) - 1) as u8;
+                    *palette.get(i as usize).ok_or_else(|| malformed("Palette index out of range."))?
+                },
+                24        => { RGBa::new(row[3 * x + 2], row[3 * x + 1], row[3 * x], 255) },
+                _         => {
+                    let v = match bpp {
+                        16 => { u16::from_le_bytes([row[2 * x], row[2 * x + 1]]) as u32 },
+                        _  => { u32::from_le_bytes([row[4 * x], row[4 * x + 1], row[4 * x + 2], row[4 * x + 3]]) },
+                    };
+                    RGBa::new(channel(v, masks[0]), channel(v, masks[1]), channel(v, masks[2]), channel(v, masks[3]))
+                },
+            };
+            pixels.push(c);
+        }
+    }
+    Ok(Image { width, height, pixels })
+}
+
+fn qoi_hash(c: &RGBa) -> usize {
+    (c.red as usize * 3 + c.green as usize * 5 + c.blue as usize * 7 + c.alpha as usize * 11) % 64
+}
+
+fn decode_qoi(bytes: &[u8]) -> Result<Image, ImageError> {
+    if bytes.len() < 14 + 8
+    { return Err(malformed("Truncated header.")); }
+
+    let width  = u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]) as usize;
+    let height = u32::from_be_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize;
+    let count  = width.checked_mul(height).ok_or_else(|| malformed("Image too large."))?;
+    // Every byte yields at most 62 pixels, which bounds the allocation for damaged headers.
+    let mut pixels = Vec::with_capacity(count.min(bytes.len() * 62));
+    let mut index  = [RGBa::new(0, 0, 0, 0); 64];
+    let mut px     = RGBa::new(0, 0, 0, 255);
+    let mut pos    = 14;
+    let data_end   = bytes.len() - 8;
+    let mut next   = || -> Result<u8, ImageError> {
+        let b = *bytes[..data_end].get(pos).ok_or_else(|| malformed("Not enough pixel data."))?;
+        pos += 1;
+        Ok(b)
+    };
+
+    while pixels.len() < count {
+        let b1 = next()?;
+        let mut run = 1;
+        match b1 {
+            0xfe => { px = RGBa::new(next()?, next()?, next()?, px.alpha); },
+            0xff => { px = RGBa::new(next()?, next()?, next()?, next()?); },
+            _    => {
+                match b1 >> 6 {
+                    0 => { px = index[b1 as usize]; },
+                    1 => {
+                        px.red   = px.red.wrapping_add((b1 >> 4) & 3).wrapping_sub(2);
+                        px.green = px.green.wrapping_add((b1 >> 2) & 3).wrapping_sub(2);
+                        px.blue  = px.blue.wrapping_add(b1 & 3).wrapping_sub(2);
+                    },
+                    2 => {
+                        let b2 = next()?;
+                        let dg = (b1 & 0x3f).wrapping_sub(32);
+                        px.red   = px.red.wrapping_add(dg).wrapping_add(b2 >> 4).wrapping_sub(8);
+                        px.green = px.green.wrapping_add(dg);
+                        px.blue  = px.blue.wrapping_add(dg).wrapping_add(b2 & 0x0f).wrapping_sub(8);
+                    },
+                    _ => { run = (b1 & 0x3f) as usize + 1; },
+                }
+            },
+        }
+        index[qoi_hash(&px)] = px;
+        for _ in 0..run.min(count - pixels.len()) {
+            pixels.push(px);
+        }
+    }
+    Ok(Image { width, height, pixels })
+}
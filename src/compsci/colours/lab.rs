@@ -0,0 +1,330 @@
+//! In here you'll find the structs for the CIE `L*a*b*` and `LCh` colour-definitions and the ΔE colour-difference metrics.
+use crate::compsci::colours::{rgba::RGBa, wrap_hue, xyz::{XYZ, WhitePoint}};
+
+const EPSILON: f64 = 216.0 / 24389.0;
+const KAPPA:   f64 = 24389.0 / 27.0;
+
+/// A struct for storing CIE L*a*b* values.
+/// \
+/// `l` is the lightness in `[0; 100]`, `a` runs from green to red and `b` from blue to yellow.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lab {
+    /// The lightness.
+    pub l: f64,
+    /// The green-red axis.
+    pub a: f64,
+    /// The blue-yellow axis.
+    pub b: f64,
+}
+
+/// A struct for storing CIE LCh values, the cylindrical form of `Lab`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LCh {
+    /// The lightness.
+    pub l: f64,
+    /// The chroma.
+    pub c: f64,
+    /// The hue in degrees in `[0; 360)`.
+    pub h: f64,
+}
+
+/// The weighting factors of the CIE94 colour difference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cie94Weights {
+    /// `kL = 1`, `K1 = 0.045`, `K2 = 0.015`.
+    GraphicArts,
+    /// `kL = 2`, `K1 = 0.048`, `K2 = 0.014`.
+    Textiles,
+}
+
+impl Lab {
+    /// Create a new `Lab` struct.
+    /// # Arguments
+    /// * `l: f64` - The lightness.
+    /// * `a: f64` - The green-red axis.
+    /// * `b: f64` - The blue-yellow axis.
+    /// # Returns
+    /// A new `Lab` struct.
+    #[inline]
+    #[must_use]
+    pub const fn new(l: f64, a: f64, b: f64) -> Lab {
+        Lab { l, a, b }
+    }
+    /// Create a new `Lab` struct from an existing `RGBa` struct, relative to the D65 white point. The alpha value is ignored.
+    /// # Arguments
+    /// * `rgb: &RGBa` - The `RGBa` struct.
+    /// # Returns
+    /// A new `Lab` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{lab::Lab, rgba::RGBa};
+    ///
+    /// let red = Lab::from_rgba_struct(&RGBa::SOLID_RED);
+    /// assert!((red.l - 53.2408).abs() < 1e-3);
+    /// assert!((red.a - 80.0925).abs() < 1e-3);
+    /// assert!((red.b - 67.2032).abs() < 1e-3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_rgba_struct(rgb: &RGBa) -> Lab {
+        Lab::from_xyz(&XYZ::from_rgba_struct(rgb), WhitePoint::D65)
+    }
+    /// Create a new `Lab` struct from a `XYZ` struct.
+    /// # Arguments
+    /// * `xyz: &XYZ` - The XYZ struct.
+    /// * `white: WhitePoint` - The reference white of `xyz`.
+    /// # Returns
+    /// A new `Lab` struct.
+    #[must_use]
+    pub fn from_xyz(xyz: &XYZ, white: WhitePoint) -> Lab {
+        let w = white.xyz();
+        let f = |t: f64| match t > EPSILON {
+            true  => t.cbrt(),
+            false => (KAPPA * t + 16.0) / 116.0,
+        };
+        let (fx, fy, fz) = (f(xyz.x / w.x), f(xyz.y / w.y), f(xyz.z / w.z));
+
+        Lab { l: 116.0 * fy - 16.0,
+              a: 500.0 * (fx - fy),
+              b: 200.0 * (fy - fz) }
+    }
+    /// Converts `self` into a `XYZ` struct.
+    /// # Arguments
+    /// * `white: WhitePoint` - The reference white.
+    /// # Returns
+    /// A new `XYZ` struct.
+    #[must_use]
+    pub fn to_xyz(&self, white: WhitePoint) -> XYZ {
+        let w  = white.xyz();
+        let fy = (self.l + 16.0) / 116.0;
+        let fx = self.a / 500.0 + fy;
+        let fz = fy - self.b / 200.0;
+        let f_inv = |f: f64| match f.powi(3) > EPSILON {
+            true  => f.powi(3),
+            false => (116.0 * f - 16.0) / KAPPA,
+        };
+        let yr = match self.l > KAPPA * EPSILON {
+            true  => fy.powi(3),
+            false => self.l / KAPPA,
+        };
+
+        XYZ { x: f_inv(fx) * w.x,
+              y: yr * w.y,
+              z: f_inv(fz) * w.z }
+    }
+    /// Create a new `Lab` struct from a `LCh` struct.
+    /// # Arguments
+    /// * `lch: &LCh` - The LCh struct.
+    /// # Returns
+    /// A new `Lab` struct.
+    #[inline]
+    #[must_use]
+    pub fn from_lch_struct(lch: &LCh) -> Lab {
+        let h = lch.h.to_radians();
+        Lab { l: lch.l,
+              a: lch.c * h.cos(),
+              b: lch.c * h.sin() }
+    }
+    /// Computes the CIE76 colour difference, the euclidean distance of `self` and `other`.
+    /// # Arguments
+    /// * `other: &Lab` - The other colour.
+    /// # Returns
+    /// A `f64`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::lab::Lab;
+    ///
+    /// assert_eq!(5.0, Lab::new(50.0, 0.0, 0.0).delta_e_76(&Lab::new(50.0, 3.0, 4.0)));
+    /// ```
+    #[must_use]
+    pub fn delta_e_76(&self, other: &Lab) -> f64 {
+        ((self.l - other.l).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2)).sqrt()
+    }
+    /// Computes the CIE94 colour difference. `self` is the reference colour, so the metric is not symmetric.
+    /// # Arguments
+    /// * `other: &Lab` - The sample colour.
+    /// * `weights: Cie94Weights` - The application-specific weights.
+    /// # Returns
+    /// A `f64`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::lab::{Lab, Cie94Weights};
+    ///
+    /// let a = Lab::new(50.0, 2.6772, -79.7751);
+    /// let b = Lab::new(50.0, 0.0, -82.7485);
+    /// assert!((a.delta_e_94(&b, Cie94Weights::GraphicArts) - 1.3950).abs() < 1e-4);
+    /// // Lightness differences only count half for textiles.
+    /// let grey = Lab::new(40.0, 0.0, 0.0);
+    /// assert_eq!(5.0, grey.delta_e_94(&Lab::new(50.0, 0.0, 0.0), Cie94Weights::Textiles));
+    /// ```
+    #[must_use]
+    pub fn delta_e_94(&self, other: &Lab, weights: Cie94Weights) -> f64 {
+        let (kl, k1, k2) = match weights {
+            Cie94Weights::GraphicArts => { (1.0, 0.045, 0.015) },
+            Cie94Weights::Textiles    => { (2.0, 0.048, 0.014) },
+        };
+        let c1 = self.a.hypot(self.b);
+        let c2 = other.a.hypot(other.b);
+        let dl = self.l - other.l;
+        let dc = c1 - c2;
+        let dh_sq = ((self.a - other.a).powi(2) + (self.b - other.b).powi(2) - dc.powi(2)).max(0.0);
+        let sc = 1.0 + k1 * c1;
+        let sh = 1.0 + k2 * c1;
+
+        ((dl / kl).powi(2) + (dc / sc).powi(2) + dh_sq / sh.powi(2)).sqrt()
+    }
+    /// Computes the CIEDE2000 colour difference with `kL = kC = kH = 1`.
+    /// # Arguments
+    /// * `other: &Lab` - The other colour.
+    /// # Returns
+    /// A `f64`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::lab::Lab;
+    ///
+    /// // Test data by G. Sharma, W. Wu and E. N. Dalal (2005).
+    /// let data = [
+    ///     [50.0000,   2.6772, -79.7751, 50.0000,   0.0000, -82.7485,  2.0425],
+    ///     [50.0000,   3.1571, -77.2803, 50.0000,   0.0000, -82.7485,  2.8615],
+    ///     [50.0000,   2.8361, -74.0200, 50.0000,   0.0000, -82.7485,  3.4412],
+    ///     [50.0000,  -1.3802, -84.2814, 50.0000,   0.0000, -82.7485,  1.0000],
+    ///     [50.0000,  -1.1848, -84.8006, 50.0000,   0.0000, -82.7485,  1.0000],
+    ///     [50.0000,  -0.9009, -85.5211, 50.0000,   0.0000, -82.7485,  1.0000],
+    ///     [50.0000,   0.0000,   0.0000, 50.0000,  -1.0000,   2.0000,  2.3669],
+    ///     [50.0000,  -1.0000,   2.0000, 50.0000,   0.0000,   0.0000,  2.3669],
+    ///     [50.0000,   2.4900,  -0.0010, 50.0000,  -2.4900,   0.0009,  7.1792],
+    ///     [50.0000,   2.4900,  -0.0010, 50.0000,  -2.4900,   0.0010,  7.1792],
+    ///     [50.0000,   2.4900,  -0.0010, 50.0000,  -2.4900,   0.0011,  7.2195],
+    ///     [50.0000,   2.4900,  -0.0010, 50.0000,  -2.4900,   0.0012,  7.2195],
+    ///     [50.0000,  -0.0010,   2.4900, 50.0000,   0.0009,  -2.4900,  4.8045],
+    ///     [50.0000,  -0.0010,   2.4900, 50.0000,   0.0010,  -2.4900,  4.8045],
+    ///     [50.0000,  -0.0010,   2.4900, 50.0000,   0.0011,  -2.4900,  4.7461],
+    ///     [50.0000,   2.5000,   0.0000, 50.0000,   0.0000,  -2.5000,  4.3065],
+    ///     [50.0000,   2.5000,   0.0000, 73.0000,  25.0000, -18.0000, 27.1492],
+    ///     [50.0000,   2.5000,   0.0000, 61.0000,  -5.0000,  29.0000, 22.8977],
+    ///     [50.0000,   2.5000,   0.0000, 56.0000, -27.0000,  -3.0000, 31.9030],
+    ///     [50.0000,   2.5000,   0.0000, 58.0000,  24.0000,  15.0000, 19.4535],
+    ///     [50.0000,   2.5000,   0.0000, 50.0000,   3.1736,   0.5854,  1.0000],
+    ///     [50.0000,   2.5000,   0.0000, 50.0000,   3.2972,   0.0000,  1.0000],
+    ///     [50.0000,   2.5000,   0.0000, 50.0000,   1.8634,   0.5757,  1.0000],
+    ///     [50.0000,   2.5000,   0.0000, 50.0000,   3.2592,   0.3350,  1.0000],
+    ///     [60.2574, -34.0099,  36.2677, 60.4626, -34.1751,  39.4387,  1.2644],
+    ///     [63.0109, -31.0961,  -5.8663, 62.8187, -29.7946,  -4.0864,  1.2630],
+    ///     [61.2901,   3.7196,  -5.3901, 61.4292,   2.2480,  -4.9620,  1.8731],
+    ///     [35.0831, -44.1164,   3.7933, 35.0232, -40.0716,   1.5901,  1.8645],
+    ///     [22.7233,  20.0904, -46.6940, 23.0331,  14.9730, -42.5619,  2.0373],
+    ///     [36.4612,  47.8580,  18.3852, 36.2715,  50.5065,  21.2231,  1.4146],
+    ///     [90.8027,  -2.0831,   1.4410, 91.1528,  -1.6435,   0.0447,  1.4441],
+    ///     [90.9257,  -0.5406,  -0.9208, 88.6381,  -0.8985,  -0.7239,  1.5381],
+    ///     [ 6.7747,  -0.2908,  -2.4247,  5.8714,  -0.0985,  -2.2286,  0.6377],
+    ///     [ 2.0776,   0.0795,  -1.1350,  0.9033,  -0.0636,  -0.5514,  0.9082],
+    /// ];
+    /// for d in data {
+    ///     let (a, b) = (Lab::new(d[0], d[1], d[2]), Lab::new(d[3], d[4], d[5]));
+    ///     assert!((a.delta_e_2000(&b) - d[6]).abs() < 1e-4);
+    ///     assert!((b.delta_e_2000(&a) - d[6]).abs() < 1e-4);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn delta_e_2000(&self, other: &Lab) -> f64 {
+        const POW25_7: f64 = 6_103_515_625.0;
+
+        let c_bar = (self.a.hypot(self.b) + other.a.hypot(other.b)) / 2.0;
+        let g     = 0.5 * (1.0 - (c_bar.powi(7) / (c_bar.powi(7) + POW25_7)).sqrt());
+        let a1    = (1.0 + g) * self.a;
+        let a2    = (1.0 + g) * other.a;
+        let c1    = a1.hypot(self.b);
+        let c2    = a2.hypot(other.b);
+        let hue   = |b: f64, a: f64| match b == 0.0 && a == 0.0 {
+            true  => 0.0,
+            false => wrap_hue(b.atan2(a).to_degrees()),
+        };
+        let h1 = hue(self.b, a1);
+        let h2 = hue(other.b, a2);
+
+        let dl = other.l - self.l;
+        let dc = c2 - c1;
+        let dh = match c1 * c2 == 0.0 {
+            true  => 0.0,
+            false => match h2 - h1 {
+                d if d > 180.0  => d - 360.0,
+                d if d < -180.0 => d + 360.0,
+                d               => d,
+            },
+        };
+        let dh_big = 2.0 * (c1 * c2).sqrt() * (dh / 2.0).to_radians().sin();
+
+        let l_bar  = (self.l + other.l) / 2.0;
+        let c_bar2 = (c1 + c2) / 2.0;
+        let h_bar  = match (c1 * c2 == 0.0, (h1 - h2).abs() <= 180.0, h1 + h2 < 360.0) {
+            (true, _, _)     => h1 + h2,
+            (_, true, _)     => (h1 + h2) / 2.0,
+            (_, false, true) => (h1 + h2 + 360.0) / 2.0,
+            _                => (h1 + h2 - 360.0) / 2.0,
+        };
+        let cos = |deg: f64| deg.to_radians().cos();
+        let t   = 1.0 - 0.17 * cos(h_bar - 30.0)
+                      + 0.24 * cos(2.0 * h_bar)
+                      + 0.32 * cos(3.0 * h_bar + 6.0)
+                      - 0.20 * cos(4.0 * h_bar - 63.0);
+        let d_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+        let rc = 2.0 * (c_bar2.powi(7) / (c_bar2.powi(7) + POW25_7)).sqrt();
+        let sl = 1.0 + 0.015 * (l_bar - 50.0).powi(2) / (20.0 + (l_bar - 50.0).powi(2)).sqrt();
+        let sc = 1.0 + 0.045 * c_bar2;
+        let sh = 1.0 + 0.015 * c_bar2 * t;
+        let rt = -(2.0 * d_theta).to_radians().sin() * rc;
+
+        ((dl / sl).powi(2) + (dc / sc).powi(2) + (dh_big / sh).powi(2) + rt * (dc / sc) * (dh_big / sh)).sqrt()
+    }
+}
+
+impl LCh {
+    /// Create a new `LCh` struct. The hue is wrapped into `[0; 360)`.
+    /// # Arguments
+    /// * `l: f64` - The lightness.
+    /// * `c: f64` - The chroma.
+    /// * `h: f64` - The hue in degrees.
+    /// # Returns
+    /// A new `LCh` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::lab::LCh;
+    ///
+    /// assert_eq!(270.0, LCh::new(50.0, 10.0, -90.0).h);
+    /// assert_eq!(0.0, LCh::new(50.0, 10.0, -1e-15).h);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new(l: f64, c: f64, h: f64) -> LCh {
+        LCh { l, c, h: wrap_hue(h) }
+    }
+    /// Create a new `LCh` struct from a `Lab` struct.
+    /// # Arguments
+    /// * `lab: &Lab` - The Lab struct.
+    /// # Returns
+    /// A new `LCh` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::lab::{Lab, LCh};
+    ///
+    /// let lch = LCh::from_lab_struct(&Lab::new(50.0, 0.0, -20.0));
+    /// assert_eq!((50.0, 20.0, 270.0), (lch.l, lch.c, lch.h));
+    /// let lab = Lab::from_lch_struct(&lch);
+    /// assert!(lab.a.abs() < 1e-12 && (lab.b + 20.0).abs() < 1e-12);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_lab_struct(lab: &Lab) -> LCh {
+        LCh::new(lab.l, lab.a.hypot(lab.b), lab.b.atan2(lab.a).to_degrees())
+    }
+    /// Create a new `LCh` struct from an existing `RGBa` struct, relative to the D65 white point. The alpha value is ignored.
+    /// # Arguments
+    /// * `rgb: &RGBa` - The `RGBa` struct.
+    /// # Returns
+    /// A new `LCh` struct.
+    #[inline]
+    #[must_use]
+    pub fn from_rgba_struct(rgb: &RGBa) -> LCh {
+        LCh::from_lab_struct(&Lab::from_rgba_struct(rgb))
+    }
+}
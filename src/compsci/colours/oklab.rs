@@ -0,0 +1,97 @@
+//! In here you'll find the struct for the `OKLab` colour-definition by Björn Ottosson.
+use crate::compsci::colours::rgba::RGBa;
+
+/// A struct for storing OKLab values, a perceptually uniform colour space.
+/// \
+/// `l` is the lightness in `[0; 1]`, `a` runs from green to red and `b` from blue to yellow.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OKLab {
+    /// The lightness.
+    pub l: f64,
+    /// The green-red axis.
+    pub a: f64,
+    /// The blue-yellow axis.
+    pub b: f64,
+}
+
+impl OKLab {
+    /// Create a new `OKLab` struct.
+    /// # Arguments
+    /// * `l: f64` - The lightness.
+    /// * `a: f64` - The green-red axis.
+    /// * `b: f64` - The blue-yellow axis.
+    /// # Returns
+    /// A new `OKLab` struct.
+    #[inline]
+    #[must_use]
+    pub const fn new(l: f64, a: f64, b: f64) -> OKLab {
+        OKLab { l, a, b }
+    }
+    /// Create a new `OKLab` struct from an existing `RGBa` struct. The alpha value is ignored.
+    /// # Arguments
+    /// * `rgb: &RGBa` - The `RGBa` struct.
+    /// # Returns
+    /// A new `OKLab` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{oklab::OKLab, rgba::RGBa};
+    ///
+    /// let red = OKLab::from_rgba_struct(&RGBa::SOLID_RED);
+    /// assert!((red.l - 0.627955).abs() < 1e-6);
+    /// assert!((red.a - 0.224863).abs() < 1e-6);
+    /// assert!((red.b - 0.125846).abs() < 1e-6);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_rgba_struct(rgb: &RGBa) -> OKLab {
+        let (r, g, b) = rgb.to_linear_rgb();
+        OKLab::from_linear_rgb(r, g, b)
+    }
+    /// Create a new `OKLab` struct from channels in linear light.
+    /// # Arguments
+    /// * `r: f64` - The red part.
+    /// * `g: f64` - The green part.
+    /// * `b: f64` - The blue part.
+    /// # Returns
+    /// A new `OKLab` struct.
+    #[must_use]
+    pub fn from_linear_rgb(r: f64, g: f64, b: f64) -> OKLab {
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+        OKLab { l: 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+                a: 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+                b: 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s }
+    }
+    /// Converts `self` into channels in linear light. Colours outside of the sRGB gamut give values outside of `[0; 1]`.
+    /// # Returns
+    /// A `(f64, f64, f64)` in the order `R, G, B`.
+    #[must_use]
+    pub fn to_linear_rgb(&self) -> (f64, f64, f64) {
+        let l = (self.l + 0.3963377774 * self.a + 0.2158037573 * self.b).powi(3);
+        let m = (self.l - 0.1055613458 * self.a - 0.0638541728 * self.b).powi(3);
+        let s = (self.l - 0.0894841775 * self.a - 1.2914855480 * self.b).powi(3);
+
+        ( 4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+         -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+         -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s)
+    }
+    /// Computes the euclidean distance of `self` and `other`, a simple perceptual colour difference.
+    /// # Arguments
+    /// * `other: &OKLab` - The other colour.
+    /// # Returns
+    /// A `f64`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{oklab::OKLab, rgba::RGBa};
+    ///
+    /// let white = OKLab::from_rgba_struct(&RGBa::SOLID_WHITE);
+    /// let black = OKLab::from_rgba_struct(&RGBa::SOLID_BLACK);
+    /// assert!((white.distance(&black) - 1.0).abs() < 1e-6);
+    /// ```
+    #[must_use]
+    pub fn distance(&self, other: &OKLab) -> f64 {
+        ((self.l - other.l).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2)).sqrt()
+    }
+}
@@ -0,0 +1,296 @@
+//! In here you'll find colour quantization, i.e. reducing pixels to a small palette, and the mapping of pixels onto a palette.
+use crate::{compsci::colours::{lab::Lab, oklab::OKLab, rgba::RGBa},
+            math::general::Averages};
+
+/// The perceptual colour space in which colours are compared and averaged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuantSpace {
+    /// OKLab, the better choice for most images.
+    OKLab,
+    /// CIE L*a*b* relative to D65.
+    Lab,
+}
+
+/// How the quantization error of a pixel is handled when mapping onto a palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dithering {
+    /// Every pixel gets its nearest palette colour.
+    None,
+    /// The error is spread onto the neighbouring pixels, which hides banding.
+    FloydSteinberg,
+}
+
+/// A colour of a palette and the number of pixels it represents.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PaletteEntry {
+    /// The colour.
+    pub colour:     RGBa,
+    /// The number of pixels represented by `colour`.
+    pub population: usize,
+}
+
+impl QuantSpace {
+    /// Converts a colour into a point of `self`. The alpha value is ignored.
+    /// # Arguments
+    /// * `colour: &RGBa` - The colour.
+    /// # Returns
+    /// A `[f64; 3]`.
+    #[must_use]
+    pub fn point(&self, colour: &RGBa) -> [f64; 3] {
+        match self {
+            QuantSpace::OKLab => { let c = OKLab::from_rgba_struct(colour); [c.l, c.a, c.b] },
+            QuantSpace::Lab   => { let c = Lab::from_rgba_struct(colour); [c.l, c.a, c.b] },
+        }
+    }
+    /// Converts a point of `self` into an opaque colour. Points outside of the sRGB gamut are clipped.
+    /// # Arguments
+    /// * `p: [f64; 3]` - The point.
+    /// # Returns
+    /// A `RGBa`.
+    #[must_use]
+    pub fn colour(&self, p: [f64; 3]) -> RGBa {
+        match self {
+            QuantSpace::OKLab => { RGBa::from_oklab_struct(&OKLab::new(p[0], p[1], p[2])) },
+            QuantSpace::Lab   => { RGBa::from_lab_struct(&Lab::new(p[0], p[1], p[2])) },
+        }
+    }
+}
+
+/// Reduces pixels to at most `n` colours with the median cut algorithm.
+/// \
+/// The box with the largest extent is split at the median of its longest axis until there are `n` boxes.
+/// Each box becomes the mean of its pixels. The alpha values are ignored.
+/// # Arguments
+/// * `pixels: &[RGBa]` - The pixels.
+/// * `n: usize` - The maximum size of the palette.
+/// * `space: QuantSpace` - The colour space.
+/// # Returns
+/// A `Vec<PaletteEntry>`, sorted by descending population.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::colours::{palette::{median_cut, QuantSpace}, rgba::RGBa};
+///
+/// let mut pixels = vec![RGBa::SOLID_RED; 60];
+/// pixels.extend(vec![RGBa::SOLID_BLUE; 30]);
+/// pixels.extend(vec![RGBa::new(0, 0, 230, 255); 10]);
+///
+/// let palette = median_cut(&pixels, 2, QuantSpace::OKLab);
+/// assert_eq!(2, palette.len());
+/// assert_eq!((RGBa::SOLID_RED, 60), (palette[0].colour, palette[0].population));
+/// assert_eq!(40, palette[1].population);
+/// ```
+#[must_use]
+pub fn median_cut(pixels: &[RGBa], n: usize, space: QuantSpace) -> Vec<PaletteEntry> {
+    if pixels.is_empty() || n == 0
+    { return Vec::new(); }
+
+    let mut boxes: Vec<Vec<[f64; 3]>> = vec![pixels.iter().map(|c| space.point(c)).collect()];
+    while boxes.len() < n {
+        let widest = boxes.iter()
+                          .enumerate()
+                          .map(|(i, b)| (i, longest_axis(b)))
+                          .filter(|(_, (_, extent))| *extent > 0.0)
+                          .max_by(|a, b| (a.1).1.total_cmp(&(b.1).1));
+        let (i, axis) = match widest {
+            Some((i, (axis, _))) => { (i, axis) },
+            None                 => { break; },
+        };
+        let mut b = boxes.swap_remove(i);
+        b.sort_by(|p, q| p[axis].total_cmp(&q[axis]));
+        // Equal values stay in one box, so the split never separates identical colours.
+        let mut mid = b.len() / 2;
+        while mid < b.len() && b[mid][axis] == b[mid - 1][axis]
+        { mid += 1; }
+        if mid == b.len() {
+            mid = b.len() / 2;
+            while mid > 1 && b[mid - 1][axis] == b[mid][axis]
+            { mid -= 1; }
+        }
+        let upper = b.split_off(mid);
+        boxes.push(b);
+        boxes.push(upper);
+    }
+
+    sorted(boxes.iter()
+                .map(|b| PaletteEntry { colour:     space.colour(mean(b)),
+                                        population: b.len() })
+                .collect())
+}
+
+/// Reduces pixels to at most `n` colours with k-means clustering.
+/// \
+/// The clusters start from the result of `median_cut`, which makes the result deterministic.
+/// Clusters which lose all their pixels are dropped. The alpha values are ignored.
+/// # Arguments
+/// * `pixels: &[RGBa]` - The pixels.
+/// * `n: usize` - The maximum size of the palette.
+/// * `space: QuantSpace` - The colour space.
+/// * `max_iterations: usize` - The maximum number of refinement steps.
+/// # Returns
+/// A `Vec<PaletteEntry>`, sorted by descending population.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::colours::{palette::{k_means, QuantSpace}, rgba::RGBa};
+///
+/// let pixels: Vec<RGBa> = (0..100u8).map(|i| match i % 2 {
+///                                        0 => RGBa::new(250 - i / 10, 10, 10, 255),
+///                                        _ => RGBa::new(10, 10, 250 - i / 10, 255),
+///                                    })
+///                                    .collect();
+/// let palette = k_means(&pixels, 2, QuantSpace::Lab, 10);
+///
+/// assert_eq!(vec![50, 50], palette.iter().map(|e| e.population).collect::<Vec<_>>());
+/// assert!(palette.iter().any(|e| e.colour.red > 200) && palette.iter().any(|e| e.colour.blue > 200));
+/// ```
+#[must_use]
+pub fn k_means(pixels: &[RGBa], n: usize, space: QuantSpace, max_iterations: usize) -> Vec<PaletteEntry> {
+    let points: Vec<[f64; 3]> = pixels.iter().map(|c| space.point(c)).collect();
+    let mut centres: Vec<[f64; 3]> = median_cut(pixels, n, space).iter()
+                                                                 .map(|e| space.point(&e.colour))
+                                                                 .collect();
+    let mut assignment = vec![usize::MAX; points.len()];
+    for _ in 0..max_iterations {
+        let mut changed = false;
+        for (p, a) in points.iter().zip(assignment.iter_mut()) {
+            let i = nearest_point(&centres, p);
+            if *a != i
+            { *a = i; changed = true; }
+        }
+        if !changed
+        { break; }
+
+        let mut clusters: Vec<Vec<[f64; 3]>> = vec![Vec::new(); centres.len()];
+        for (p, a) in points.iter().zip(assignment.iter()) {
+            clusters[*a].push(*p);
+        }
+        let kept = clusters.iter()
+                           .filter(|c| !c.is_empty())
+                           .map(|c| mean(c))
+                           .collect::<Vec<[f64; 3]>>();
+        // Dropping empty clusters shifts the indices, so the old assignment must not count as unchanged.
+        if kept.len() != centres.len()
+        { assignment.fill(usize::MAX); }
+        centres = kept;
+    }
+
+    let mut populations = vec![0; centres.len()];
+    for p in &points {
+        populations[nearest_point(&centres, p)] += 1;
+    }
+    sorted(centres.iter()
+                  .zip(populations)
+                  .filter(|(_, n)| *n > 0)
+                  .map(|(c, population)| PaletteEntry { colour: space.colour(*c), population })
+                  .collect())
+}
+
+/// Finds the palette colour nearest to a colour.
+/// # Arguments
+/// * `palette: &[RGBa]` - The palette.
+/// * `colour: &RGBa` - The colour.
+/// * `space: QuantSpace` - The colour space in which distances are measured.
+/// # Returns
+/// An `Option<usize>`, the index in `palette`. `None` if the palette is empty.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::colours::{palette::{nearest, QuantSpace}, rgba::RGBa};
+///
+/// let palette = [RGBa::SOLID_BLACK, RGBa::SOLID_WHITE, RGBa::SOLID_RED];
+/// assert_eq!(Some(2), nearest(&palette, &RGBa::new(200, 40, 30, 255), QuantSpace::OKLab));
+/// ```
+#[must_use]
+pub fn nearest(palette: &[RGBa], colour: &RGBa, space: QuantSpace) -> Option<usize> {
+    if palette.is_empty()
+    { return None; }
+
+    let points: Vec<[f64; 3]> = palette.iter().map(|c| space.point(c)).collect();
+    Some(nearest_point(&points, &space.point(colour)))
+}
+
+/// Maps the pixels of an image onto a palette.
+/// # Arguments
+/// * `pixels: &[RGBa]` - The pixels, row by row.
+/// * `width: usize` - The width of the image, which is needed for dithering.
+/// * `palette: &[RGBa]` - The palette.
+/// * `space: QuantSpace` - The colour space in which distances are measured and errors are spread.
+/// * `dithering: Dithering` - The dithering.
+/// # Returns
+/// A `Vec<usize>` with the palette index of every pixel.
+/// # Panics
+/// Panics if `palette` is empty or `width` is `0`.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::colours::{palette::{map_to_palette, QuantSpace, Dithering}, rgba::RGBa};
+///
+/// let grey    = vec![RGBa::new(80, 80, 80, 255); 64];
+/// let palette = [RGBa::SOLID_BLACK, RGBa::SOLID_WHITE];
+///
+/// // Without dithering, everything becomes black.
+/// assert!(map_to_palette(&grey, 8, &palette, QuantSpace::OKLab, Dithering::None).iter().all(|i| *i == 0));
+/// // With dithering, the pattern has about the same lightness as the grey.
+/// let dithered = map_to_palette(&grey, 8, &palette, QuantSpace::OKLab, Dithering::FloydSteinberg);
+/// let whites   = dithered.iter().filter(|i| **i == 1).count();
+/// assert!((20..36).contains(&whites));
+/// ```
+#[must_use]
+pub fn map_to_palette(pixels: &[RGBa], width: usize, palette: &[RGBa], space: QuantSpace, dithering: Dithering) -> Vec<usize> {
+    if palette.is_empty() || width == 0
+    { panic!("The palette must not be empty and the width must be greater than 0."); }
+
+    let entries: Vec<[f64; 3]> = palette.iter().map(|c| space.point(c)).collect();
+    let mut points: Vec<[f64; 3]> = pixels.iter().map(|c| space.point(c)).collect();
+    let mut res = Vec::with_capacity(points.len());
+    for i in 0..points.len() {
+        let idx = nearest_point(&entries, &points[i]);
+        res.push(idx);
+        if dithering == Dithering::None
+        { continue; }
+
+        let err: [f64; 3] = [0, 1, 2].map(|k| points[i][k] - entries[idx][k]);
+        let (x, y) = (i % width, i / width);
+        let mut spread = |dx: isize, dy: usize, weight: f64| {
+            let nx = x as isize + dx;
+            if nx < 0 || nx as usize >= width
+            { return; }
+            let j = (y + dy) * width + nx as usize;
+            if let Some(p) = points.get_mut(j) {
+                for k in 0..3 {
+                    p[k] += err[k] * weight;
+                }
+            }
+        };
+        spread( 1, 0, 7.0 / 16.0);
+        spread(-1, 1, 3.0 / 16.0);
+        spread( 0, 1, 5.0 / 16.0);
+        spread( 1, 1, 1.0 / 16.0);
+    }
+    res
+}
+
+/// Gets the axis with the largest extent and that extent.
+fn longest_axis(points: &[[f64; 3]]) -> (usize, f64) {
+    (0..3).map(|k| {
+              let (lo, hi) = points.iter().fold((f64::INFINITY, f64::NEG_INFINITY),
+                                                |(lo, hi), p| (lo.min(p[k]), hi.max(p[k])));
+              (k, hi - lo)
+          })
+          .max_by(|a, b| a.1.total_cmp(&b.1))
+          .unwrap_or((0, 0.0))
+}
+
+fn mean(points: &[[f64; 3]]) -> [f64; 3] {
+    [0, 1, 2].map(|k| points.iter().map(|p| p[k]).collect::<Vec<f64>>().arithmetic_mean())
+}
+
+fn nearest_point(points: &[[f64; 3]], p: &[f64; 3]) -> usize {
+    let dist = |q: &[f64; 3]| (0..3).map(|k| (p[k] - q[k]).powi(2)).sum::<f64>();
+    points.iter()
+          .enumerate()
+          .min_by(|a, b| dist(a.1).total_cmp(&dist(b.1)))
+          .map_or(0, |(i, _)| i)
+}
+
+fn sorted(mut entries: Vec<PaletteEntry>) -> Vec<PaletteEntry> {
+    entries.sort_by_key(|e| std::cmp::Reverse(e.population));
+    entries
+}
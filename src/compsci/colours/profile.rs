@@ -0,0 +1,390 @@
+//! In here you'll find a simple print profile for converting between `RGBa` and `CMYK` with black generation, ink limits and rendering intents.
+use std::str::FromStr;
+use crate::compsci::colours::{cmyk::CMYK, css::parse_css, rgba::RGBa};
+
+/// How much of the grey component of a colour is printed with black ink instead of cyan, magenta and yellow.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlackGeneration {
+    /// No black ink is used at all.
+    None,
+    /// Undercolour removal: black only replaces the grey in the shadows, starting at a grey level in `[0; 1)`.
+    Ucr(f32),
+    /// Grey-component replacement: the given share in `[0; 1]` of the grey is replaced everywhere.
+    Gcr(f32),
+}
+
+/// How colours are treated which the print process cannot reproduce exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderingIntent {
+    /// The ink coverage of all colours is compressed smoothly, which keeps the gradation in the shadows.
+    Perceptual,
+    /// Colours within the ink limit are kept and the others are clipped. White is the paper white.
+    RelativeColorimetric,
+    /// Like `RelativeColorimetric`, but the colour of the paper is taken into account.
+    AbsoluteColorimetric,
+    /// Black ink is reduced first when clipping, which keeps colours as vivid as possible.
+    Saturation,
+}
+
+/// A simple description of a print process.
+/// \
+/// The conversion subtracts the colour from the paper white, corrects the inks with `matrix`,
+/// generates black, applies the ink limits and finally adjusts every channel with its tone curve.
+/// \
+/// A profile can be read from a text file of `key = value` lines. A `#` at the start of a line or followed by a space starts a comment:
+/// ```text
+/// name             = Uncoated
+/// paper_white      = #f5f2e8
+/// black_generation = gcr 60%
+/// ink_limit        = 260%
+/// black_limit      = 95%
+/// # Cross-contamination of the inks, row by row for C, M and Y.
+/// matrix           = 1 0 0  0.05 1 0  0 0.1 1
+/// # Dot gain compensation, evenly spaced samples of the tone curve.
+/// curve_k          = 0 0.2 0.45 0.7 1
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CmykProfile {
+    /// The name of the profile.
+    pub name:             String,
+    /// The colour of the unprinted paper.
+    pub paper_white:      RGBa,
+    /// The black generation.
+    pub black_generation: BlackGeneration,
+    /// The maximum sum of all four inks in `[0; 4]`.
+    pub ink_limit:        f32,
+    /// The maximum amount of black ink in `[0; 1]`.
+    pub black_limit:      f32,
+    /// The correction applied to the cyan, magenta and yellow amounts, row by row.
+    pub matrix:           [[f32; 3]; 3],
+    /// The tone curves for C, M, Y and K as evenly spaced samples in `[0; 1]`. An empty curve is the identity.
+    pub curves:           [Vec<f32>; 4],
+}
+
+/// An error while reading a profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileError {
+    /// The line number, starting at 1. `0` if the file could not be read.
+    pub line:   usize,
+    /// What went wrong.
+    pub reason: String,
+}
+
+impl std::fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+impl std::error::Error for ProfileError { }
+
+const IDENTITY: [[f32; 3]; 3] = [[1.0, 0.0, 0.0],
+                                 [0.0, 1.0, 0.0],
+                                 [0.0, 0.0, 1.0]];
+
+/// A neutral profile on white paper, with full grey-component replacement and no ink limits.
+impl Default for CmykProfile {
+    fn default() -> Self {
+        CmykProfile { name:             String::new(),
+                      paper_white:      RGBa::SOLID_WHITE,
+                      black_generation: BlackGeneration::Gcr(1.0),
+                      ink_limit:        4.0,
+                      black_limit:      1.0,
+                      matrix:           IDENTITY,
+                      curves:           Default::default() }
+    }
+}
+
+impl CmykProfile {
+    /// Reads a profile from a file. See `CmykProfile` for the format.
+    /// # Arguments
+    /// * `path: &str` - The path of the file.
+    /// # Returns
+    /// A `Result<CmykProfile, ProfileError>`.
+    pub fn open(path: &str) -> Result<CmykProfile, ProfileError> {
+        std::fs::read_to_string(path).map_err(|e| ProfileError { line:   0,
+                                                                  reason: format!("Problem reading file: {:?}", e) })?
+                                     .parse()
+    }
+    /// Converts a colour into ink amounts. The alpha value is ignored.
+    /// # Arguments
+    /// * `rgb: &RGBa` - The colour.
+    /// * `intent: RenderingIntent` - The rendering intent.
+    /// # Returns
+    /// A new `CMYK` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{profile::{CmykProfile, BlackGeneration, RenderingIntent}, rgba::RGBa};
+    ///
+    /// let profile = CmykProfile { black_generation: BlackGeneration::Gcr(0.5),
+    ///                             ink_limit:        1.8,
+    ///                             ..CmykProfile::default() };
+    /// let black   = profile.to_cmyk(&RGBa::SOLID_BLACK, RenderingIntent::RelativeColorimetric);
+    /// let total   = black.cyan() + black.magenta() + black.yellow() + black.black();
+    ///
+    /// assert_eq!(0.5, black.black());
+    /// assert!((total - 1.8).abs() < 1e-6);
+    /// // Colours within the limit are not touched.
+    /// let grey = profile.to_cmyk(&RGBa::new(204, 204, 204, 255), RenderingIntent::RelativeColorimetric);
+    /// assert!((grey.cyan() - 0.1).abs() < 1e-6 && (grey.black() - 0.1).abs() < 1e-6);
+    ///
+    /// // The intents differ in how they get black within the limit.
+    /// let profile = CmykProfile { black_generation: BlackGeneration::Gcr(0.25),
+    ///                             ink_limit:        2.4,
+    ///                             ..CmykProfile::default() };
+    /// let relative   = profile.to_cmyk(&RGBa::SOLID_BLACK, RenderingIntent::RelativeColorimetric);
+    /// let saturation = profile.to_cmyk(&RGBa::SOLID_BLACK, RenderingIntent::Saturation);
+    /// assert_eq!(0.25, relative.black());
+    /// assert!((saturation.black() - 0.15).abs() < 1e-6 && saturation.cyan() == 0.75);
+    /// // Perceptual also lightens the dark colours near the limit, so they stay distinguishable.
+    /// let dark       = RGBa::new(20, 20, 20, 255);
+    /// let perceptual = profile.to_cmyk(&dark, RenderingIntent::Perceptual);
+    /// assert!(perceptual.black() < profile.to_cmyk(&dark, RenderingIntent::RelativeColorimetric).black());
+    /// ```
+    #[must_use]
+    pub fn to_cmyk(&self, rgb: &RGBa, intent: RenderingIntent) -> CMYK {
+        let [c, m, y, k] = self.separate(rgb, intent);
+        CMYK::new(self.apply_curve(0, c),
+                  self.apply_curve(1, m),
+                  self.apply_curve(2, y),
+                  self.apply_curve(3, k))
+    }
+    /// Simulates the printed colour of ink amounts, e.g. for a proof on screen.
+    /// \
+    /// With `RenderingIntent::AbsoluteColorimetric` the colour of the paper shows, otherwise the paper is displayed as white.
+    /// # Arguments
+    /// * `cmyk: &CMYK` - The ink amounts.
+    /// * `intent: RenderingIntent` - The rendering intent.
+    /// # Returns
+    /// A new `RGBa` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{profile::{CmykProfile, RenderingIntent}, rgba::RGBa};
+    ///
+    /// let profile = CmykProfile { paper_white: RGBa::new(240, 235, 220, 255), ..CmykProfile::default() };
+    /// let blue    = RGBa::new(40, 90, 200, 255);
+    /// let ink     = profile.to_cmyk(&blue, RenderingIntent::RelativeColorimetric);
+    ///
+    /// assert_eq!(blue, profile.to_rgba(&ink, RenderingIntent::RelativeColorimetric));
+    /// assert_eq!(RGBa::new(240, 235, 220, 255),
+    ///            profile.to_rgba(&profile.to_cmyk(&RGBa::SOLID_WHITE, RenderingIntent::AbsoluteColorimetric),
+    ///                            RenderingIntent::AbsoluteColorimetric));
+    /// ```
+    #[must_use]
+    pub fn to_rgba(&self, cmyk: &CMYK, intent: RenderingIntent) -> RGBa {
+        let k   = self.invert_curve(3, cmyk.black());
+        let ink = [self.invert_curve(0, cmyk.cyan()),
+                   self.invert_curve(1, cmyk.magenta()),
+                   self.invert_curve(2, cmyk.yellow())];
+        let ink = mul(&invert(&self.matrix), ink);
+        let white = self.white(intent);
+
+        let ch = |i: usize| (((1.0 - (ink[i] + k).clamp(0.0, 1.0)) * white[i]) * 255.0).round() as u8;
+        RGBa::new(ch(0), ch(1), ch(2), 255)
+    }
+
+    /// Gets the paper white as seen by `intent`.
+    fn white(&self, intent: RenderingIntent) -> [f32; 3] {
+        match intent {
+            RenderingIntent::AbsoluteColorimetric => {
+                [self.paper_white.red as f32 / 255.0,
+                 self.paper_white.green as f32 / 255.0,
+                 self.paper_white.blue as f32 / 255.0]
+            },
+            _                                     => { [1.0; 3] },
+        }
+    }
+    /// Computes the ink amounts before the tone curves.
+    fn separate(&self, rgb: &RGBa, intent: RenderingIntent) -> [f32; 4] {
+        let [c, m, y, k] = self.generate_black(rgb, intent);
+        let limit = self.ink_limit.clamp(0.0, 4.0);
+        let total = c + m + y + k;
+        match intent {
+            RenderingIntent::Perceptual => {
+                // Everything above the knee is compressed, so that the darkest colour just reaches the limit.
+                let knee = 0.8 * limit;
+                let max: f32 = self.generate_black(&RGBa::SOLID_BLACK, intent).iter().sum();
+                if total <= knee || max <= limit
+                { return clip([c, m, y, k], limit); }
+
+                let s = (knee + (limit - knee) * (total - knee) / (max - knee)) / total;
+                clip([c * s, m * s, y * s, k * s], limit)
+            },
+            RenderingIntent::Saturation if total > limit => {
+                let k = k.min((limit - (c + m + y)).max(0.0));
+                clip([c, m, y, k], limit)
+            },
+            _ => { clip([c, m, y, k], limit) },
+        }
+    }
+    /// Computes the ink amounts before the ink limit is applied.
+    fn generate_black(&self, rgb: &RGBa, intent: RenderingIntent) -> [f32; 4] {
+        let white = self.white(intent);
+        let src   = [rgb.red as f32 / 255.0, rgb.green as f32 / 255.0, rgb.blue as f32 / 255.0];
+        let cmy   = [0, 1, 2].map(|i| 1.0 - (src[i] / white[i].max(f32::EPSILON)).min(1.0));
+        let cmy   = mul(&self.matrix, cmy).map(|v| v.clamp(0.0, 1.0));
+
+        let grey = cmy[0].min(cmy[1]).min(cmy[2]);
+        let k    = match self.black_generation {
+            BlackGeneration::None   => { 0.0 },
+            BlackGeneration::Ucr(t) => { ((grey - t) / (1.0 - t)).max(0.0) },
+            BlackGeneration::Gcr(a) => { a * grey },
+        }.min(self.black_limit).min(grey);
+        let [c, m, y] = cmy.map(|v| v - k);
+        [c, m, y, k]
+    }
+    /// Applies the tone curve of a channel.
+    fn apply_curve(&self, channel: usize, x: f32) -> f32 {
+        let curve = &self.curves[channel];
+        match curve.len() {
+            0 => { x },
+            1 => { curve[0].clamp(0.0, 1.0) },
+            n => {
+                let pos = x.clamp(0.0, 1.0) * (n - 1) as f32;
+                let i   = (pos as usize).min(n - 2);
+                (curve[i] + (curve[i + 1] - curve[i]) * (pos - i as f32)).clamp(0.0, 1.0)
+            },
+        }
+    }
+    /// Inverts the tone curve of a channel, which must be monotonically increasing.
+    fn invert_curve(&self, channel: usize, y: f32) -> f32 {
+        if self.curves[channel].len() < 2
+        { return y; }
+
+        let (mut lo, mut hi) = (0.0_f32, 1.0_f32);
+        for _ in 0..32 {
+            let mid = (lo + hi) / 2.0;
+            match self.apply_curve(channel, mid) < y {
+                true  => { lo = mid; },
+                false => { hi = mid; },
+            }
+        }
+        (lo + hi) / 2.0
+    }
+}
+
+/// Scales cyan, magenta and yellow down until the total ink stays within the limit.
+fn clip([c, m, y, k]: [f32; 4], limit: f32) -> [f32; 4] {
+    let k   = k.min(limit);
+    let cmy = c + m + y;
+    if cmy + k <= limit
+    { return [c, m, y, k]; }
+
+    let s = (limit - k) / cmy;
+    [c * s, m * s, y * s, k]
+}
+
+fn mul(m: &[[f32; 3]; 3], v: [f32; 3]) -> [f32; 3] {
+    [0, 1, 2].map(|i| m[i][0] * v[0] + m[i][1] * v[1] + m[i][2] * v[2])
+}
+
+/// Inverts a 3x3 matrix. A singular matrix gives the identity.
+fn invert(m: &[[f32; 3]; 3]) -> [[f32; 3]; 3] {
+    let cof = |r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+    let det = m[0][0] * cof(1, 2, 1, 2) - m[0][1] * cof(1, 2, 0, 2) + m[0][2] * cof(1, 2, 0, 1);
+    if det.abs() < f32::EPSILON
+    { return IDENTITY; }
+
+    [[ cof(1, 2, 1, 2) / det, -cof(0, 2, 1, 2) / det,  cof(0, 1, 1, 2) / det],
+     [-cof(1, 2, 0, 2) / det,  cof(0, 2, 0, 2) / det, -cof(0, 1, 0, 2) / det],
+     [ cof(1, 2, 0, 1) / det, -cof(0, 2, 0, 1) / det,  cof(0, 1, 0, 1) / det]]
+}
+
+/// Parses a fraction, either plain or as percentage.
+fn parse_amount(s: &str) -> Result<f32, String> {
+    let (num, scale) = match s.strip_suffix('%') {
+        Some(n) => { (n, 100.0) },
+        None    => { (s, 1.0) },
+    };
+    match num.trim().parse::<f32>() {
+        Ok(v) if v.is_finite() && v >= 0.0 => { Ok(v / scale) },
+        _                                  => { Err(format!("Invalid amount: {}", s)) },
+    }
+}
+
+/// Removes a comment, but keeps hex colours like `#f0ebdc`.
+fn strip_comment(line: &str) -> &str {
+    let bytes = line.as_bytes();
+    let start = line.len() - line.trim_start().len();
+    match (start..bytes.len()).find(|&i| bytes[i] == b'#' &&
+                                         (i == start || bytes.get(i + 1).is_none_or(u8::is_ascii_whitespace))) {
+        Some(i) => { &line[..i] },
+        None    => { line },
+    }
+}
+
+fn parse_list(s: &str) -> Result<Vec<f32>, String> {
+    s.split_whitespace()
+     .map(parse_amount)
+     .collect()
+}
+
+/// Parses a profile from the text format described at `CmykProfile`. Missing keys keep their default value.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::colours::{profile::{CmykProfile, BlackGeneration}, rgba::RGBa};
+///
+/// let profile: CmykProfile = "name = Newsprint\n\
+///                             paper_white = #f0ebdc\n\
+///                             black_generation = ucr 50%\n\
+///                             ink_limit = 240% # typical for newsprint\n\
+///                             curve_k = 0 0.6 1".parse().unwrap();
+///
+/// assert_eq!("Newsprint", profile.name);
+/// assert_eq!(RGBa::new(240, 235, 220, 255), profile.paper_white);
+/// assert_eq!(BlackGeneration::Ucr(0.5), profile.black_generation);
+/// assert_eq!(2.4, profile.ink_limit);
+/// assert_eq!(vec![0.0, 0.6, 1.0], profile.curves[3]);
+///
+/// let err = "ink_limit = lots".parse::<CmykProfile>().unwrap_err();
+/// assert_eq!(1, err.line);
+/// ```
+impl FromStr for CmykProfile {
+    type Err = ProfileError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut res = CmykProfile::default();
+        for (i, line) in s.lines().enumerate() {
+            let line = strip_comment(line);
+            if line.trim().is_empty()
+            { continue; }
+
+            let err = |reason: String| ProfileError { line: i + 1, reason };
+            let (key, value) = line.split_once('=')
+                                   .ok_or_else(|| err(format!("Expected key = value: {}", line.trim())))?;
+            let value = value.trim();
+            match key.trim() {
+                "name"             => { res.name = value.to_owned(); },
+                "paper_white"      => { res.paper_white = parse_css(value).map_err(|e| err(e.to_string()))?; },
+                "ink_limit"        => { res.ink_limit = parse_amount(value).map_err(err)?; },
+                "black_limit"      => { res.black_limit = parse_amount(value).map_err(err)?; },
+                "black_generation" => {
+                    let mut parts = value.split_whitespace();
+                    let kind      = parts.next().unwrap_or("").to_ascii_lowercase();
+                    let amount    = parts.next().map(parse_amount).transpose().map_err(err)?;
+                    res.black_generation = match (kind.as_str(), amount) {
+                        ("none", None)   => { BlackGeneration::None },
+                        ("ucr", Some(a)) => { BlackGeneration::Ucr(a.min(0.999)) },
+                        ("gcr", Some(a)) => { BlackGeneration::Gcr(a.min(1.0)) },
+                        _                => { return Err(err(format!("Invalid black generation: {}", value))); },
+                    };
+                },
+                "matrix"           => {
+                    let v: Vec<f32> = value.split_whitespace()
+                                           .map(|x| x.parse::<f32>().map_err(|_| err(format!("Invalid number: {}", x))))
+                                           .collect::<Result<_, _>>()?;
+                    if v.len() != 9
+                    { return Err(err(format!("Expected 9 matrix entries, found {}.", v.len()))); }
+
+                    res.matrix = [[v[0], v[1], v[2]], [v[3], v[4], v[5]], [v[6], v[7], v[8]]];
+                },
+                k @ ("curve_c" | "curve_m" | "curve_y" | "curve_k") => {
+                    let i = "cmyk".find(&k[6..]).unwrap_or(0);
+                    res.curves[i] = parse_list(value).map_err(err)?;
+                },
+                k                  => { return Err(err(format!("Unknown key: {}", k))); },
+            }
+        }
+        Ok(res)
+    }
+}
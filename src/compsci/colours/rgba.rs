@@ -1,5 +1,8 @@
 //! In here you'll find the struct for the classic `RGB` colour-definition and the extended `RGBa`-definition.
-use crate::{compsci::colours::cmyk::CMYK, math::general::Averages};
+use std::str::FromStr;
+use crate::{compsci::colours::{blend::{BlendMode, CompositeOp}, cmyk::CMYK, css::{parse_css, ColourParseError, NAMED_COLOURS}, hsl::HSL, hsv::HSV, hwb::HWB, xyz::XYZ, lab::Lab, oklab::OKLab,
+                               rgb_from_hue, srgb_to_linear, linear_to_srgb},
+            math::{general::Averages, unit_conversion::trim_number}};
 /// A struct for storing RGBa-Values.
 #[derive(Clone, Copy, PartialEq)]
 pub struct RGBa {
@@ -41,14 +44,17 @@ impl RGBa {
     /// # Returns
     /// A new `RGBa` struct.
     /// ```
+    /// #![allow(deprecated)]
     /// use lib_rapid::compsci::colours::rgba::RGBa;
     /// 
     /// let c_1 = RGBa::from_str("ffffffff");
     /// let c_2 = RGBa::new(255, 255, 255, 255);
     /// assert_eq!(c_1, c_2);
+    /// assert_eq!(Ok(c_2), "ffffffff".parse());
     /// ```
     #[inline]
     #[must_use]
+    #[deprecated(note = "Panics on invalid input and shadows `FromStr`; use `str::parse` or `css::parse_css` instead.")]
     pub fn from_str(s: &str) -> RGBa {
         if s.len() != 8
         { panic!("String did not have the required length of 8."); }
@@ -65,7 +71,7 @@ impl RGBa {
                blue:  vals[2],
                alpha: vals[3] }
     }
-    /// Create a new `RGBa` struct.
+    /// Create a new `RGBa` struct with the naive formula. For a proof of a print, use `profile::CmykProfile::to_rgba`.
     /// # Arguments
     /// * `cmyk: &CMYK` - The CMYK struct.
     /// # Returns
@@ -88,6 +94,215 @@ impl RGBa {
                blue:  (255.0 * (1.0 - cmyk.yellow()) * (1.0 - cmyk.black())).round() as u8,
                alpha: 255 }
     }
+    /// Create a new opaque `RGBa` struct from a `HSL` struct.
+    /// # Arguments
+    /// * `hsl: &HSL` - The HSL struct.
+    /// # Returns
+    /// A new `RGBa` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{hsl::HSL, rgba::RGBa};
+    /// 
+    /// let nice_blue_hsl  = HSL::new(210.0, 1.0, 0.75);
+    /// let nice_blue_rgba = RGBa::new(128, 191, 255, 255);
+    /// 
+    /// assert_eq!(RGBa::from_hsl_struct(&nice_blue_hsl),
+    ///            nice_blue_rgba);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_hsl_struct(hsl: &HSL) -> RGBa {
+        let c: f32 = (1.0 - (2.0 * hsl.lightness() - 1.0).abs()) * hsl.saturation();
+        RGBa::from_unit_rgb(rgb_from_hue(hsl.hue(), c, hsl.lightness() - c / 2.0))
+    }
+    /// Create a new opaque `RGBa` struct from a `HSV` struct.
+    /// # Arguments
+    /// * `hsv: &HSV` - The HSV struct.
+    /// # Returns
+    /// A new `RGBa` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{hsv::HSV, rgba::RGBa};
+    /// 
+    /// let nice_blue_hsv  = HSV::new(210.0, 0.5, 1.0);
+    /// let nice_blue_rgba = RGBa::new(128, 191, 255, 255);
+    /// 
+    /// assert_eq!(RGBa::from_hsv_struct(&nice_blue_hsv),
+    ///            nice_blue_rgba);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_hsv_struct(hsv: &HSV) -> RGBa {
+        let c: f32 = hsv.value() * hsv.saturation();
+        RGBa::from_unit_rgb(rgb_from_hue(hsv.hue(), c, hsv.value() - c))
+    }
+    /// Create a new opaque `RGBa` struct from a `HWB` struct.
+    /// If whiteness and blackness add up to more than 1, they are scaled down, which results in a grey.
+    /// # Arguments
+    /// * `hwb: &HWB` - The HWB struct.
+    /// # Returns
+    /// A new `RGBa` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{hwb::HWB, rgba::RGBa};
+    /// 
+    /// let nice_blue_hwb  = HWB::new(210.0, 0.5, 0.0);
+    /// let nice_blue_rgba = RGBa::new(128, 191, 255, 255);
+    /// 
+    /// assert_eq!(RGBa::from_hwb_struct(&nice_blue_hwb),
+    ///            nice_blue_rgba);
+    /// assert_eq!(RGBa::from_hwb_struct(&HWB::new(0.0, 1.0, 1.0)),
+    ///            RGBa::new(128, 128, 128, 255));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_hwb_struct(hwb: &HWB) -> RGBa {
+        let (mut w, mut b) = (hwb.whiteness(), hwb.blackness());
+        if w + b > 1.0 {
+            let sum = w + b;
+            w /= sum;
+            b /= sum;
+        }
+        RGBa::from_unit_rgb(rgb_from_hue(hwb.hue(), 1.0 - b - w, w))
+    }
+
+    /// Create a new opaque `RGBa` struct from a `XYZ` struct, relative to the D65 white point.
+    /// Colours outside of the sRGB gamut are clipped.
+    /// # Arguments
+    /// * `xyz: &XYZ` - The XYZ struct.
+    /// # Returns
+    /// A new `RGBa` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{xyz::XYZ, rgba::RGBa};
+    ///
+    /// let nice_blue = RGBa::new(128, 191, 255, 255);
+    /// assert_eq!(nice_blue, RGBa::from_xyz_struct(&XYZ::from_rgba_struct(&nice_blue)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_xyz_struct(xyz: &XYZ) -> RGBa {
+        RGBa::from_linear_rgb( 3.2404542 * xyz.x - 1.5371385 * xyz.y - 0.4985314 * xyz.z,
+                              -0.9692660 * xyz.x + 1.8760108 * xyz.y + 0.0415560 * xyz.z,
+                               0.0556434 * xyz.x - 0.2040259 * xyz.y + 1.0572252 * xyz.z,
+                               255)
+    }
+    /// Create a new opaque `RGBa` struct from a `Lab` struct, relative to the D65 white point.
+    /// Colours outside of the sRGB gamut are clipped.
+    /// # Arguments
+    /// * `lab: &Lab` - The Lab struct.
+    /// # Returns
+    /// A new `RGBa` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{lab::Lab, rgba::RGBa};
+    ///
+    /// assert_eq!(RGBa::SOLID_RED, RGBa::from_lab_struct(&Lab::new(53.2408, 80.0925, 67.2032)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_lab_struct(lab: &Lab) -> RGBa {
+        RGBa::from_xyz_struct(&lab.to_xyz(crate::compsci::colours::xyz::WhitePoint::D65))
+    }
+    /// Create a new opaque `RGBa` struct from an `OKLab` struct.
+    /// Colours outside of the sRGB gamut are clipped.
+    /// # Arguments
+    /// * `lab: &OKLab` - The OKLab struct.
+    /// # Returns
+    /// A new `RGBa` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{oklab::OKLab, rgba::RGBa};
+    ///
+    /// let nice_blue = RGBa::new(128, 191, 255, 255);
+    /// assert_eq!(nice_blue, RGBa::from_oklab_struct(&OKLab::from_rgba_struct(&nice_blue)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_oklab_struct(lab: &OKLab) -> RGBa {
+        let (r, g, b) = lab.to_linear_rgb();
+        RGBa::from_linear_rgb(r, g, b, 255)
+    }
+    /// Gets the channels of `self` in linear light, i.e. with the sRGB gamma curve removed.
+    /// # Returns
+    /// A `(f64, f64, f64)` with values in `[0; 1]`, in the order `R, G, B`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::rgba::RGBa;
+    ///
+    /// assert_eq!((1.0, 0.0, 1.0), RGBa::new(255, 0, 255, 255).to_linear_rgb());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_linear_rgb(&self) -> (f64, f64, f64) {
+        (srgb_to_linear(self.red as f64 / 255.0),
+         srgb_to_linear(self.green as f64 / 255.0),
+         srgb_to_linear(self.blue as f64 / 255.0))
+    }
+    /// Create a new `RGBa` struct from channels in linear light. Values outside of `[0; 1]` are clipped.
+    /// # Arguments
+    /// * `red: f64` - The red part.
+    /// * `green: f64` - The green part.
+    /// * `blue: f64` - The blue part.
+    /// * `alpha: u8` - The alpha part.
+    /// # Returns
+    /// A new `RGBa` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::rgba::RGBa;
+    ///
+    /// assert_eq!(RGBa::new(188, 188, 188, 255), RGBa::from_linear_rgb(0.5, 0.5, 0.5, 255));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_linear_rgb(red: f64, green: f64, blue: f64, alpha: u8) -> RGBa {
+        let enc = |c: f64| (linear_to_srgb(c.clamp(0.0, 1.0)) * 255.0).round() as u8;
+        RGBa { red:   enc(red),
+               green: enc(green),
+               blue:  enc(blue),
+               alpha }
+    }
+
+    /// Gets the relative luminance of `self` as defined by WCAG 2.x. The alpha value is ignored.
+    /// # Returns
+    /// A `f64` in `[0; 1]`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::rgba::RGBa;
+    ///
+    /// assert_eq!(1.0, RGBa::SOLID_WHITE.relative_luminance());
+    /// assert!((RGBa::SOLID_RED.relative_luminance() - 0.2126).abs() < 1e-12);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn relative_luminance(&self) -> f64 {
+        let (r, g, b) = self.to_linear_rgb();
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+    /// Gets the WCAG 2.x contrast ratio of `self` and `other`. The order of both colours does not matter.
+    /// # Arguments
+    /// * `other: &RGBa` - The other colour.
+    /// # Returns
+    /// A `f64` in `[1; 21]`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::rgba::RGBa;
+    ///
+    /// assert_eq!(21.0, RGBa::SOLID_BLACK.contrast_ratio(&RGBa::SOLID_WHITE));
+    /// assert!((RGBa::SOLID_WHITE.contrast_ratio(&RGBa::new(118, 118, 118, 255)) - 4.54).abs() < 0.01);
+    /// ```
+    #[must_use]
+    pub fn contrast_ratio(&self, other: &RGBa) -> f64 {
+        let (l1, l2) = (self.relative_luminance(), other.relative_luminance());
+        (l1.max(l2) + 0.05) / (l1.min(l2) + 0.05)
+    }
+
+    fn from_unit_rgb((r, g, b): (f32, f32, f32)) -> RGBa {
+        RGBa { red:   (r * 255.0).round() as u8,
+               green: (g * 255.0).round() as u8,
+               blue:  (b * 255.0).round() as u8,
+               alpha: 255 }
+    }
     /// Determines whethe `self` is transparent or not. `true` if `alpha != 255`, otherwise `false`.
     /// # Returns
     /// A `bool`.
@@ -118,6 +333,108 @@ impl RGBa {
     pub const fn is_opaque(&self) -> bool {
         self.alpha == 255
     }
+    /// Multiplies the colour channels of `self` with its alpha value.
+    /// # Returns
+    /// A new `RGBa` struct with premultiplied alpha.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::rgba::RGBa;
+    ///
+    /// let c = RGBa::new(255, 128, 0, 128);
+    /// assert_eq!(RGBa::new(128, 64, 0, 128), c.premultiplied());
+    /// assert_eq!(c, c.premultiplied().unpremultiplied());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn premultiplied(&self) -> RGBa {
+        let mul = |c: u8| ((c as u16 * self.alpha as u16 + 127) / 255) as u8;
+        RGBa { red:   mul(self.red),
+               green: mul(self.green),
+               blue:  mul(self.blue),
+               alpha: self.alpha }
+    }
+    /// Divides the premultiplied colour channels of `self` by its alpha value. A fully transparent colour becomes transparent black.
+    /// # Returns
+    /// A new `RGBa` struct with straight alpha.
+    #[inline]
+    #[must_use]
+    pub fn unpremultiplied(&self) -> RGBa {
+        if self.alpha == 0
+        { return RGBa::new(0, 0, 0, 0); }
+
+        let div = |c: u8| ((c as u32 * 255 + self.alpha as u32 / 2) / self.alpha as u32).min(255) as u8;
+        RGBa { red:   div(self.red),
+               green: div(self.green),
+               blue:  div(self.blue),
+               alpha: self.alpha }
+    }
+    /// Composites `self` as source onto a backdrop with a Porter-Duff operator. The colours are mixed in linear light.
+    /// # Arguments
+    /// * `backdrop: &RGBa` - The backdrop.
+    /// * `op: CompositeOp` - The compositing operator.
+    /// # Returns
+    /// A new `RGBa` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{blend::CompositeOp, rgba::RGBa};
+    ///
+    /// let half_red = RGBa::new(255, 0, 0, 128);
+    ///
+    /// assert_eq!(RGBa::new(188, 0, 187, 255), half_red.composite(&RGBa::SOLID_BLUE, CompositeOp::Over));
+    /// assert_eq!(RGBa::new(255, 0, 0, 128), half_red.composite(&RGBa::SOLID_BLUE, CompositeOp::In));
+    /// assert_eq!(RGBa::new(0, 0, 0, 0), half_red.composite(&RGBa::SOLID_BLUE, CompositeOp::Out));
+    /// assert_eq!(RGBa::new(0, 0, 255, 127), half_red.composite(&RGBa::SOLID_BLUE, CompositeOp::Xor));
+    /// ```
+    #[must_use]
+    pub fn composite(&self, backdrop: &RGBa, op: CompositeOp) -> RGBa {
+        let a_s = self.alpha as f64 / 255.0;
+        let a_b = backdrop.alpha as f64 / 255.0;
+        let (f_s, f_b) = op.factors(a_s, a_b);
+        let a_o = a_s * f_s + a_b * f_b;
+        if a_o <= 0.0
+        { return RGBa::new(0, 0, 0, 0); }
+
+        let (rs, gs, bs) = self.to_linear_rgb();
+        let (rb, gb, bb) = backdrop.to_linear_rgb();
+        let mix = |s: f64, b: f64| (a_s * f_s * s + a_b * f_b * b) / a_o;
+
+        RGBa::from_linear_rgb(mix(rs, rb), mix(gs, gb), mix(bs, bb), (a_o * 255.0).round() as u8)
+    }
+    /// Blends `self` as source onto a backdrop and places the result over it. The colours are mixed in linear light.
+    /// # Arguments
+    /// * `backdrop: &RGBa` - The backdrop.
+    /// * `mode: BlendMode` - The blend mode.
+    /// # Returns
+    /// A new `RGBa` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{blend::BlendMode, rgba::RGBa};
+    ///
+    /// let grey = RGBa::new(128, 128, 128, 255);
+    ///
+    /// assert_eq!(grey, RGBa::SOLID_WHITE.blend(&grey, BlendMode::Multiply));
+    /// assert_eq!(grey, RGBa::SOLID_BLACK.blend(&grey, BlendMode::Screen));
+    /// assert_eq!(RGBa::SOLID_BLACK, grey.blend(&grey, BlendMode::Difference));
+    /// assert_eq!(RGBa::new(255, 0, 255, 255), RGBa::SOLID_RED.blend(&RGBa::SOLID_BLUE, BlendMode::Lighten));
+    /// // A transparent source leaves the backdrop untouched.
+    /// assert_eq!(grey, RGBa::new(255, 0, 0, 0).blend(&grey, BlendMode::Overlay));
+    /// ```
+    #[must_use]
+    pub fn blend(&self, backdrop: &RGBa, mode: BlendMode) -> RGBa {
+        let a_b = backdrop.alpha as f64 / 255.0;
+        let (rs, gs, bs) = self.to_linear_rgb();
+        let (rb, gb, bb) = backdrop.to_linear_rgb();
+        let mix = |s: f64, b: f64| (1.0 - a_b) * s + a_b * mode.apply(b, s);
+
+        let (r, g, b) = (mix(rs, rb), mix(gs, gb), mix(bs, bb));
+        let a_s = self.alpha as f64 / 255.0;
+        let a_o = a_s + a_b * (1.0 - a_s);
+        if a_o <= 0.0
+        { return RGBa::new(0, 0, 0, 0); }
+
+        let over = |s: f64, b: f64| (a_s * s + a_b * (1.0 - a_s) * b) / a_o;
+        RGBa::from_linear_rgb(over(r, rb), over(g, gb), over(b, bb), (a_o * 255.0).round() as u8)
+    }
     /// Convert `self` into a string with no alpha value.
     /// # Returns
     /// A `String`.
@@ -133,6 +450,80 @@ impl RGBa {
         res.drain((res.len() - 2)..res.len());
         res
     }
+    /// Formats `self` as CSS hex colour, `#rrggbb` if opaque, otherwise `#rrggbbaa`.
+    /// # Returns
+    /// A `String`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::rgba::RGBa;
+    ///
+    /// assert_eq!("#9074ff", RGBa::LIBRAPID_SOLID_BLUE.to_css_hex());
+    /// assert_eq!("#ff000080", RGBa::new(255, 0, 0, 128).to_css_hex());
+    /// ```
+    #[must_use]
+    pub fn to_css_hex(&self) -> String {
+        match self.is_opaque() {
+            true  => format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue),
+            false => format!("#{:02x}{:02x}{:02x}{:02x}", self.red, self.green, self.blue, self.alpha),
+        }
+    }
+    /// Formats `self` in the CSS functional notation, `rgb()` if opaque, otherwise `rgba()`.
+    /// # Returns
+    /// A `String`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::rgba::RGBa;
+    ///
+    /// let c = RGBa::new(255, 0, 0, 128);
+    ///
+    /// assert_eq!("rgba(255, 0, 0, 0.502)", c.to_css_rgb());
+    /// assert_eq!(Ok(c), c.to_css_rgb().parse());
+    /// ```
+    #[must_use]
+    pub fn to_css_rgb(&self) -> String {
+        match self.is_opaque() {
+            true  => format!("rgb({}, {}, {})", self.red, self.green, self.blue),
+            false => format!("rgba({}, {}, {}, {})", self.red, self.green, self.blue,
+                                                     trim_number(self.alpha as f64 / 255.0, 3)),
+        }
+    }
+    /// Formats `self` in the CSS `hsl()` or `hsla()` notation.
+    /// # Returns
+    /// A `String`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::rgba::RGBa;
+    ///
+    /// assert_eq!("hsl(210.2, 100%, 75.1%)", RGBa::new(128, 191, 255, 255).to_css_hsl());
+    /// ```
+    #[must_use]
+    pub fn to_css_hsl(&self) -> String {
+        let hsl = HSL::from_rgba_struct(self);
+        let (h, s, l) = (trim_number(hsl.hue() as f64, 1),
+                         trim_number(hsl.saturation() as f64 * 100.0, 1),
+                         trim_number(hsl.lightness() as f64 * 100.0, 1));
+        match self.is_opaque() {
+            true  => format!("hsl({}, {}%, {}%)", h, s, l),
+            false => format!("hsla({}, {}%, {}%, {})", h, s, l, trim_number(self.alpha as f64 / 255.0, 3)),
+        }
+    }
+    /// Gets the CSS name of `self`, if it is an opaque named colour. For colours with two names, the alphabetically first one is returned.
+    /// # Returns
+    /// An `Option<&'static str>`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::rgba::RGBa;
+    ///
+    /// assert_eq!(Some("red"), RGBa::SOLID_RED.css_name());
+    /// assert_eq!(Some("aqua"), RGBa::new(0, 255, 255, 255).css_name());
+    /// assert_eq!(None, RGBa::LIBRAPID_SOLID_BLUE.css_name());
+    /// ```
+    #[must_use]
+    pub fn css_name(&self) -> Option<&'static str> {
+        NAMED_COLOURS.iter()
+                     .find(|(_, c)| c == self)
+                     .map(|(n, _)| *n)
+    }
     /// Create a new `RGBa` struct from `CMYK` values.
     /// # Arguments
     /// * `cmyk: &CMYK` - The CMYK struct.
@@ -185,6 +576,21 @@ impl std::fmt::Display for RGBa {
         write!(f, "#{:02X}{:02X}{:02X}{:02X}", self.red, self.green, self.blue, self.alpha)
     }
 }
+/// Parses CSS colour strings, see `css::parse_css`.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::colours::rgba::RGBa;
+///
+/// assert_eq!(Ok(RGBa::SOLID_WHITE), "white".parse());
+/// assert!("#12345".parse::<RGBa>().is_err());
+/// ```
+impl FromStr for RGBa {
+    type Err = ColourParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_css(s)
+    }
+}
 /// Iterate over a `RGBa` struct in the order `R, G, B, a`.
 impl IntoIterator for RGBa {
     type Item = u8;
@@ -0,0 +1,105 @@
+//! In here you'll find the struct for the CIE `XYZ` colour-definition and the standard white points.
+use crate::compsci::colours::rgba::RGBa;
+
+/// Standard illuminants used as reference white.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WhitePoint {
+    /// Noon daylight, the white point of sRGB.
+    D65,
+    /// Horizon light, the white point used for print and ICC profiles.
+    D50,
+}
+
+/// A struct for storing CIE XYZ tristimulus values. `y` is the relative luminance, `1.0` for the reference white.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XYZ {
+    /// The X part.
+    pub x: f64,
+    /// The Y part, i.e. the luminance.
+    pub y: f64,
+    /// The Z part.
+    pub z: f64,
+}
+
+/// Bradford chromatic adaptation from D65 to D50.
+const D65_TO_D50: [[f64; 3]; 3] = [[ 1.0478112,  0.0228866, -0.0501270],
+                                   [ 0.0295424,  0.9904844, -0.0170491],
+                                   [-0.0092345,  0.0150436,  0.7521316]];
+/// Bradford chromatic adaptation from D50 to D65.
+const D50_TO_D65: [[f64; 3]; 3] = [[ 0.9555766, -0.0230393,  0.0631636],
+                                   [-0.0282895,  1.0099416,  0.0210077],
+                                   [ 0.0122982, -0.0204830,  1.3299098]];
+
+impl WhitePoint {
+    /// Gets the XYZ values of `self`.
+    /// # Returns
+    /// A `XYZ` struct with `y == 1.0`.
+    #[must_use]
+    pub const fn xyz(&self) -> XYZ {
+        match self {
+            WhitePoint::D65 => { XYZ { x: 0.95047, y: 1.0, z: 1.08883 } },
+            WhitePoint::D50 => { XYZ { x: 0.96422, y: 1.0, z: 0.82521 } },
+        }
+    }
+}
+
+impl XYZ {
+    /// Create a new `XYZ` struct.
+    /// # Arguments
+    /// * `x: f64` - The X part.
+    /// * `y: f64` - The Y part.
+    /// * `z: f64` - The Z part.
+    /// # Returns
+    /// A new `XYZ` struct.
+    #[inline]
+    #[must_use]
+    pub const fn new(x: f64, y: f64, z: f64) -> XYZ {
+        XYZ { x, y, z }
+    }
+    /// Create a new `XYZ` struct from an existing `RGBa` struct, relative to the D65 white point. The alpha value is ignored.
+    /// # Arguments
+    /// * `rgb: &RGBa` - The `RGBa` struct.
+    /// # Returns
+    /// A new `XYZ` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::{xyz::{XYZ, WhitePoint}, rgba::RGBa};
+    ///
+    /// let white = XYZ::from_rgba_struct(&RGBa::SOLID_WHITE);
+    /// assert!((white.x - WhitePoint::D65.xyz().x).abs() < 1e-4);
+    /// assert!((white.y - 1.0).abs() < 1e-6);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_rgba_struct(rgb: &RGBa) -> XYZ {
+        let (r, g, b) = rgb.to_linear_rgb();
+        XYZ { x: 0.4124564 * r + 0.3575761 * g + 0.1804375 * b,
+              y: 0.2126729 * r + 0.7151522 * g + 0.0721750 * b,
+              z: 0.0193339 * r + 0.1191920 * g + 0.9503041 * b }
+    }
+    /// Adapts `self` from one white point to another with the Bradford transform.
+    /// # Arguments
+    /// * `from: WhitePoint` - The white point of `self`.
+    /// * `to: WhitePoint` - The target white point.
+    /// # Returns
+    /// A new `XYZ` struct.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::colours::xyz::WhitePoint;
+    ///
+    /// let d50 = WhitePoint::D65.xyz().adapt(WhitePoint::D65, WhitePoint::D50);
+    /// assert!((d50.x - WhitePoint::D50.xyz().x).abs() < 1e-4);
+    /// assert!((d50.z - WhitePoint::D50.xyz().z).abs() < 1e-4);
+    /// ```
+    #[must_use = "This returns the result of the operation, without modifying the original."]
+    pub fn adapt(&self, from: WhitePoint, to: WhitePoint) -> XYZ {
+        let m = match (from, to) {
+            (WhitePoint::D65, WhitePoint::D50) => { &D65_TO_D50 },
+            (WhitePoint::D50, WhitePoint::D65) => { &D50_TO_D65 },
+            _                                  => { return *self; },
+        };
+        XYZ { x: m[0][0] * self.x + m[0][1] * self.y + m[0][2] * self.z,
+              y: m[1][0] * self.x + m[1][1] * self.y + m[1][2] * self.z,
+              z: m[2][0] * self.x + m[2][1] * self.y + m[2][2] * self.z }
+    }
+}
@@ -1,46 +1,194 @@
 //! Basic logging functionality.
-use chrono::{Utc, SecondsFormat};
-use std::{io::prelude::*, fs::OpenOptions};
-use crate::math::general::NumTools;
+use std::{sync::{Mutex, MutexGuard}, time::{Duration, Instant}};
+use formatters::{LogFormatter, TextFormatter};
+use record::{Record, Level};
+use sinks::{Sink, StdoutSink, FileSink};
+
+/// The structured log record.
+pub mod record;
+/// Formatters for text, JSON lines and logfmt output.
+pub mod formatters;
+/// A logger which does its I/O on a separate thread.
+pub mod background;
+/// Destinations for log output: stdout, stderr, files, memory and arbitrary writers.
+pub mod sinks;
+/// The `rapid_log!` macro, which records the source location of the call.
+pub mod macros;
+/// Parsing and querying log files written by a `Logger`.
+pub mod reader;
+/// Scoped timing spans with an aggregated summary report.
+pub mod spans;
+
+/// Trait for everything records can be logged to. Implemented by `Logger` and `BackgroundLogger`.
+pub trait Log {
+    /// Logs a structured `Record`.
+    /// # Returns
+    /// A `Result<(), String>`. `()` if it was successful, otherwise the error message as a `String`.
+    fn log_record(&self, record: &Record) -> Result<(), String>;
+    /// Writes all buffered entries.
+    /// # Returns
+    /// A `Result<(), String>`. `()` if it was successful, otherwise the error message as a `String`.
+    fn flush(&self) -> Result<(), String>;
+}
 
 /// The structure for the RapidLogger.
+/// \
+/// All logging methods take `&self`, so a `Logger` can be shared between threads, e.g. via `std::sync::Arc`.
+/// Any buffered entries are written when the `Logger` is dropped.
 pub struct Logger {
-    /// The buffer size. If the buffer count exceeds this value, the buffer gets written to the specified file.
+    /// The buffer size. If the buffer count exceeds this value, the buffer gets written to all sinks.
     pub buff_size:      usize,
-        buff_count:     usize,
-        buffer:         String,
-    /// Determines wether to write to the console.
-    pub log_to_console: bool,
-    /// Determines wether to write to a file.
-    pub log_to_file:    bool,
-    /// The optional file path.
-    pub file_path:      Option<String>,
+    /// If set, the buffer also gets written once this much time has passed since the last write.
+    pub flush_interval: Option<Duration>,
+        state:          Mutex<BufferState>,
+}
+
+/// A sink together with its own level filter and formatter.
+struct Output {
+    sink:      Box<dyn Sink>,
+    min_level: Level,
+    formatter: Box<dyn LogFormatter>,
+}
+
+struct BufferState {
+    buffer:     Vec<Record>,
+    outputs:    Vec<Output>,
+    last_flush: Instant,
+}
+
+impl BufferState {
+    fn new() -> BufferState {
+        BufferState { buffer:     Vec::new(),
+                      outputs:    Vec::new(),
+                      last_flush: Instant::now() }
+    }
 }
 
 impl Logger {
     /// Creates a new `Logger` object.
+    /// # Arguments
+    /// * `buff_size: usize` - The buffer size.
+    /// * `log_to_console: bool` - Adds a `StdoutSink` if `true`.
+    /// * `log_to_file: bool` - Adds a `FileSink` writing to `file_path` if `true`. Ignored if `file_path` is `None`.
+    /// * `file_path: Option<String>` - The optional file path.
     #[must_use]
-    pub const fn new(buff_size:      usize,
+    pub fn new(buff_size:      usize,
                log_to_console: bool,
                log_to_file:    bool,
                file_path:      Option<String>)
                -> Logger {
-                Logger { buff_size,
-                         buffer:     String::new(),
-                         buff_count: 0,
-                         log_to_console,
-                         log_to_file,
-                         file_path }
+        let mut res = Logger::empty(buff_size);
+        if log_to_console
+        { res = res.with_sink(StdoutSink); }
+        if let (true, Some(p)) = (log_to_file, file_path)
+        { res = res.with_sink(FileSink::new(&p)); }
+        res
     }
     /// Creates a new `Logger` object with default values.
     #[must_use]
-    pub const fn new_default() -> Logger {
-        Logger { buff_size:      10,
-                 buff_count:     0,
-                 buffer:         String::new(),
-                 log_to_console: true,
-                 log_to_file:    false,
-                 file_path:      None }
+    pub fn new_default() -> Logger {
+        Logger::new(10, true, false, None)
+    }
+    /// Creates a new `Logger` object without any sinks.
+    /// # Arguments
+    /// * `buff_size: usize` - The buffer size.
+    /// # Returns
+    /// A new `Logger`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::{Logger, sinks::StderrSink};
+    ///
+    /// let l: Logger = Logger::empty(10).with_sink(StderrSink);
+    /// let _ = l.log(Some(vec!["Info"]), "Logged to stderr.");
+    /// ```
+    #[must_use]
+    pub fn empty(buff_size: usize) -> Logger {
+        Logger { buff_size,
+                 flush_interval: None,
+                 state:          Mutex::new(BufferState::new()) }
+    }
+    /// Adds a sink which receives every record, formatted with a `TextFormatter`.
+    /// # Arguments
+    /// * `sink: S` - The new sink.
+    /// # Returns
+    /// The modified `Logger`.
+    #[must_use]
+    pub fn with_sink<S: Sink + 'static>(self, sink: S) -> Logger {
+        self.with_filtered_sink(sink, Level::Trace, TextFormatter::new())
+    }
+    /// Adds a sink with its own level filter and formatter.
+    /// # Arguments
+    /// * `sink: S` - The new sink.
+    /// * `min_level: Level` - Records with a lower level are not written to this sink.
+    /// * `formatter: F` - The formatter used for this sink.
+    /// # Returns
+    /// The modified `Logger`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::{Logger,
+    ///                                        record::Level,
+    ///                                        formatters::{JsonFormatter, TextFormatter},
+    ///                                        sinks::MemorySink};
+    ///
+    /// let everything = MemorySink::new(10);
+    /// let errors     = MemorySink::new(10);
+    /// let l = Logger::empty(10).with_filtered_sink(everything.clone(), Level::Trace, TextFormatter::new())
+    ///                          .with_filtered_sink(errors.clone(),     Level::Error, JsonFormatter::new());
+    /// let _ = l.log(Some(vec!["Info"]),  "Started.");
+    /// let _ = l.log(Some(vec!["Error"]), "Crashed.");
+    /// let _ = l.flush();
+    ///
+    /// assert_eq!(2, everything.lines().len());
+    /// assert_eq!(1, errors.lines().len());
+    /// assert!(errors.lines()[0].ends_with("\"message\":\"Crashed.\"}"));
+    /// ```
+    #[must_use]
+    pub fn with_filtered_sink<S: Sink + 'static,
+                              F: LogFormatter + 'static>(self,
+                                                         sink:      S,
+                                                         min_level: Level,
+                                                         formatter: F) -> Logger {
+        self.lock_state().outputs.push(Output { sink:      Box::new(sink),
+                                                min_level,
+                                                formatter: Box::new(formatter) });
+        self
+    }
+    /// Sets the formatter of every sink added so far.
+    /// # Arguments
+    /// * `formatter: F` - The new formatter.
+    /// # Returns
+    /// The modified `Logger`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::{Logger, formatters::JsonFormatter};
+    /// let l: Logger = Logger::new_default().with_formatter(JsonFormatter::new());
+    /// let _ = l.log(Some(vec!["Info"]), "Logged as JSON.");
+    /// ```
+    #[must_use]
+    pub fn with_formatter<F: LogFormatter + Clone + 'static>(self, formatter: F) -> Logger {
+        for o in self.lock_state().outputs.iter_mut()
+        { o.formatter = Box::new(formatter.clone()); }
+        self
+    }
+    /// Sets the flush interval of `self`. Once `interval` has passed since the last write, the next log call writes the buffer,
+    /// even if it holds less than `buff_size` entries.
+    /// # Arguments
+    /// * `interval: Duration` - The maximum time between two writes.
+    /// # Returns
+    /// The modified `Logger`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::Logger;
+    /// use std::time::Duration;
+    ///
+    /// let l: Logger = Logger::new(100, true, false, None).with_flush_interval(Duration::from_millis(0));
+    /// let _ = l.log(None, "Written immediately, although the buffer is not full.");
+    /// assert_eq!(0, l.buffered());
+    /// ```
+    #[must_use]
+    pub fn with_flush_interval(mut self, interval: Duration) -> Logger {
+        self.flush_interval = Some(interval);
+        self
     }
     /// Logs to a `Logger`.
     /// # Returns
@@ -48,61 +196,139 @@ impl Logger {
     /// # Examples
     /// ```
     /// use lib_rapid::compsci::rapidlogging::Logger;
-    /// let mut l: Logger = Logger::new(3, true, true, Some("log.txt".to_string()));
+    /// let l: Logger = Logger::new(3, true, true, Some("log.txt".to_string()));
     /// let _ = l.log(None, "Test-Log.");
     /// let _ = l.log(None, "Test-Log.");
     /// let _ = l.log(None, "Test-Log.");
     /// let _ = l.log(Some(vec!["Warning"]), "This is a warning.");
     /// ```
     /// As you can see, we initialise a new Logger `l` with the buffer size 3. This means that only after 3x logging, the logger writes to the file and to the console.
-    pub fn log(&mut self, prefixes: Option<Vec<&str>>, msg: &str) -> Result<(), String> {
-        self.buff_count.inc();
-        let mut out: String = format!("[{}]", Utc::now()
-                                                   .to_rfc3339_opts(SecondsFormat::Secs,
-                                                                    true));
+    /// The fourth entry is written once `l` is dropped.
+    /// \
+    /// \
+    /// Logging from several threads at once:
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::Logger;
+    /// let l: Logger = Logger::new(4, true, false, None);
+    ///
+    /// std::thread::scope(|s| {
+    ///     for i in 0..4 {
+    ///         let l = &l;
+    ///         s.spawn(move || { let _ = l.log(None, &format!("Hello from thread {}.", i)); });
+    ///     }
+    /// });
+    /// assert_eq!(0, l.buffered());
+    /// ```
+    pub fn log(&self, prefixes: Option<Vec<&str>>, msg: &str) -> Result<(), String> {
+        let mut record = Record::new(msg);
         if let Some(v) = prefixes {
             for s in v
-            { out.push_str(&format!("[{}]", s)); }
+            { record = record.with_prefix(s); }
         }
-        out.push(' ');
-        out.push_str(msg);
-        out.push('\n');
-        self.buffer.push_str(&out);
+        self.log_record(&record)
+    }
+    /// Logs a structured `Record` to a `Logger`, using the logger's formatter.
+    /// # Arguments
+    /// * `record: &Record` - The record to be logged.
+    /// # Returns
+    /// A `Result<(), String>`. `()` if it was successful, otherwise the error message as a `String`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::{Logger, record::Record, formatters::LogfmtFormatter};
+    /// let l: Logger = Logger::new(1, true, false, None).with_formatter(LogfmtFormatter::new());
+    /// let r = Record::new("Request handled.").with_prefix("Info")
+    ///                                        .with_field("status", 200)
+    ///                                        .with_field("took_ms", 12.5);
+    /// assert!(l.log_record(&r).is_ok());
+    /// ```
+    pub fn log_record(&self, record: &Record) -> Result<(), String> {
+        let mut state = self.lock_state();
+        state.buffer.push(record.clone());
 
-        if self.buff_count == self.buff_size
-        { return self.backend_log(); }
+        let interval_passed = match self.flush_interval {
+            Some(i) => { state.last_flush.elapsed() >= i }
+            None    => { false }
+        };
+        if state.buffer.len() >= self.buff_size || interval_passed
+        { return self.backend_log(&mut state); }
 
         Ok(())
     }
-    /// Resets `buffer` and `buff_counter`.
-    pub fn reset_buffs(&mut self) {
-        self.buff_count = 0;
-        self.buffer = String::new();
+    /// Writes all buffered entries, no matter how many there are.
+    /// # Returns
+    /// A `Result<(), String>`. `()` if it was successful, otherwise the error message as a `String`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::Logger;
+    /// let l: Logger = Logger::new(10, true, false, None);
+    /// let _ = l.log(None, "Buffered.");
+    /// assert_eq!(1, l.buffered());
+    ///
+    /// assert!(l.flush().is_ok());
+    /// assert_eq!(0, l.buffered());
+    /// ```
+    pub fn flush(&self) -> Result<(), String> {
+        let mut state = self.lock_state();
+        if state.buffer.is_empty()
+        { return Ok(()); }
+        self.backend_log(&mut state)
+    }
+    /// Gets the number of entries which are currently buffered and not yet written.
+    /// # Returns
+    /// A `usize`.
+    #[must_use]
+    pub fn buffered(&self) -> usize {
+        self.lock_state().buffer.len()
+    }
+    /// Resets the buffer. Buffered entries are discarded without being written.
+    pub fn reset_buffs(&self) {
+        self.lock_state().buffer.clear();
+    }
+    /// A poisoned lock only means that another thread panicked while logging; the buffer itself is still usable.
+    fn lock_state(&self) -> MutexGuard<'_, BufferState> {
+        match self.state.lock() {
+            Ok(s)  => s,
+            Err(e) => e.into_inner(),
+        }
     }
     /// For cleaner code, the main functionality is hidden from the user in this function.
-    fn backend_log(&mut self) -> Result<(), String> {
-        if self.log_to_file { 
-            let file = OpenOptions::new()
-                                    .create(true)
-                                    .append(true)
-                                    .open(&self.file_path.as_ref().unwrap());
-            let mut file = match file {
-                Ok(f)  => f,
-                Err(e) => { return Err(format!("Problem opening or creating file: {:?}", e)); }
-            };
-            match write!(file, "{}", self.buffer) {
-                Ok(_)  => { }
-                Err(e) => { return Err(format!("Problem writing to file: {:?}", e)); }
+    /// Every sink gets its chance to write, even if another one failed; the first error is returned.
+    fn backend_log(&self, state: &mut BufferState) -> Result<(), String> {
+        let mut res: Result<(), String> = Ok(());
+        let BufferState { buffer, outputs, last_flush } = state;
+
+        for o in outputs.iter_mut() {
+            let min_level = o.min_level;
+            for r in buffer.iter().filter(|r| r.level() >= min_level) {
+                if let Err(e) = o.sink.write_line(&o.formatter.format(r)) {
+                    if res.is_ok()
+                    { res = Err(e); }
+                }
+            }
+            if let Err(e) = o.sink.flush() {
+                if res.is_ok()
+                { res = Err(e); }
             }
-            
         }
+        buffer.clear();
+        *last_flush = Instant::now();
 
-        if self.log_to_console {
-            print!("{}", self.buffer);
-            std::io::stdout().flush().unwrap();
-        }
-        self.reset_buffs();
+        res
+    }
+}
 
-        Ok(())
+impl Log for Logger {
+    fn log_record(&self, record: &Record) -> Result<(), String> {
+        Logger::log_record(self, record)
+    }
+
+    fn flush(&self) -> Result<(), String> {
+        Logger::flush(self)
     }
-}
\ No newline at end of file
+}
+
+impl Drop for Logger {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
@@ -0,0 +1,172 @@
+//! A logger handle which sends records to a background thread. The thread owns the `Logger` and does all the formatting and I/O.
+use std::{sync::mpsc::{self, Sender, RecvTimeoutError}, thread::{self, JoinHandle}};
+use super::{Log, Logger, record::Record};
+
+const THREAD_ERR: &str = "The background logging thread has stopped.";
+
+enum Message {
+    Record(Record),
+    Flush(Sender<Result<(), String>>),
+}
+
+/// A handle to a `Logger` which lives on its own thread.
+/// \
+/// Logging only sends the record over a channel, so the calling thread never waits for I/O.
+/// When the handle is dropped, the thread writes all remaining entries and is joined.
+pub struct BackgroundLogger {
+    sender: Option<Sender<Message>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl BackgroundLogger {
+    /// Moves `logger` onto a new thread and returns a handle to it.
+    /// If `logger` has a `flush_interval`, the thread also writes the buffer when no records arrive within that interval.
+    /// An interval of zero writes every record as it arrives, so the thread then only waits for records.
+    /// # Arguments
+    /// * `logger: Logger` - The logger which does the actual work.
+    /// # Returns
+    /// A new `BackgroundLogger`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::{Logger, background::BackgroundLogger};
+    /// use std::time::Duration;
+    ///
+    /// let l = BackgroundLogger::spawn(Logger::new(100, true, false, None)
+    ///                                        .with_flush_interval(Duration::from_millis(50)));
+    /// let _ = l.log(Some(vec!["Info"]), "Logged without blocking.");
+    /// ```
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::{Logger, background::BackgroundLogger};
+    /// use std::time::Duration;
+    ///
+    /// // Every record is written as it arrives; the idle thread does not spin.
+    /// let l = BackgroundLogger::spawn(Logger::new(100, true, false, None).with_flush_interval(Duration::ZERO));
+    /// let _ = l.log(None, "Written at once.");
+    /// assert!(l.flush().is_ok());
+    /// ```
+    #[must_use]
+    pub fn spawn(logger: Logger) -> BackgroundLogger {
+        let (sender, receiver) = mpsc::channel::<Message>();
+        let handle = thread::spawn(move || {
+            let mut last_err: Option<String> = None;
+            // `recv_timeout` would return at once for a zero interval and spin.
+            let interval = logger.flush_interval.filter(|i| !i.is_zero());
+            loop {
+                let msg = match interval {
+                    Some(i) => match receiver.recv_timeout(i) {
+                        Ok(m)                                => m,
+                        Err(RecvTimeoutError::Timeout)      => {
+                            if let Err(e) = logger.flush()
+                            { last_err = Some(e); }
+                            continue;
+                        }
+                        Err(RecvTimeoutError::Disconnected) => { break; }
+                    },
+                    None    => match receiver.recv() {
+                        Ok(m)  => m,
+                        Err(_) => { break; }
+                    },
+                };
+                match msg {
+                    Message::Record(r) => {
+                        if let Err(e) = logger.log_record(&r)
+                        { last_err = Some(e); }
+                    }
+                    Message::Flush(reply) => {
+                        let res = match last_err.take() {
+                            Some(e) => Err(e),
+                            None    => logger.flush(),
+                        };
+                        let _ = reply.send(res);
+                    }
+                }
+            }
+            // `logger` is dropped here, which writes whatever is left in the buffer.
+        });
+
+        BackgroundLogger { sender: Some(sender),
+                           handle: Some(handle) }
+    }
+    /// Logs to the background `Logger`.
+    /// # Returns
+    /// A `Result<(), String>`. `()` if the record was handed over, otherwise the error message as a `String`.
+    /// I/O errors are reported by the next call to `flush`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::{Logger, background::BackgroundLogger};
+    /// use std::sync::Arc;
+    ///
+    /// let l = Arc::new(BackgroundLogger::spawn(Logger::new(10, true, false, None)));
+    /// let handles: Vec<_> = (0..3).map(|i| {
+    ///     let l = Arc::clone(&l);
+    ///     std::thread::spawn(move || l.log(None, &format!("Hello from thread {}.", i)))
+    /// }).collect();
+    ///
+    /// for h in handles
+    /// { assert!(h.join().unwrap().is_ok()); }
+    /// ```
+    pub fn log(&self, prefixes: Option<Vec<&str>>, msg: &str) -> Result<(), String> {
+        let mut record = Record::new(msg);
+        if let Some(v) = prefixes {
+            for s in v
+            { record = record.with_prefix(s); }
+        }
+        self.log_record(&record)
+    }
+    /// Logs a structured `Record` to the background `Logger`.
+    /// # Arguments
+    /// * `record: &Record` - The record to be logged.
+    /// # Returns
+    /// A `Result<(), String>`. `()` if the record was handed over, otherwise the error message as a `String`.
+    /// I/O errors are reported by the next call to `flush`.
+    pub fn log_record(&self, record: &Record) -> Result<(), String> {
+        self.send(Message::Record(record.clone()))
+    }
+    /// Writes all buffered entries and waits until the background thread is done.
+    /// # Returns
+    /// A `Result<(), String>`. `()` if it was successful, otherwise the error message of the first
+    /// failure since the last call to `flush`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::{Logger, background::BackgroundLogger};
+    ///
+    /// let l = BackgroundLogger::spawn(Logger::new(100, true, false, None));
+    /// let _ = l.log(None, "First.");
+    /// let _ = l.log(None, "Second.");
+    /// assert!(l.flush().is_ok());
+    /// ```
+    pub fn flush(&self) -> Result<(), String> {
+        let (reply, answer) = mpsc::channel();
+        self.send(Message::Flush(reply))?;
+        match answer.recv() {
+            Ok(res) => res,
+            Err(_)  => Err(THREAD_ERR.to_owned()),
+        }
+    }
+
+    fn send(&self, msg: Message) -> Result<(), String> {
+        match &self.sender {
+            Some(s) => s.send(msg).map_err(|_| THREAD_ERR.to_owned()),
+            None    => Err(THREAD_ERR.to_owned()),
+        }
+    }
+}
+
+impl Log for BackgroundLogger {
+    fn log_record(&self, record: &Record) -> Result<(), String> {
+        BackgroundLogger::log_record(self, record)
+    }
+
+    fn flush(&self) -> Result<(), String> {
+        BackgroundLogger::flush(self)
+    }
+}
+
+impl Drop for BackgroundLogger {
+    fn drop(&mut self) {
+        // Closing the channel ends the loop on the background thread.
+        self.sender = None;
+        if let Some(h) = self.handle.take()
+        { let _ = h.join(); }
+    }
+}
@@ -0,0 +1,407 @@
+//! Formatters which turn a `Record` into a single line of text.
+use chrono::{DateTime, FixedOffset, Local, SecondsFormat, TimeZone};
+use std::borrow::Cow;
+use super::record::Record;
+
+/// Trait for everything that can turn a `Record` into a line of log output.
+/// Formatters must be `Send + Sync`, as a `Logger` may be shared between threads.
+pub trait LogFormatter: Send + Sync {
+    /// Formats a record.
+    /// # Arguments
+    /// * `record: &Record` - The record to be formatted.
+    /// # Returns
+    /// A `String` without a trailing line break.
+    #[must_use]
+    fn format(&self, record: &Record) -> String;
+}
+
+/// The number of fractional digits of a timestamp.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Precision {
+    #[default]
+    Seconds,
+    Millis,
+    Micros,
+    Nanos,
+}
+
+/// The clock a timestamp is read from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Clock {
+    /// Wall clock time in UTC, e.g. `2022-01-01T12:00:00Z`.
+    #[default]
+    Utc,
+    /// Wall clock time in the local time zone, e.g. `2022-01-01T13:00:00+01:00`.
+    Local,
+    /// Wall clock time with a fixed offset from UTC, e.g. `FixedOffset::east_opt(3600)`, which rejects offsets of a day or more.
+    FixedOffset(FixedOffset),
+    /// The monotonic time passed since the first record of the process, e.g. `+12.5s`.
+    Elapsed,
+}
+
+/// Describes how timestamps are written. The default is UTC RFC3339 at second precision.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TimestampFormat {
+    /// The clock.
+    pub clock:     Clock,
+    /// The precision.
+    pub precision: Precision,
+}
+
+/// The classic LibRapid format: `[timestamp][prefix] msg key=value`.
+/// \
+/// The message is quoted and escaped like a logfmt value if it starts with `[` or `"`, or contains `=` or control characters.
+/// An unquoted message thus never contains `=`, which tells it apart from the prefixes before and the fields after it.
+/// Brackets, backslashes and line breaks in prefixes are escaped with a backslash.
+/// Fields called `thread` or `location` are written with a leading `_`, see `JsonFormatter`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TextFormatter {
+    /// How timestamps are written.
+    pub timestamp:     TimestampFormat,
+    /// Appends the thread name as the field `thread`.
+    pub show_thread:   bool,
+    /// Appends the source location as the field `location`, if the record has one.
+    pub show_location: bool,
+}
+
+/// JSON lines: one flat JSON object per record.
+/// The keys `timestamp`, `prefixes` and `message` are always written first, followed by `thread` and `location` if enabled, and then the fields.
+/// \
+/// Fields called like one of these keys, possibly after leading underscores, are written with one more leading `_`,
+/// e.g. `_message`, so they cannot overwrite the record's own values. `reader::parse_line` removes it again.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JsonFormatter {
+    /// How timestamps are written.
+    pub timestamp:     TimestampFormat,
+    /// Writes the thread name under the key `thread`.
+    pub show_thread:   bool,
+    /// Writes the source location under the key `location`, if the record has one.
+    pub show_location: bool,
+}
+
+/// The logfmt format: `timestamp=... prefix=... msg=... key=value`.
+/// Fields called `timestamp`, `prefix`, `msg`, `thread` or `location` are written with a leading `_`, see `JsonFormatter`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LogfmtFormatter {
+    /// How timestamps are written.
+    pub timestamp:     TimestampFormat,
+    /// Writes the thread name under the key `thread`.
+    pub show_thread:   bool,
+    /// Writes the source location under the key `location`, if the record has one.
+    pub show_location: bool,
+}
+
+impl TimestampFormat {
+    /// Creates a new `TimestampFormat`.
+    /// # Arguments
+    /// * `clock: Clock` - The clock.
+    /// * `precision: Precision` - The precision.
+    /// # Returns
+    /// A new `TimestampFormat`.
+    #[must_use]
+    pub const fn new(clock: Clock, precision: Precision) -> TimestampFormat {
+        TimestampFormat { clock, precision }
+    }
+    /// Formats the timestamp of a record.
+    /// # Arguments
+    /// * `record: &Record` - The record.
+    /// # Returns
+    /// A `String`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::{record::Record,
+    ///                                        formatters::{TimestampFormat, Clock, Precision}};
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    /// use std::time::Duration;
+    ///
+    /// let mut r   = Record::new("Hello.");
+    /// r.timestamp = Utc.with_ymd_and_hms(2022, 1, 1, 12, 0, 0).unwrap();
+    /// r.elapsed   = Duration::from_micros(1_500_250);
+    /// let cet     = Clock::FixedOffset(FixedOffset::east_opt(3600).unwrap());
+    ///
+    /// assert_eq!("2022-01-01T12:00:00Z",
+    ///            TimestampFormat::default().format(&r));
+    /// assert_eq!("2022-01-01T12:00:00.000Z",
+    ///            TimestampFormat::new(Clock::Utc, Precision::Millis).format(&r));
+    /// assert_eq!("2022-01-01T13:00:00+01:00",
+    ///            TimestampFormat::new(cet, Precision::Seconds).format(&r));
+    /// assert!(FixedOffset::east_opt(86_400).is_none());
+    /// assert_eq!("+1.500250s",
+    ///            TimestampFormat::new(Clock::Elapsed, Precision::Micros).format(&r));
+    /// ```
+    #[must_use]
+    pub fn format(&self, record: &Record) -> String {
+        let secs_format = match self.precision {
+            Precision::Seconds => SecondsFormat::Secs,
+            Precision::Millis  => SecondsFormat::Millis,
+            Precision::Micros  => SecondsFormat::Micros,
+            Precision::Nanos   => SecondsFormat::Nanos,
+        };
+        match self.clock {
+            Clock::Utc            => { record.timestamp.to_rfc3339_opts(secs_format, true) }
+            Clock::Local          => { record.timestamp.with_timezone(&Local)
+                                                       .to_rfc3339_opts(secs_format, false) }
+            Clock::FixedOffset(o) => {
+                let t: DateTime<FixedOffset> = o.from_utc_datetime(&record.timestamp.naive_utc());
+                t.to_rfc3339_opts(secs_format, false)
+            }
+            Clock::Elapsed        => {
+                let e = record.elapsed;
+                match self.precision {
+                    Precision::Seconds => format!("+{}s",       e.as_secs()),
+                    Precision::Millis  => format!("+{}.{:03}s", e.as_secs(), e.subsec_millis()),
+                    Precision::Micros  => format!("+{}.{:06}s", e.as_secs(), e.subsec_micros()),
+                    Precision::Nanos   => format!("+{}.{:09}s", e.as_secs(), e.subsec_nanos()),
+                }
+            }
+        }
+    }
+}
+
+/// Generates the shared constructor and builder methods of the formatters.
+macro_rules! formatter_options {
+    ($t:ident) => {
+        impl $t {
+            /// Creates a new formatter with the default timestamp format and without thread names or source locations.
+            #[must_use]
+            pub const fn new() -> $t {
+                $t { timestamp:     TimestampFormat::new(Clock::Utc, Precision::Seconds),
+                     show_thread:   false,
+                     show_location: false }
+            }
+            /// Sets the timestamp format.
+            #[must_use]
+            pub const fn with_timestamp(mut self, timestamp: TimestampFormat) -> $t {
+                self.timestamp = timestamp;
+                self
+            }
+            /// Sets whether the thread name is written.
+            #[must_use]
+            pub const fn with_thread(mut self, show_thread: bool) -> $t {
+                self.show_thread = show_thread;
+                self
+            }
+            /// Sets whether the source location is written.
+            #[must_use]
+            pub const fn with_location(mut self, show_location: bool) -> $t {
+                self.show_location = show_location;
+                self
+            }
+            /// The thread and location, if enabled and present.
+            fn extra_fields<'a>(&self, record: &'a Record) -> Vec<(&'static str, Cow<'a, str>)> {
+                let mut res = Vec::new();
+                if let (true, Some(t)) = (self.show_thread, &record.thread)
+                { res.push(("thread", Cow::Borrowed(t.as_str()))); }
+                if let (true, Some(l)) = (self.show_location, &record.location)
+                { res.push(("location", Cow::Owned(l.to_string()))); }
+                res
+            }
+        }
+    };
+}
+
+formatter_options!(TextFormatter);
+formatter_options!(JsonFormatter);
+formatter_options!(LogfmtFormatter);
+
+impl LogFormatter for TextFormatter {
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::{record::Record,
+    ///                                        formatters::{LogFormatter, TextFormatter}};
+    ///
+    /// let r = Record::new("Disk almost full.").with_prefix("Warning")
+    ///                                         .with_field("free", "2 GiB");
+    /// let s = TextFormatter::new().format(&r);
+    /// assert!(s.starts_with('['));
+    /// assert!(s.ends_with("][Warning] Disk almost full. free=\"2 GiB\""));
+    ///
+    /// let s = TextFormatter::new().with_thread(true).format(&r);
+    /// assert!(s.ends_with("][Warning] Disk almost full. thread=main free=\"2 GiB\""));
+    ///
+    /// let s = TextFormatter::new().format(&Record::new("Set x=5"));
+    /// assert!(s.ends_with("] \"Set x=5\""));
+    ///
+    /// let s = TextFormatter::new().format(&Record::new("hello").with_prefix("a]b"));
+    /// assert!(s.ends_with("][a\\]b] hello"));
+    /// ```
+    fn format(&self, record: &Record) -> String {
+        let mut out: String = format!("[{}]", self.timestamp.format(record));
+        for p in &record.prefixes
+        { push_text_prefix(&mut out, p); }
+        out.push(' ');
+        push_text_message(&mut out, &record.message);
+        for (k, v) in self.extra_fields(record) {
+            out.push(' ');
+            push_logfmt_pair(&mut out, k, &v);
+        }
+        for (k, v) in &record.fields {
+            out.push(' ');
+            push_logfmt_pair(&mut out, &escape_key(k, &TEXT_KEYS), v);
+        }
+        out
+    }
+}
+
+impl LogFormatter for JsonFormatter {
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::{record::Record,
+    ///                                        formatters::{LogFormatter, JsonFormatter}};
+    ///
+    /// let r = Record::new("Say \"hi\".").with_prefix("Info")
+    ///                                   .with_field("user", "bob");
+    /// let s = JsonFormatter::new().format(&r);
+    /// assert!(s.starts_with("{\"timestamp\":\""));
+    /// assert!(s.ends_with("\"prefixes\":[\"Info\"],\"message\":\"Say \\\"hi\\\".\",\"user\":\"bob\"}"));
+    ///
+    /// let s = JsonFormatter::new().format(&Record::new("real").with_field("message", "spoofed"));
+    /// assert!(s.ends_with("\"message\":\"real\",\"_message\":\"spoofed\"}"));
+    /// ```
+    fn format(&self, record: &Record) -> String {
+        let mut out = String::from("{\"timestamp\":");
+        push_json_string(&mut out, &self.timestamp.format(record));
+        out.push_str(",\"prefixes\":[");
+        for (i, p) in record.prefixes.iter().enumerate() {
+            if i != 0
+            { out.push(','); }
+            push_json_string(&mut out, p);
+        }
+        out.push_str("],\"message\":");
+        push_json_string(&mut out, &record.message);
+        for (k, v) in self.extra_fields(record) {
+            out.push(',');
+            push_json_string(&mut out, k);
+            out.push(':');
+            push_json_string(&mut out, &v);
+        }
+        for (k, v) in &record.fields {
+            out.push(',');
+            push_json_string(&mut out, &escape_key(k, &JSON_KEYS));
+            out.push(':');
+            push_json_string(&mut out, v);
+        }
+        out.push('}');
+        out
+    }
+}
+
+impl LogFormatter for LogfmtFormatter {
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::{record::Record,
+    ///                                        formatters::{LogFormatter, LogfmtFormatter}};
+    ///
+    /// let r = Record::new("Cache miss").with_prefix("Debug")
+    ///                                  .with_field("key", "user:42")
+    ///                                  .with_location("src/cache.rs", 12, "cache");
+    /// let s = LogfmtFormatter::new().with_location(true).format(&r);
+    /// assert!(s.starts_with("timestamp="));
+    /// assert!(s.ends_with(" prefix=Debug msg=\"Cache miss\" location=src/cache.rs:12 key=user:42"));
+    /// ```
+    fn format(&self, record: &Record) -> String {
+        let mut out = String::new();
+        push_logfmt_pair(&mut out, "timestamp", &self.timestamp.format(record));
+        for p in &record.prefixes {
+            out.push(' ');
+            push_logfmt_pair(&mut out, "prefix", p);
+        }
+        out.push(' ');
+        push_logfmt_pair(&mut out, "msg", &record.message);
+        for (k, v) in self.extra_fields(record) {
+            out.push(' ');
+            push_logfmt_pair(&mut out, k, &v);
+        }
+        for (k, v) in &record.fields {
+            out.push(' ');
+            push_logfmt_pair(&mut out, &escape_key(k, &LOGFMT_KEYS), v);
+        }
+        out
+    }
+}
+
+/// The keys each format writes for the record itself, which fields must not take.
+pub(super) const TEXT_KEYS:   [&str; 2] = ["thread", "location"];
+pub(super) const JSON_KEYS:   [&str; 5] = ["timestamp", "prefixes", "message", "thread", "location"];
+pub(super) const LOGFMT_KEYS: [&str; 5] = ["timestamp", "prefix", "msg", "thread", "location"];
+
+/// Adds a `_` to a field key which is reserved after removing its leading underscores.
+fn escape_key<'a>(key: &'a str, reserved: &[&str]) -> Cow<'a, str> {
+    match reserved.contains(&key.trim_start_matches('_')) {
+        true  => Cow::Owned(format!("_{}", key)),
+        false => Cow::Borrowed(key),
+    }
+}
+
+/// Reverts `escape_key`.
+pub(super) fn unescape_key(key: String, reserved: &[&str]) -> String {
+    match key.starts_with('_') && reserved.contains(&key.trim_start_matches('_')) {
+        true  => key[1..].to_owned(),
+        false => key,
+    }
+}
+
+fn push_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"'  => { out.push_str("\\\""); }
+            '\\' => { out.push_str("\\\\"); }
+            '\n' => { out.push_str("\\n"); }
+            '\r' => { out.push_str("\\r"); }
+            '\t' => { out.push_str("\\t"); }
+            c if (c as u32) < 0x20 => { out.push_str(&format!("\\u{:04x}", c as u32)); }
+            c    => { out.push(c); }
+        }
+    }
+    out.push('"');
+}
+
+fn push_text_prefix(out: &mut String, prefix: &str) {
+    out.push('[');
+    for c in prefix.chars() {
+        match c {
+            '[' | ']' | '\\' => { out.push('\\'); out.push(c); }
+            '\n'             => { out.push_str("\\n"); }
+            '\r'             => { out.push_str("\\r"); }
+            '\t'             => { out.push_str("\\t"); }
+            c                => { out.push(c); }
+        }
+    }
+    out.push(']');
+}
+
+fn push_text_message(out: &mut String, msg: &str) {
+    let needs_quotes = msg.starts_with(['[', '"']) ||
+                       msg.chars().any(|c| c == '=' || c.is_control());
+    match needs_quotes {
+        true  => push_quoted(out, msg),
+        false => out.push_str(msg),
+    }
+}
+
+fn push_logfmt_pair(out: &mut String, key: &str, value: &str) {
+    out.push_str(key);
+    out.push('=');
+    let needs_quotes = value.is_empty() ||
+                       value.chars().any(|c| c == ' ' || c == '=' || c == '"' || c.is_control());
+    match needs_quotes {
+        true  => push_quoted(out, value),
+        false => out.push_str(value),
+    }
+}
+
+fn push_quoted(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"'  => { out.push_str("\\\""); }
+            '\\' => { out.push_str("\\\\"); }
+            '\n' => { out.push_str("\\n"); }
+            '\r' => { out.push_str("\\r"); }
+            '\t' => { out.push_str("\\t"); }
+            c    => { out.push(c); }
+        }
+    }
+    out.push('"');
+}
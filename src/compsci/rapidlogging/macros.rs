@@ -0,0 +1,38 @@
+//! Macros for logging with the source location of the call.
+/// Logs a formatted message together with the file, line and module of the call.
+/// The first argument is anything with a `log_record(&Record)` method, i.e. a `Logger` or a `BackgroundLogger`.
+/// Prefixes may be given in square brackets before the message.
+/// # Returns
+/// The `Result<(), String>` of `log_record`.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::rapidlogging::{Logger,
+///                                        macros::rapid_log,
+///                                        record::Level,
+///                                        formatters::TextFormatter,
+///                                        sinks::MemorySink};
+///
+/// let mem = MemorySink::new(10);
+/// let l   = Logger::empty(1).with_filtered_sink(mem.clone(),
+///                                               Level::Trace,
+///                                               TextFormatter::new().with_location(true));
+/// let port = 8080;
+/// let _ = rapid_log!(l, "Listening on port {}.", port);
+/// let _ = rapid_log!(l, ["Warning", "Network"], "Port {} is slow.", port);
+///
+/// let lines = mem.lines();
+/// assert!(lines[0].contains("] Listening on port 8080. location="));
+/// assert!(lines[1].contains("][Warning][Network] Port 8080 is slow. location="));
+/// ```
+#[macro_export]
+macro_rules! rapid_log {
+    ($logger:expr, [$($prefix:expr),* $(,)?], $($arg:tt)+) => {
+        $logger.log_record(&$crate::compsci::rapidlogging::record::Record::new(&format!($($arg)+))
+                            $(.with_prefix($prefix))*
+                            .with_location(file!(), line!(), module_path!()))
+    };
+    ($logger:expr, $($arg:tt)+) => {
+        $crate::rapid_log!($logger, [], $($arg)+)
+    };
+}
+pub use rapid_log;
@@ -0,0 +1,719 @@
+//! Reading log files written by a `Logger` back into `Record`s.
+//! \
+//! All three output formats are understood. Records written with `Clock::Elapsed` have no wall clock time;
+//! their `timestamp` is the Unix epoch and `elapsed` holds the parsed value.
+use chrono::{DateTime, Utc};
+use std::{fs::File,
+          io::{BufRead, BufReader, Seek, SeekFrom},
+          thread,
+          time::Duration};
+use super::{formatters::{unescape_key, JSON_KEYS, LOGFMT_KEYS, TEXT_KEYS},
+            record::{Record, Level, Location}};
+
+/// The format of the lines to be parsed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InputFormat {
+    /// Detects the format of each line: JSON if it starts with `{`, text if it starts with `[`, logfmt otherwise.
+    #[default]
+    Auto,
+    /// `[timestamp][prefix] msg key=value`, as written by `TextFormatter`. Messages starting with `[` or `"`,
+    /// or containing `=`, must be quoted; brackets in prefixes must be escaped with `\`.
+    Text,
+    /// JSON lines, as written by `JsonFormatter`.
+    Json,
+    /// logfmt, as written by `LogfmtFormatter`.
+    Logfmt,
+}
+
+/// An error while reading a log file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line number, starting at 1.
+    pub line:   usize,
+    /// What went wrong.
+    pub reason: String,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+impl std::error::Error for ParseError { }
+
+/// A filter for records. Every criterion which is set must match.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Query {
+    /// Only records at or after this point in time.
+    pub from:      Option<DateTime<Utc>>,
+    /// Only records before this point in time.
+    pub to:        Option<DateTime<Utc>>,
+    /// Only records of this level or above.
+    pub min_level: Option<Level>,
+    /// Only records with this prefix.
+    pub prefix:    Option<String>,
+}
+
+impl Query {
+    /// Creates a new `Query` which matches everything.
+    #[must_use]
+    pub fn new() -> Query {
+        Query::default()
+    }
+    /// Only match records at or after `from`.
+    #[must_use]
+    pub fn with_from(mut self, from: DateTime<Utc>) -> Query {
+        self.from = Some(from);
+        self
+    }
+    /// Only match records before `to`.
+    #[must_use]
+    pub fn with_to(mut self, to: DateTime<Utc>) -> Query {
+        self.to = Some(to);
+        self
+    }
+    /// Only match records of `level` or above.
+    #[must_use]
+    pub fn with_min_level(mut self, level: Level) -> Query {
+        self.min_level = Some(level);
+        self
+    }
+    /// Only match records with the prefix `prefix`.
+    #[must_use]
+    pub fn with_prefix(mut self, prefix: &str) -> Query {
+        self.prefix = Some(prefix.to_owned());
+        self
+    }
+    /// Determines whether `record` matches `self`.
+    /// # Arguments
+    /// * `record: &Record` - The record to be checked.
+    /// # Returns
+    /// A `bool`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::{record::{Record, Level}, reader::Query};
+    ///
+    /// let q = Query::new().with_min_level(Level::Warning)
+    ///                     .with_prefix("Database");
+    /// assert!(q.matches(&Record::new("Slow.").with_prefix("Database").with_prefix("Warning")));
+    /// assert!(!q.matches(&Record::new("Slow.").with_prefix("Database")));
+    /// assert!(!q.matches(&Record::new("Slow.").with_prefix("Error")));
+    /// ```
+    #[must_use]
+    pub fn matches(&self, record: &Record) -> bool {
+        if let Some(f) = self.from {
+            if record.timestamp < f
+            { return false; }
+        }
+        if let Some(t) = self.to {
+            if record.timestamp >= t
+            { return false; }
+        }
+        if let Some(l) = self.min_level {
+            if record.level() < l
+            { return false; }
+        }
+        if let Some(p) = &self.prefix {
+            if !record.prefixes.iter().any(|x| x == p)
+            { return false; }
+        }
+        true
+    }
+}
+
+/// Reads records from anything implementing `BufRead`. Empty lines are skipped.
+pub struct LogReader<R: BufRead> {
+    reader: R,
+    format: InputFormat,
+    query:  Query,
+    line:   usize,
+}
+
+impl<R: BufRead> LogReader<R> {
+    /// Creates a new `LogReader`.
+    /// # Arguments
+    /// * `reader: R` - The source.
+    /// * `format: InputFormat` - The format of the lines.
+    /// # Returns
+    /// A new `LogReader`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::{record::Level, reader::{LogReader, InputFormat, Query}};
+    ///
+    /// let log = "[2022-01-01T12:00:00Z] Started.\n\
+    ///            [2022-01-01T12:00:05Z][Warning] Disk almost full. free=\"2 GiB\"\n\
+    ///            [2022-01-01T12:00:09Z][Error] Crashed.\n";
+    ///
+    /// let all: Vec<_> = LogReader::new(log.as_bytes(), InputFormat::Text).collect();
+    /// assert_eq!(3, all.len());
+    ///
+    /// let warnings: Vec<_> = LogReader::new(log.as_bytes(), InputFormat::Text)
+    ///                                  .with_query(Query::new().with_min_level(Level::Warning))
+    ///                                  .map(|r| r.unwrap())
+    ///                                  .collect();
+    /// assert_eq!(2, warnings.len());
+    /// assert_eq!("Disk almost full.", warnings[0].message);
+    /// assert_eq!(Some("2 GiB"), warnings[0].field("free"));
+    /// ```
+    #[must_use]
+    pub fn new(reader: R, format: InputFormat) -> LogReader<R> {
+        LogReader { reader,
+                    format,
+                    query: Query::new(),
+                    line:  0 }
+    }
+    /// Only yields records matching `query`. Lines which cannot be parsed are still reported.
+    #[must_use]
+    pub fn with_query(mut self, query: Query) -> LogReader<R> {
+        self.query = query;
+        self
+    }
+}
+
+impl LogReader<BufReader<File>> {
+    /// Opens a log file.
+    /// # Arguments
+    /// * `path: &str` - The path of the file.
+    /// * `format: InputFormat` - The format of the lines.
+    /// # Returns
+    /// A `Result<LogReader<BufReader<File>>, String>`.
+    pub fn open(path: &str, format: InputFormat) -> Result<LogReader<BufReader<File>>, String> {
+        match File::open(path) {
+            Ok(f)  => Ok(LogReader::new(BufReader::new(f), format)),
+            Err(e) => Err(format!("Problem opening file: {:?}", e)),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for LogReader<R> {
+    type Item = Result<Record, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut buf = String::new();
+        loop {
+            buf.clear();
+            self.line += 1;
+            match self.reader.read_line(&mut buf) {
+                Ok(0)  => { return None; }
+                Ok(_)  => { }
+                Err(e) => { return Some(Err(ParseError { line:   self.line,
+                                                         reason: format!("Problem reading: {:?}", e) })); }
+            }
+            if buf.trim().is_empty()
+            { continue; }
+            match parse_line(&buf, self.format) {
+                Ok(r) if self.query.matches(&r) => { return Some(Ok(r)); }
+                Ok(_)                           => { }
+                Err(e)                          => { return Some(Err(ParseError { line: self.line, reason: e })); }
+            }
+        }
+    }
+}
+
+/// Follows a growing log file, like `tail -f`.
+/// \
+/// The iterator never ends on its own: when there are no new lines, it waits for the poll interval and tries again.
+/// If the file shrinks, e.g. because it was truncated, reading starts over from the beginning.
+pub struct Tail {
+    reader:  BufReader<File>,
+    format:  InputFormat,
+    query:   Query,
+    poll:    Duration,
+    partial: String,
+    pos:     u64,
+    line:    usize,
+}
+
+impl Tail {
+    /// Opens a log file for following.
+    /// # Arguments
+    /// * `path: &str` - The path of the file.
+    /// * `format: InputFormat` - The format of the lines.
+    /// * `from_start: bool` - Whether to yield the lines already in the file, too.
+    /// # Returns
+    /// A `Result<Tail, String>`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::reader::{Tail, InputFormat};
+    /// use std::io::Write;
+    ///
+    /// let path = std::env::temp_dir().join("librapid_tail_example.log");
+    /// std::fs::write(&path, "[2022-01-01T12:00:00Z] First.\n").unwrap();
+    ///
+    /// let mut tail = Tail::open(path.to_str().unwrap(), InputFormat::Text, true).unwrap();
+    /// assert_eq!("First.", tail.next().unwrap().unwrap().message);
+    ///
+    /// let mut f = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
+    /// writeln!(f, "[2022-01-01T12:00:01Z] Second.").unwrap();
+    /// assert_eq!("Second.", tail.next().unwrap().unwrap().message);
+    /// ```
+    pub fn open(path: &str, format: InputFormat, from_start: bool) -> Result<Tail, String> {
+        let mut file = File::open(path).map_err(|e| format!("Problem opening file: {:?}", e))?;
+        let pos      = match from_start {
+            true  => 0,
+            false => file.seek(SeekFrom::End(0)).map_err(|e| format!("Problem seeking: {:?}", e))?,
+        };
+        Ok(Tail { reader:  BufReader::new(file),
+                  format,
+                  query:   Query::new(),
+                  poll:    Duration::from_millis(250),
+                  partial: String::new(),
+                  pos,
+                  line:    0 })
+    }
+    /// Only yields records matching `query`.
+    #[must_use]
+    pub fn with_query(mut self, query: Query) -> Tail {
+        self.query = query;
+        self
+    }
+    /// Sets the time to wait before looking for new lines again. The default is 250 ms.
+    #[must_use]
+    pub fn with_poll_interval(mut self, poll: Duration) -> Tail {
+        self.poll = poll;
+        self
+    }
+}
+
+impl Iterator for Tail {
+    type Item = Result<Record, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut buf = String::new();
+        loop {
+            buf.clear();
+            let n = match self.reader.read_line(&mut buf) {
+                Ok(n)  => n,
+                Err(e) => { return Some(Err(ParseError { line:   self.line + 1,
+                                                         reason: format!("Problem reading: {:?}", e) })); }
+            };
+            self.pos += n as u64;
+            self.partial.push_str(&buf);
+
+            if !self.partial.ends_with('\n') {
+                if n == 0 {
+                    let len = self.reader.get_ref().metadata().map(|m| m.len()).unwrap_or(self.pos);
+                    if len < self.pos {
+                        self.partial.clear();
+                        self.pos = self.reader.seek(SeekFrom::Start(0)).unwrap_or(0);
+                    }
+                }
+                thread::sleep(self.poll);
+                continue;
+            }
+
+            let line = std::mem::take(&mut self.partial);
+            self.line += 1;
+            if line.trim().is_empty()
+            { continue; }
+            match parse_line(&line, self.format) {
+                Ok(r) if self.query.matches(&r) => { return Some(Ok(r)); }
+                Ok(_)                           => { }
+                Err(e)                          => { return Some(Err(ParseError { line: self.line, reason: e })); }
+            }
+        }
+    }
+}
+
+/// Parses a single line of log output.
+/// # Arguments
+/// * `line: &str` - The line, with or without a trailing line break.
+/// * `format: InputFormat` - The format of the line.
+/// # Returns
+/// A `Result<Record, String>`. The `Record` if it was successful, otherwise the reason as a `String`.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::rapidlogging::{record::{Record, Level},
+///                                        formatters::{LogFormatter, JsonFormatter, LogfmtFormatter, TextFormatter},
+///                                        reader::{parse_line, InputFormat}};
+///
+/// let r = Record::new("Say \"hi\".").with_prefix("Warning")
+///                                   .with_field("user", "bob");
+///
+/// let from_json   = parse_line(&JsonFormatter::new().format(&r), InputFormat::Auto).unwrap();
+/// let from_logfmt = parse_line(&LogfmtFormatter::new().format(&r), InputFormat::Auto).unwrap();
+/// for p in [from_json, from_logfmt] {
+///     assert_eq!(r.message, p.message);
+///     assert_eq!(r.prefixes, p.prefixes);
+///     assert_eq!(r.fields, p.fields);
+///     assert_eq!(Level::Warning, p.level());
+/// }
+///
+/// // Messages which look like prefixes or fields survive the text format, too.
+/// for msg in ["Set x=5", "[not a prefix] \"quoted\"", "Two\nlines", "plain"] {
+///     let r = Record::new(msg).with_field("k", "v w");
+///     let p = parse_line(&TextFormatter::new().format(&r), InputFormat::Text).unwrap();
+///     assert_eq!((r.message, r.fields), (p.message, p.fields));
+/// }
+///
+/// // So do prefixes with brackets.
+/// let r = Record::new("hello").with_prefix("a]b").with_prefix("[c\\");
+/// let p = parse_line(&TextFormatter::new().format(&r), InputFormat::Text).unwrap();
+/// assert_eq!((r.prefixes, r.message), (p.prefixes, p.message));
+///
+/// // Fields named like the keys of a format do not replace the record's own values.
+/// let r = Record::new("real").with_field("message", "spoofed")
+///                            .with_field("msg", "spoofed")
+///                            .with_field("_thread", "x")
+///                            .with_field("thread", "y");
+/// let from_json   = parse_line(&JsonFormatter::new().format(&r), InputFormat::Json).unwrap();
+/// let from_logfmt = parse_line(&LogfmtFormatter::new().format(&r), InputFormat::Logfmt).unwrap();
+/// let from_text   = parse_line(&TextFormatter::new().format(&r), InputFormat::Text).unwrap();
+/// for p in [from_json, from_logfmt, from_text] {
+///     assert_eq!(("real", &r.fields, None), (p.message.as_str(), &p.fields, p.thread));
+/// }
+///
+/// // Surrogate pairs must be complete.
+/// let json = |msg: &str| format!("{{\"timestamp\":\"2022-01-01T12:00:00Z\",\"message\":\"{}\"}}", msg);
+/// assert_eq!("😀", parse_line(&json("\\ud83d\\ude00"), InputFormat::Json).unwrap().message);
+/// assert!(parse_line(&json("\\ud83d\\u0041"), InputFormat::Json).is_err());
+/// assert!(parse_line(&json("\\ude00"), InputFormat::Json).is_err());
+///
+/// assert!(parse_line("no timestamp", InputFormat::Text).is_err());
+/// ```
+pub fn parse_line(line: &str, format: InputFormat) -> Result<Record, String> {
+    let line = line.trim_end_matches(['\n', '\r']);
+    match format {
+        InputFormat::Text   => parse_text(line),
+        InputFormat::Json   => parse_json(line),
+        InputFormat::Logfmt => parse_logfmt(line),
+        InputFormat::Auto   => match line.trim_start().chars().next() {
+            Some('{') => parse_json(line),
+            Some('[') => parse_text(line),
+            _         => parse_logfmt(line),
+        },
+    }
+}
+
+/// A record without any of the values `Record::new` fills in automatically.
+fn blank_record() -> Record {
+    let mut r  = Record::new("");
+    r.thread   = None;
+    r.elapsed  = Duration::ZERO;
+    r
+}
+
+fn set_timestamp(record: &mut Record, s: &str) -> Result<(), String> {
+    if let Some(secs) = s.strip_prefix('+').and_then(|x| x.strip_suffix('s')) {
+        let secs: f64 = secs.parse().map_err(|_| format!("Invalid elapsed time: {}", s))?;
+        record.timestamp = DateTime::<Utc>::UNIX_EPOCH;
+        record.elapsed   = Duration::from_secs_f64(secs);
+        return Ok(());
+    }
+    match DateTime::parse_from_rfc3339(s) {
+        Ok(t)  => { record.timestamp = t.with_timezone(&Utc); Ok(()) }
+        Err(_) => Err(format!("Invalid timestamp: {}", s)),
+    }
+}
+
+/// Moves the keys `thread` and `location` into their own members of `record` and unescapes the keys of fields.
+fn set_key(record: &mut Record, key: String, value: String, reserved: &[&str]) {
+    match key.as_str() {
+        "thread"   => { record.thread = Some(value); }
+        "location" => {
+            let parsed = value.rsplit_once(':')
+                              .and_then(|(f, l)| l.parse::<u32>().ok().map(|l| (f.to_owned(), l)));
+            match parsed {
+                Some((file, line)) => { record.location = Some(Location { file, line, module: String::new() }); }
+                None               => { record.fields.push((key, value)); }
+            }
+        }
+        _          => { record.fields.push((unescape_key(key, reserved), value)); }
+    }
+}
+
+fn parse_text(line: &str) -> Result<Record, String> {
+    let mut record = blank_record();
+    let mut rest   = line.strip_prefix('[').ok_or("Line does not start with a timestamp.")?;
+    let end        = rest.find(']').ok_or("Unterminated timestamp.")?;
+    set_timestamp(&mut record, &rest[..end])?;
+    rest = &rest[end + 1..];
+
+    while let Some(r) = rest.strip_prefix('[') {
+        let (prefix, len) = unbracket(r)?;
+        record.prefixes.push(prefix);
+        rest = &r[len..];
+    }
+    let rest = rest.strip_prefix(' ').unwrap_or(rest);
+
+    // A message containing `=` is always quoted, so the fields begin with the first token containing one.
+    let fields = match rest.strip_prefix('"') {
+        Some(quoted) => {
+            let mut chars  = quoted.chars().peekable();
+            record.message = unquote(&mut chars).map_err(|e| format!("{} in message", e))?;
+            let fields     = chars.collect::<String>();
+            if !fields.is_empty() && !fields.starts_with(' ')
+            { return Err("Unexpected character after message.".to_owned()); }
+            fields
+        }
+        None         => {
+            let split = match rest.find('=') {
+                Some(eq) => rest[..eq].rfind(' ').unwrap_or(0),
+                None     => rest.len(),
+            };
+            record.message = rest[..split].to_owned();
+            rest[split..].to_owned()
+        }
+    };
+    for (k, v) in parse_logfmt_pairs(&fields)?
+    { set_key(&mut record, k, v, &TEXT_KEYS); }
+    Ok(record)
+}
+
+/// Reads a prefix with escapes, after the opening bracket, up to and including the closing bracket.
+/// # Returns
+/// The prefix and the number of bytes read.
+fn unbracket(s: &str) -> Result<(String, usize), String> {
+    let mut prefix = String::new();
+    let mut chars  = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            ']'  => { return Ok((prefix, i + 1)); }
+            '\\' => match chars.next() {
+                Some((_, 'n')) => { prefix.push('\n'); }
+                Some((_, 'r')) => { prefix.push('\r'); }
+                Some((_, 't')) => { prefix.push('\t'); }
+                Some((_, c))   => { prefix.push(c); }
+                None           => { return Err("Unterminated escape sequence in prefix.".to_owned()); }
+            },
+            c    => { prefix.push(c); }
+        }
+    }
+    Err("Unterminated prefix.".to_owned())
+}
+
+/// Reads a quoted value with escapes, after the opening quote, up to and including the closing quote.
+fn unquote(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<String, String> {
+    let mut value = String::new();
+    loop {
+        match chars.next() {
+            Some('"')  => { return Ok(value); }
+            Some('\\') => match chars.next() {
+                Some('n') => { value.push('\n'); }
+                Some('r') => { value.push('\r'); }
+                Some('t') => { value.push('\t'); }
+                Some(c)   => { value.push(c); }
+                None      => { return Err("Unterminated escape sequence".to_owned()); }
+            },
+            Some(c)    => { value.push(c); }
+            None       => { return Err("Unterminated quote".to_owned()); }
+        }
+    }
+}
+
+fn parse_logfmt(line: &str) -> Result<Record, String> {
+    let mut record        = blank_record();
+    let mut has_timestamp = false;
+    for (k, v) in parse_logfmt_pairs(line)? {
+        match k.as_str() {
+            "timestamp" => { set_timestamp(&mut record, &v)?; has_timestamp = true; }
+            "prefix"    => { record.prefixes.push(v); }
+            "msg"       => { record.message = v; }
+            _           => { set_key(&mut record, k, v, &LOGFMT_KEYS); }
+        }
+    }
+    match has_timestamp {
+        true  => Ok(record),
+        false => Err("Missing timestamp.".to_owned()),
+    }
+}
+
+/// Parses `key=value key2="quoted value"`. Every token must contain a `=`.
+fn parse_logfmt_pairs(s: &str) -> Result<Vec<(String, String)>, String> {
+    let mut res   = Vec::new();
+    let mut chars = s.chars().peekable();
+    loop {
+        while chars.peek() == Some(&' ')
+        { chars.next(); }
+        if chars.peek().is_none()
+        { return Ok(res); }
+
+        let mut key = String::new();
+        loop {
+            match chars.next() {
+                Some('=')                     => { break; }
+                Some(c) if c == ' ' || c == '"' => { return Err(format!("Invalid key: {}", key)); }
+                Some(c)                       => { key.push(c); }
+                None                          => { return Err(format!("Missing value for key: {}", key)); }
+            }
+        }
+        if key.is_empty()
+        { return Err("Empty key.".to_owned()); }
+
+        let mut value = String::new();
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            value = unquote(&mut chars).map_err(|e| format!("{} in value of key: {}", e, key))?;
+            if !matches!(chars.peek(), None | Some(' '))
+            { return Err(format!("Unexpected character after value of key: {}", key)); }
+        }
+        else {
+            while let Some(&c) = chars.peek() {
+                if c == ' '
+                { break; }
+                if c == '"'
+                { return Err(format!("Unexpected quote in value of key: {}", key)); }
+                value.push(c);
+                chars.next();
+            }
+        }
+        res.push((key, value));
+    }
+}
+
+enum JsonValue {
+    Text(String),
+    List(Vec<String>),
+}
+
+fn parse_json(line: &str) -> Result<Record, String> {
+    let mut record        = blank_record();
+    let mut has_timestamp = false;
+    let mut p             = JsonParser { chars: line.chars().collect(), pos: 0 };
+
+    p.expect('{')?;
+    p.skip_ws();
+    if p.peek() == Some('}')
+    { return Err("Missing timestamp.".to_owned()); }
+    loop {
+        let key = p.string()?;
+        p.expect(':')?;
+        match (key.as_str(), p.value()?) {
+            ("timestamp", JsonValue::Text(v)) => { set_timestamp(&mut record, &v)?; has_timestamp = true; }
+            ("prefixes",  JsonValue::List(v)) => { record.prefixes = v; }
+            ("message",   JsonValue::Text(v)) => { record.message = v; }
+            (_,           JsonValue::Text(v)) => { set_key(&mut record, key, v, &JSON_KEYS); }
+            (_,           JsonValue::List(v)) => { record.fields.push((unescape_key(key, &JSON_KEYS), v.join(","))); }
+        }
+        p.skip_ws();
+        match p.next() {
+            Some(',') => { }
+            Some('}') => { break; }
+            _         => { return Err(format!("Expected ',' or '}}' at index {}.", p.pos)); }
+        }
+    }
+    match has_timestamp {
+        true  => Ok(record),
+        false => Err("Missing timestamp.".to_owned()),
+    }
+}
+
+/// Just enough JSON for flat objects of strings, arrays of strings and literals.
+struct JsonParser {
+    chars: Vec<char>,
+    pos:   usize,
+}
+
+impl JsonParser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.pos += 1;
+        c
+    }
+
+    fn skip_ws(&mut self) {
+        while matches!(self.peek(), Some(' ') | Some('\t') | Some('\r') | Some('\n'))
+        { self.pos += 1; }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        self.skip_ws();
+        match self.next() {
+            Some(x) if x == c => Ok(()),
+            _                 => Err(format!("Expected '{}' at index {}.", c, self.pos - 1)),
+        }
+    }
+
+    fn value(&mut self) -> Result<JsonValue, String> {
+        self.skip_ws();
+        match self.peek() {
+            Some('"') => Ok(JsonValue::Text(self.string()?)),
+            Some('[') => {
+                self.pos += 1;
+                let mut res = Vec::new();
+                self.skip_ws();
+                if self.peek() == Some(']') {
+                    self.pos += 1;
+                    return Ok(JsonValue::List(res));
+                }
+                loop {
+                    match self.value()? {
+                        JsonValue::Text(s) => { res.push(s); }
+                        JsonValue::List(_) => { return Err(format!("Nested array at index {}.", self.pos)); }
+                    }
+                    self.skip_ws();
+                    match self.next() {
+                        Some(',') => { }
+                        Some(']') => { return Ok(JsonValue::List(res)); }
+                        _         => { return Err(format!("Expected ',' or ']' at index {}.", self.pos - 1)); }
+                    }
+                }
+            }
+            Some(_)   => {
+                // Numbers, `true`, `false` and `null` are kept as written.
+                let start = self.pos;
+                while let Some(c) = self.peek() {
+                    if c == ',' || c == '}' || c == ']' || c.is_whitespace()
+                    { break; }
+                    self.pos += 1;
+                }
+                if start == self.pos
+                { return Err(format!("Expected a value at index {}.", start)); }
+                Ok(JsonValue::Text(self.chars[start..self.pos].iter().collect()))
+            }
+            None      => Err("Unexpected end of line.".to_owned()),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+        let mut res = String::new();
+        loop {
+            match self.next() {
+                Some('"')  => { return Ok(res); }
+                Some('\\') => match self.next() {
+                    Some('n') => { res.push('\n'); }
+                    Some('r') => { res.push('\r'); }
+                    Some('t') => { res.push('\t'); }
+                    Some('b') => { res.push('\u{8}'); }
+                    Some('f') => { res.push('\u{c}'); }
+                    Some('u') => { res.push(self.unicode_escape()?); }
+                    Some(c)   => { res.push(c); }
+                    None      => { return Err("Unterminated escape sequence.".to_owned()); }
+                },
+                Some(c)    => { res.push(c); }
+                None       => { return Err("Unterminated string.".to_owned()); }
+            }
+        }
+    }
+
+    fn hex4(&mut self) -> Result<u32, String> {
+        if self.pos + 4 > self.chars.len()
+        { return Err("Incomplete unicode escape.".to_owned()); }
+        let s: String = self.chars[self.pos..self.pos + 4].iter().collect();
+        self.pos += 4;
+        u32::from_str_radix(&s, 16).map_err(|_| format!("Invalid unicode escape: {}", s))
+    }
+
+    fn unicode_escape(&mut self) -> Result<char, String> {
+        let first = self.hex4()?;
+        let code  = match first {
+            0xD800..=0xDBFF => {
+                if self.next() != Some('\\') || self.next() != Some('u')
+                { return Err("Unpaired surrogate.".to_owned()); }
+                let second = self.hex4()?;
+                if !(0xDC00..=0xDFFF).contains(&second)
+                { return Err("Unpaired surrogate.".to_owned()); }
+                0x10000 + ((first - 0xD800) << 10) + (second - 0xDC00)
+            }
+            0xDC00..=0xDFFF => { return Err("Unpaired surrogate.".to_owned()); }
+            _               => first,
+        };
+        char::from_u32(code).ok_or_else(|| format!("Invalid code point: {:x}", code))
+    }
+}
@@ -0,0 +1,250 @@
+//! The structured log record which is passed to the formatters.
+use chrono::{DateTime, Utc};
+use std::{fmt::Display, sync::OnceLock, time::{Duration, Instant}};
+
+/// The reference point for `Record::elapsed`.
+static START: OnceLock<Instant> = OnceLock::new();
+
+/// A single, structured log entry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    /// The point in time at which the record was created.
+    pub timestamp: DateTime<Utc>,
+    /// The prefixes of the record, e.g. `Warning`.
+    pub prefixes:  Vec<String>,
+    /// The actual log message.
+    pub message:   String,
+    /// Arbitrary key-value pairs attached to the record, in insertion order.
+    pub fields:    Vec<(String, String)>,
+    /// The monotonic time passed between the first and this record of the process.
+    pub elapsed:   Duration,
+    /// The name of the thread which created the record.
+    pub thread:    Option<String>,
+    /// The place in the source code which created the record. Set by `rapid_log!`.
+    pub location:  Option<Location>,
+}
+
+/// A place in the source code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// The file, as given by `file!()`.
+    pub file:   String,
+    /// The line, as given by `line!()`.
+    pub line:   u32,
+    /// The module, as given by `module_path!()`.
+    pub module: String,
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)
+    }
+}
+
+impl Record {
+    /// Creates a new `Record` with the current time and thread, no prefixes and no fields.
+    /// # Arguments
+    /// * `msg: &str` - The log message.
+    /// # Returns
+    /// A new `Record`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::record::Record;
+    ///
+    /// let r = Record::new("Server started.");
+    /// assert_eq!("Server started.", r.message);
+    /// assert!(r.prefixes.is_empty());
+    /// assert!(r.fields.is_empty());
+    /// assert_eq!(Some("main"), r.thread.as_deref());
+    /// ```
+    #[must_use]
+    pub fn new(msg: &str) -> Record {
+        let now    = Instant::now();
+        let thread = std::thread::current();
+        Record { timestamp: Utc::now(),
+                 prefixes:  Vec::new(),
+                 message:   msg.to_owned(),
+                 fields:    Vec::new(),
+                 elapsed:   now.duration_since(*START.get_or_init(|| now)),
+                 thread:    Some(match thread.name() {
+                                Some(n) => n.to_owned(),
+                                None    => format!("{:?}", thread.id()),
+                            }),
+                 location:  None }
+    }
+    /// Adds a prefix to `self`.
+    /// # Arguments
+    /// * `prefix: &str` - The prefix to be appended.
+    /// # Returns
+    /// The modified `Record`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::record::Record;
+    ///
+    /// let r = Record::new("Disk almost full.").with_prefix("Warning");
+    /// assert_eq!(vec!["Warning".to_owned()], r.prefixes);
+    /// ```
+    #[must_use]
+    pub fn with_prefix(mut self, prefix: &str) -> Record {
+        self.prefixes.push(prefix.to_owned());
+        self
+    }
+    /// Adds a key-value field to `self`.
+    /// # Arguments
+    /// * `key: &str` - The name of the field.
+    /// * `value: V` - The value of the field. Anything implementing `Display` is accepted.
+    /// # Returns
+    /// The modified `Record`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::record::Record;
+    ///
+    /// let r = Record::new("Request handled.").with_field("status", 200)
+    ///                                        .with_field("path", "/index.html");
+    /// assert_eq!(("status".to_owned(), "200".to_owned()), r.fields[0]);
+    /// assert_eq!(Some("/index.html"), r.field("path"));
+    /// ```
+    #[must_use]
+    pub fn with_field<V: Display>(mut self, key: &str, value: V) -> Record {
+        self.fields.push((key.to_owned(), value.to_string()));
+        self
+    }
+    /// Adds the prefix belonging to `level` to `self`.
+    /// # Arguments
+    /// * `level: Level` - The level of the record.
+    /// # Returns
+    /// The modified `Record`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::record::{Record, Level};
+    ///
+    /// let r = Record::new("Connection lost.").with_level(Level::Error);
+    /// assert_eq!(vec!["Error".to_owned()], r.prefixes);
+    /// assert_eq!(Level::Error, r.level());
+    /// ```
+    #[must_use]
+    pub fn with_level(self, level: Level) -> Record {
+        self.with_prefix(level.as_str())
+    }
+    /// Gets the level of `self`, which is the first prefix that names a level.
+    /// Records without such a prefix are treated as `Level::Info`.
+    /// # Returns
+    /// A `Level`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::record::{Record, Level};
+    ///
+    /// assert_eq!(Level::Warning, Record::new("Slow query.").with_prefix("Database")
+    ///                                                      .with_prefix("Warning")
+    ///                                                      .level());
+    /// assert_eq!(Level::Info, Record::new("Hello.").level());
+    /// ```
+    #[must_use]
+    pub fn level(&self) -> Level {
+        self.prefixes.iter()
+                     .find_map(|p| Level::from_prefix(p))
+                     .unwrap_or(Level::Info)
+    }
+    /// Sets the source location of `self`. Usually called through `rapid_log!`.
+    /// # Arguments
+    /// * `file: &str` - The file.
+    /// * `line: u32` - The line.
+    /// * `module: &str` - The module path.
+    /// # Returns
+    /// The modified `Record`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::record::Record;
+    ///
+    /// let r = Record::new("Here.").with_location(file!(), 7, module_path!());
+    /// assert_eq!(7, r.location.unwrap().line);
+    /// ```
+    #[must_use]
+    pub fn with_location(mut self, file: &str, line: u32, module: &str) -> Record {
+        self.location = Some(Location { file:   file.to_owned(),
+                                        line,
+                                        module: module.to_owned() });
+        self
+    }
+    /// Gets the value of the first field called `key`.
+    /// # Arguments
+    /// * `key: &str` - The name of the field.
+    /// # Returns
+    /// An `Option<&str>`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::record::Record;
+    ///
+    /// let r = Record::new("Login.").with_field("user", "alice");
+    /// assert_eq!(Some("alice"), r.field("user"));
+    /// assert_eq!(None, r.field("password"));
+    /// ```
+    #[must_use]
+    pub fn field(&self, key: &str) -> Option<&str> {
+        self.fields.iter()
+                   .find(|(k, _)| k == key)
+                   .map(|(_, v)| v.as_str())
+    }
+}
+
+/// The severity of a record. Levels are ordered from `Trace` (least severe) to `Error` (most severe).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Trace,
+    Debug,
+    Info,
+    Warning,
+    Error,
+}
+
+impl Level {
+    /// Interprets a prefix as a level. The comparison ignores case and accepts the short forms `Warn` and `Err`.
+    /// # Arguments
+    /// * `prefix: &str` - The prefix, e.g. `Warning`.
+    /// # Returns
+    /// An `Option<Level>`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::record::Level;
+    ///
+    /// assert_eq!(Some(Level::Warning), Level::from_prefix("Warning"));
+    /// assert_eq!(Some(Level::Warning), Level::from_prefix("WARN"));
+    /// assert_eq!(None, Level::from_prefix("Database"));
+    /// ```
+    #[must_use]
+    pub fn from_prefix(prefix: &str) -> Option<Level> {
+        match prefix.to_ascii_lowercase().as_str() {
+            "trace"            => Some(Level::Trace),
+            "debug"            => Some(Level::Debug),
+            "info"             => Some(Level::Info),
+            "warn" | "warning" => Some(Level::Warning),
+            "err"  | "error"   => Some(Level::Error),
+            _                  => None,
+        }
+    }
+    /// Gets the name of `self` as it is written into prefixes.
+    /// # Returns
+    /// A `&'static str`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::record::Level;
+    ///
+    /// assert_eq!("Error", Level::Error.as_str());
+    /// ```
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Level::Trace   => "Trace",
+            Level::Debug   => "Debug",
+            Level::Info    => "Info",
+            Level::Warning => "Warning",
+            Level::Error   => "Error",
+        }
+    }
+}
+
+impl std::fmt::Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
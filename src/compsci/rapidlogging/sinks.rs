@@ -0,0 +1,217 @@
+//! Destinations for log output.
+use std::{collections::VecDeque,
+          fs::{File, OpenOptions},
+          io::{BufWriter, Write},
+          sync::{Arc, Mutex}};
+
+/// Trait for everything a `Logger` can write to.
+/// Sinks must be `Send`, as a `Logger` may be shared between threads.
+pub trait Sink: Send {
+    /// Writes one formatted line.
+    /// # Arguments
+    /// * `line: &str` - The formatted record, without a trailing line break.
+    /// # Returns
+    /// A `Result<(), String>`. `()` if it was successful, otherwise the error message as a `String`.
+    fn write_line(&mut self, line: &str) -> Result<(), String>;
+    /// Makes sure that everything written so far has reached its destination.
+    /// # Returns
+    /// A `Result<(), String>`. `()` if it was successful, otherwise the error message as a `String`.
+    fn flush(&mut self) -> Result<(), String>;
+}
+
+/// Writes to the standard output.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StdoutSink;
+
+/// Writes to the standard error output.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StderrSink;
+
+/// Appends to a file. The file is created if it does not exist and opened on the first write.
+#[derive(Debug)]
+pub struct FileSink {
+    path: String,
+    file: Option<BufWriter<File>>,
+}
+
+/// Keeps the last `capacity` lines in memory. Meant for tests.
+/// \
+/// Clones share the same storage, so keep a clone to read the lines after handing the sink to a `Logger`.
+#[derive(Debug, Clone)]
+pub struct MemorySink {
+    capacity: usize,
+    lines:    Arc<Mutex<VecDeque<String>>>,
+}
+
+/// Writes to anything implementing `std::io::Write`.
+#[derive(Debug)]
+pub struct WriterSink<W: Write + Send> {
+    writer: W,
+}
+
+impl Sink for StdoutSink {
+    fn write_line(&mut self, line: &str) -> Result<(), String> {
+        writeln!(std::io::stdout().lock(), "{}", line)
+            .map_err(|e| format!("Problem writing to stdout: {:?}", e))
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        std::io::stdout().flush()
+                         .map_err(|e| format!("Problem flushing stdout: {:?}", e))
+    }
+}
+
+impl Sink for StderrSink {
+    fn write_line(&mut self, line: &str) -> Result<(), String> {
+        writeln!(std::io::stderr().lock(), "{}", line)
+            .map_err(|e| format!("Problem writing to stderr: {:?}", e))
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        std::io::stderr().flush()
+                         .map_err(|e| format!("Problem flushing stderr: {:?}", e))
+    }
+}
+
+impl FileSink {
+    /// Creates a new `FileSink`.
+    /// # Arguments
+    /// * `path: &str` - The path of the file.
+    /// # Returns
+    /// A new `FileSink`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::{Logger, sinks::FileSink};
+    ///
+    /// let l = Logger::empty(10).with_sink(FileSink::new("log.txt"));
+    /// let _ = l.log(None, "Written to log.txt.");
+    /// ```
+    #[must_use]
+    pub fn new(path: &str) -> FileSink {
+        FileSink { path: path.to_owned(),
+                   file: None }
+    }
+    /// Gets the path of the file.
+    /// # Returns
+    /// A `&str`.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl Sink for FileSink {
+    fn write_line(&mut self, line: &str) -> Result<(), String> {
+        if self.file.is_none() {
+            let file = OpenOptions::new().create(true)
+                                         .append(true)
+                                         .open(&self.path)
+                                         .map_err(|e| format!("Problem opening or creating file: {:?}", e))?;
+            self.file = Some(BufWriter::new(file));
+        }
+        match &mut self.file {
+            Some(f) => writeln!(f, "{}", line).map_err(|e| format!("Problem writing to file: {:?}", e)),
+            None    => Ok(()),
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        match &mut self.file {
+            Some(f) => f.flush().map_err(|e| format!("Problem writing to file: {:?}", e)),
+            None    => Ok(()),
+        }
+    }
+}
+
+impl MemorySink {
+    /// Creates a new `MemorySink`.
+    /// # Arguments
+    /// * `capacity: usize` - The maximum number of lines kept. Older lines are discarded first.
+    /// # Returns
+    /// A new `MemorySink`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::{Logger, sinks::MemorySink};
+    ///
+    /// let mem = MemorySink::new(2);
+    /// let l   = Logger::empty(1).with_sink(mem.clone());
+    /// let _ = l.log(None, "One.");
+    /// let _ = l.log(None, "Two.");
+    /// let _ = l.log(None, "Three.");
+    ///
+    /// let lines = mem.lines();
+    /// assert_eq!(2, lines.len());
+    /// assert!(lines[0].ends_with("] Two."));
+    /// assert!(lines[1].ends_with("] Three."));
+    /// ```
+    #[must_use]
+    pub fn new(capacity: usize) -> MemorySink {
+        MemorySink { capacity,
+                     lines: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))) }
+    }
+    /// Gets a copy of the stored lines, oldest first.
+    /// # Returns
+    /// A `Vec<String>`.
+    #[must_use]
+    pub fn lines(&self) -> Vec<String> {
+        match self.lines.lock() {
+            Ok(l)  => l.iter().cloned().collect(),
+            Err(e) => e.into_inner().iter().cloned().collect(),
+        }
+    }
+    /// Removes all stored lines.
+    pub fn clear(&self) {
+        match self.lines.lock() {
+            Ok(mut l)  => l.clear(),
+            Err(e)     => e.into_inner().clear(),
+        }
+    }
+}
+
+impl Sink for MemorySink {
+    fn write_line(&mut self, line: &str) -> Result<(), String> {
+        if self.capacity == 0
+        { return Ok(()); }
+        let mut lines = match self.lines.lock() {
+            Ok(l)  => l,
+            Err(e) => e.into_inner(),
+        };
+        if lines.len() == self.capacity
+        { lines.pop_front(); }
+        lines.push_back(line.to_owned());
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+impl<W: Write + Send> WriterSink<W> {
+    /// Creates a new `WriterSink`.
+    /// # Arguments
+    /// * `writer: W` - The destination, e.g. a `TcpStream`.
+    /// # Returns
+    /// A new `WriterSink`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::{Logger, sinks::WriterSink};
+    ///
+    /// let l = Logger::empty(10).with_sink(WriterSink::new(std::io::sink()));
+    /// let _ = l.log(None, "Discarded.");
+    /// ```
+    #[must_use]
+    pub fn new(writer: W) -> WriterSink<W> {
+        WriterSink { writer }
+    }
+}
+
+impl<W: Write + Send> Sink for WriterSink<W> {
+    fn write_line(&mut self, line: &str) -> Result<(), String> {
+        writeln!(self.writer, "{}", line).map_err(|e| format!("Problem writing: {:?}", e))
+    }
+
+    fn flush(&mut self) -> Result<(), String> {
+        self.writer.flush().map_err(|e| format!("Problem flushing: {:?}", e))
+    }
+}
@@ -0,0 +1,263 @@
+//! Scoped timing spans which log their duration and collect statistics.
+use std::{collections::HashMap,
+          sync::{Mutex, MutexGuard},
+          thread::{self, ThreadId},
+          time::{Duration, Instant}};
+use super::{Log, record::{Record, Level}};
+
+/// Times spans and reports them through a logger.
+/// \
+/// Spans entered while another span of the same profiler is active on the same thread are nested: their name is
+/// prefixed with the names of the enclosing spans, separated by `/`.
+pub struct Profiler<'a, L: Log> {
+    /// Determines whether every finished span is logged on its own, as a `Level::Debug` record.
+    pub log_spans: bool,
+        logger:    &'a L,
+        durations: Mutex<HashMap<String, Vec<Duration>>>,
+        /// The names of the spans currently entered on each thread, outermost first.
+        stacks:    Mutex<HashMap<ThreadId, Vec<String>>>,
+}
+
+/// An active span. The time between creation and drop is recorded.
+#[must_use = "A span measures the time until it is dropped."]
+pub struct Span<'p, 'a, L: Log> {
+    profiler: &'p Profiler<'a, L>,
+    path:     String,
+    thread:   ThreadId,
+    depth:    usize,
+    start:    Instant,
+}
+
+/// Aggregated statistics of all spans with the same name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanStats {
+    /// The full name of the span, including the names of enclosing spans.
+    pub name:  String,
+    /// How often the span was entered.
+    pub count: usize,
+    /// The sum of all durations.
+    pub total: Duration,
+    /// The arithmetic mean of all durations.
+    pub mean:  Duration,
+    /// The shortest duration.
+    pub min:   Duration,
+    /// The longest duration.
+    pub max:   Duration,
+    /// The median (nearest rank).
+    pub p50:   Duration,
+    /// The 95th percentile (nearest rank).
+    pub p95:   Duration,
+}
+
+impl<'a, L: Log> Profiler<'a, L> {
+    /// Creates a new `Profiler` which logs to `logger`.
+    /// # Arguments
+    /// * `logger: &L` - The logger, e.g. a `Logger` or a `BackgroundLogger`.
+    /// # Returns
+    /// A new `Profiler`.
+    #[must_use]
+    pub fn new(logger: &'a L) -> Profiler<'a, L> {
+        Profiler { log_spans: true,
+                   logger,
+                   durations: Mutex::new(HashMap::new()),
+                   stacks:    Mutex::new(HashMap::new()) }
+    }
+    /// Enters a new span. It is exited when the returned guard is dropped.
+    /// # Arguments
+    /// * `name: &str` - The name of the span.
+    /// # Returns
+    /// A `Span`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::{Logger, spans::Profiler, sinks::MemorySink};
+    ///
+    /// let mem = MemorySink::new(10);
+    /// let l   = Logger::empty(1).with_sink(mem.clone());
+    /// let p   = Profiler::new(&l);
+    /// {
+    ///     let _outer = p.span("request");
+    ///     for _ in 0..2 {
+    ///         let _inner = p.span("query");
+    ///     }
+    /// }
+    ///
+    /// let lines = mem.lines();
+    /// assert_eq!(3, lines.len());
+    /// assert!(lines[0].contains("][Debug][Span] request/query took "));
+    /// assert!(lines[2].contains("][Debug][Span] request took "));
+    /// assert_eq!(2, p.summary().iter().find(|s| s.name == "request/query").unwrap().count);
+    ///
+    /// // Spans of another profiler are not nested.
+    /// let other = Profiler::new(&l);
+    /// {
+    ///     let _unrelated = other.span("unrelated");
+    ///     let _query     = p.span("query");
+    ///     assert_eq!("query", _query.name());
+    /// }
+    /// assert_eq!(1, p.summary().iter().find(|s| s.name == "query").unwrap().count);
+    /// assert_eq!(vec!["unrelated"], other.summary().iter().map(|s| s.name.as_str()).collect::<Vec<_>>());
+    /// ```
+    pub fn span(&self, name: &str) -> Span<'_, 'a, L> {
+        let thread  = thread::current().id();
+        let mut all = self.stacks();
+        let stack   = all.entry(thread).or_default();
+        stack.push(name.to_owned());
+        let (path, depth) = (stack.join("/"), stack.len() - 1);
+        drop(all);
+        Span { profiler: self,
+               path,
+               thread,
+               depth,
+               start:    Instant::now() }
+    }
+    /// Times a closure as a span.
+    /// # Arguments
+    /// * `name: &str` - The name of the span.
+    /// * `f: F` - The closure.
+    /// # Returns
+    /// The return value of `f`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::{Logger, spans::Profiler};
+    ///
+    /// let l = Logger::empty(10);
+    /// let p = Profiler::new(&l);
+    /// assert_eq!(4950, p.time("sum", || (0..100).sum::<u32>()));
+    /// assert_eq!(1, p.summary()[0].count);
+    /// ```
+    pub fn time<T, F: FnOnce() -> T>(&self, name: &str, f: F) -> T {
+        let _span = self.span(name);
+        f()
+    }
+    /// Gets the statistics of all spans finished so far, sorted by name.
+    /// # Returns
+    /// A `Vec<SpanStats>`.
+    #[must_use]
+    pub fn summary(&self) -> Vec<SpanStats> {
+        let durations = match self.durations.lock() {
+            Ok(d)  => d,
+            Err(e) => e.into_inner(),
+        };
+        let mut res: Vec<SpanStats> = durations.iter()
+                                               .map(|(name, d)| SpanStats::new(name, d))
+                                               .collect();
+        res.sort_by(|a, b| a.name.cmp(&b.name));
+        res
+    }
+    /// Logs the summary of all spans as `Level::Info` records, flushes the logger and resets the statistics.
+    /// # Returns
+    /// A `Result<(), String>`. `()` if it was successful, otherwise the error message as a `String`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::rapidlogging::{Logger, spans::Profiler, sinks::MemorySink};
+    ///
+    /// let mem = MemorySink::new(10);
+    /// let l   = Logger::empty(100).with_sink(mem.clone());
+    /// let mut p = Profiler::new(&l);
+    /// p.log_spans = false;
+    /// for _ in 0..3
+    /// { p.time("step", || std::thread::sleep(std::time::Duration::from_millis(1))); }
+    ///
+    /// assert!(p.flush().is_ok());
+    /// let lines = mem.lines();
+    /// assert_eq!(1, lines.len());
+    /// assert!(lines[0].contains("][Info][Span][Summary] step count=3 total="));
+    /// assert!(p.summary().is_empty());
+    /// ```
+    pub fn flush(&self) -> Result<(), String> {
+        let mut res = Ok(());
+        for s in self.summary() {
+            let r = Record::new(&s.name).with_level(Level::Info)
+                                        .with_prefix("Span")
+                                        .with_prefix("Summary")
+                                        .with_field("count", s.count)
+                                        .with_field("total", format!("{:?}", s.total))
+                                        .with_field("mean",  format!("{:?}", s.mean))
+                                        .with_field("min",   format!("{:?}", s.min))
+                                        .with_field("max",   format!("{:?}", s.max))
+                                        .with_field("p50",   format!("{:?}", s.p50))
+                                        .with_field("p95",   format!("{:?}", s.p95));
+            if let (Err(e), true) = (self.logger.log_record(&r), res.is_ok())
+            { res = Err(e); }
+        }
+        match self.durations.lock() {
+            Ok(mut d)  => d.clear(),
+            Err(e)     => e.into_inner().clear(),
+        }
+        res.and(self.logger.flush())
+    }
+
+    fn stacks(&self) -> MutexGuard<'_, HashMap<ThreadId, Vec<String>>> {
+        match self.stacks.lock() {
+            Ok(s)  => s,
+            Err(e) => e.into_inner(),
+        }
+    }
+
+    fn finish(&self, path: &str, depth: usize, elapsed: Duration) {
+        match self.durations.lock() {
+            Ok(mut d)  => d.entry(path.to_owned()).or_default().push(elapsed),
+            Err(e)     => e.into_inner().entry(path.to_owned()).or_default().push(elapsed),
+        }
+        if self.log_spans {
+            let r = Record::new(&format!("{} took {:?}", path, elapsed)).with_level(Level::Debug)
+                                                                        .with_prefix("Span")
+                                                                        .with_field("span", path)
+                                                                        .with_field("depth", depth)
+                                                                        .with_field("duration_ns", elapsed.as_nanos());
+            let _ = self.logger.log_record(&r);
+        }
+    }
+}
+
+impl<L: Log> Span<'_, '_, L> {
+    /// Gets the full name of `self`, including the names of enclosing spans.
+    /// # Returns
+    /// A `&str`.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.path
+    }
+    /// Gets the time passed since `self` was entered.
+    /// # Returns
+    /// A `Duration`.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+impl<L: Log> Drop for Span<'_, '_, L> {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        // Truncating instead of popping keeps the stack consistent if spans are dropped out of order.
+        let mut all = self.profiler.stacks();
+        if let Some(stack) = all.get_mut(&self.thread) {
+            stack.truncate(self.depth);
+            if stack.is_empty()
+            { all.remove(&self.thread); }
+        }
+        drop(all);
+        self.profiler.finish(&self.path, self.depth, elapsed);
+    }
+}
+
+impl SpanStats {
+    fn new(name: &str, durations: &[Duration]) -> SpanStats {
+        let mut sorted = durations.to_vec();
+        sorted.sort();
+        let count = sorted.len();
+        let total: Duration = sorted.iter().sum();
+        // Nearest-rank percentile.
+        let rank = |p: usize| sorted[((p * count).div_ceil(100)).max(1) - 1];
+
+        SpanStats { name:  name.to_owned(),
+                    count,
+                    total,
+                    mean:  total / count as u32,
+                    min:   sorted[0],
+                    max:   sorted[count - 1],
+                    p50:   rank(50),
+                    p95:   rank(95) }
+    }
+}
@@ -1,6 +1,13 @@
 //! Certain functions for working with Strings.
 
 use crate::math::general::NumTools;
+use numbers::{NumberFormat, NumberParseError};
+
+pub mod dictionary;
+pub mod edit_distance;
+pub mod search;
+pub mod numbers;
+mod fuzzy;
 
 /// Useful String related functions.
 pub trait StringUtils {
@@ -137,6 +144,7 @@ pub trait StringUtils {
     #[must_use]
     fn similarity_with(&self, other: &str) -> f32;
     /// Calculate the Levenshtein-distance between two Strings.
+    /// For a bounded or bit-parallel variant and the edit script, see `edit_distance`.
     /// # Arguments
     /// * `a: &str`
     /// * `b: &str`
@@ -149,8 +157,223 @@ pub trait StringUtils {
     /// ```
     #[must_use]
     fn levenshtein_dist_with(&self, other: &str) -> usize;
+    /// Calculate the Damerau-Levenshtein-distance between two Strings, which also counts swapping two adjacent characters as one edit.
+    /// Unlike the optimal string alignment distance, a substring may be edited again after a transposition.
+    /// # Arguments
+    /// * `other: &str` - The other string to be compared to.
+    /// # Returns
+    /// A `usize`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::stringhelpers::StringUtils;
+    /// 
+    /// assert_eq!("Schmidt".damerau_levenshtein_dist_with("Schmdit"), 1);
+    /// assert_eq!("Schmidt".levenshtein_dist_with("Schmdit"), 2);
+    /// assert_eq!("ca".damerau_levenshtein_dist_with("abc"), 2);
+    /// ```
+    #[must_use]
+    fn damerau_levenshtein_dist_with(&self, other: &str) -> usize;
+    /// Gets the Levenshtein-distance as similarity in `[0; 1]`, relative to the length of the longer String.
+    /// # Arguments
+    /// * `other: &str` - The other string to be compared to.
+    /// # Returns
+    /// A `f64`, `1.0` for equal Strings.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::stringhelpers::StringUtils;
+    /// 
+    /// assert_eq!("kitten".normalized_levenshtein_with("sitting"), 1.0 - 3.0 / 7.0);
+    /// assert_eq!("".normalized_levenshtein_with(""), 1.0);
+    /// ```
+    #[must_use]
+    fn normalized_levenshtein_with(&self, other: &str) -> f64;
+    /// Gets the Damerau-Levenshtein-distance as similarity in `[0; 1]`, relative to the length of the longer String.
+    /// # Arguments
+    /// * `other: &str` - The other string to be compared to.
+    /// # Returns
+    /// A `f64`, `1.0` for equal Strings.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::stringhelpers::StringUtils;
+    /// 
+    /// assert_eq!("Müller".normalized_damerau_levenshtein_with("Mülelr"), 1.0 - 1.0 / 6.0);
+    /// ```
+    #[must_use]
+    fn normalized_damerau_levenshtein_with(&self, other: &str) -> f64;
+    /// Gets the Jaro similarity between two Strings, which rewards characters matching near the same position.
+    /// # Arguments
+    /// * `other: &str` - The other string to be compared to.
+    /// # Returns
+    /// A `f64` in `[0; 1]`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::stringhelpers::StringUtils;
+    /// 
+    /// assert!(("MARTHA".jaro_with("MARHTA") - 0.9444).abs() < 1e-4);
+    /// assert!(("DIXON".jaro_with("DICKSONX") - 0.7667).abs() < 1e-4);
+    /// assert_eq!("abc".jaro_with("xyz"), 0.0);
+    /// ```
+    #[must_use]
+    fn jaro_with(&self, other: &str) -> f64;
+    /// Gets the Jaro-Winkler similarity between two Strings.
+    /// This is the Jaro similarity, raised for a common prefix of up to 4 characters with a scaling factor of `0.1`.
+    /// # Arguments
+    /// * `other: &str` - The other string to be compared to.
+    /// # Returns
+    /// A `f64` in `[0; 1]`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::stringhelpers::StringUtils;
+    /// 
+    /// assert!(("MARTHA".jaro_winkler_with("MARHTA") - 0.9611).abs() < 1e-4);
+    /// assert!(("DIXON".jaro_winkler_with("DICKSONX") - 0.8133).abs() < 1e-4);
+    /// ```
+    #[must_use]
+    fn jaro_winkler_with(&self, other: &str) -> f64;
+    /// Gets the longest common subsequence of two Strings, i.e. the longest sequence of characters appearing in both in the same order, but not necessarily next to each other.
+    /// # Arguments
+    /// * `other: &str` - The other string to be compared to.
+    /// # Returns
+    /// A `String`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::stringhelpers::StringUtils;
+    /// 
+    /// assert_eq!("ABCBDAB".longest_common_subsequence_with("BDCABA").chars().count(), 4);
+    /// assert_eq!("Straße".longest_common_subsequence_with("Strasse"), "Strae");
+    /// ```
+    #[must_use]
+    fn longest_common_subsequence_with(&self, other: &str) -> String;
+    /// Gets the longest common substring of two Strings, i.e. the longest run of characters appearing in both.
+    /// If there are several, the first one in `self` is returned.
+    /// # Arguments
+    /// * `other: &str` - The other string to be compared to.
+    /// # Returns
+    /// A `String`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::stringhelpers::StringUtils;
+    /// 
+    /// assert_eq!("ACME Holdings Ltd".longest_common_substring_with("Acme Holdings Limited"), " Holdings L");
+    /// assert_eq!("abc".longest_common_substring_with("xyz"), "");
+    /// ```
+    #[must_use]
+    fn longest_common_substring_with(&self, other: &str) -> String;
+    /// Calculate the Hamming-distance between two Strings, i.e. the number of positions with different characters.
+    /// # Arguments
+    /// * `other: &str` - The other string to be compared to.
+    /// # Returns
+    /// An `Option<usize>`, `None` if the Strings have a different number of characters.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::stringhelpers::StringUtils;
+    /// 
+    /// assert_eq!("karolin".hamming_dist_with("kathrin"), Some(3));
+    /// assert_eq!("größe".hamming_dist_with("grösse"), None);
+    /// ```
+    #[must_use]
+    fn hamming_dist_with(&self, other: &str) -> Option<usize>;
+    /// Gets the Sørensen-Dice coefficient of the n-grams of two Strings.
+    /// N-grams occuring multiple times are counted multiple times.
+    /// # Arguments
+    /// * `other: &str` - The other string to be compared to.
+    /// * `n: usize` - The length of the n-grams, e.g. `2` for bigrams.
+    /// # Returns
+    /// A `f64` in `[0; 1]`. Different Strings which are too short for a single n-gram have a similarity of `0.0`.
+    /// # Panics
+    /// Panics if `n` is `0`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::stringhelpers::StringUtils;
+    /// 
+    /// // "ht" is the only shared bigram.
+    /// assert_eq!("night".dice_coefficient_with("nacht", 2), 0.25);
+    /// assert_eq!("Meier".dice_coefficient_with("Meier", 3), 1.0);
+    /// ```
+    #[must_use]
+    fn dice_coefficient_with(&self, other: &str, n: usize) -> f64;
+    /// Gets the Jaccard index of the n-grams of two Strings, i.e. the size of their intersection divided by the size of their union.
+    /// N-grams occuring multiple times are counted multiple times.
+    /// # Arguments
+    /// * `other: &str` - The other string to be compared to.
+    /// * `n: usize` - The length of the n-grams, e.g. `2` for bigrams.
+    /// # Returns
+    /// A `f64` in `[0; 1]`. Different Strings which are too short for a single n-gram have a similarity of `0.0`.
+    /// # Panics
+    /// Panics if `n` is `0`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::stringhelpers::StringUtils;
+    /// 
+    /// assert_eq!("night".jaccard_index_with("nacht", 2), 1.0 / 7.0);
+    /// ```
+    #[must_use]
+    fn jaccard_index_with(&self, other: &str, n: usize) -> f64;
+    /// Parses an integer, written with the separators of a `NumberFormat`.
+    /// Surrounding whitespace and a leading sign are allowed. If the format has a thousands separator,
+    /// it may only group the digits by three.
+    /// # Arguments
+    /// * `format: &NumberFormat` - The format. Only its thousands separator is used.
+    /// # Returns
+    /// A `Result<isize, NumberParseError>`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::stringhelpers::{StringUtils, numbers::{NumberFormat, NumberParseError}};
+    /// 
+    /// assert_eq!(Ok(-1_234_567), " -1,234,567 ".parse_isize(&NumberFormat::ENGLISH));
+    /// assert_eq!(Ok(1_234_567), "1.234.567".parse_isize(&NumberFormat::GERMAN));
+    /// assert_eq!(Ok(isize::MIN), isize::MIN.to_string().parse_isize(&NumberFormat::PLAIN));
+    /// 
+    /// assert_eq!(Err(NumberParseError::InvalidDigit(1, '.')), "1.5".parse_isize(&NumberFormat::PLAIN));
+    /// assert_eq!(Err(NumberParseError::MisplacedSeparator(2)), "12,34".parse_isize(&NumberFormat::ENGLISH));
+    /// assert_eq!(Err(NumberParseError::MissingDigits(1)), "-".parse_isize(&NumberFormat::PLAIN));
+    /// assert_eq!(Err(NumberParseError::Overflow(18)), "9999999999999999999".parse_isize(&NumberFormat::PLAIN));
+    /// ```
+    #[must_use = "This returns the result of the operation, without modifying the original."]
+    fn parse_isize(&self, format: &NumberFormat) -> Result<isize, NumberParseError>;
+    /// Parses an integer in a radix. Digits above 9 are the letters `a` to `z`, in any case.
+    /// Surrounding whitespace and a leading sign are allowed, but no prefix such as `0x`.
+    /// # Arguments
+    /// * `radix: u32` - The radix in `[2; 36]`.
+    /// # Returns
+    /// A `Result<isize, NumberParseError>`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::stringhelpers::{StringUtils, numbers::NumberParseError};
+    /// 
+    /// assert_eq!(Ok(255), "ff".parse_isize_radix(16));
+    /// assert_eq!(Ok(-5), "-101".parse_isize_radix(2));
+    /// assert_eq!(Ok(1295), "ZZ".parse_isize_radix(36));
+    /// assert_eq!(Err(NumberParseError::InvalidDigit(2, '2')), "10201".parse_isize_radix(2));
+    /// assert_eq!(Err(NumberParseError::InvalidRadix(40)), "1".parse_isize_radix(40));
+    /// ```
+    #[must_use = "This returns the result of the operation, without modifying the original."]
+    fn parse_isize_radix(&self, radix: u32) -> Result<isize, NumberParseError>;
+    /// Parses a decimal float, written with the separators of a `NumberFormat`, and an optional exponent after `e` or `E`.
+    /// Surrounding whitespace and a leading sign are allowed. The result is correctly rounded.
+    /// # Arguments
+    /// * `format: &NumberFormat` - The format.
+    /// # Returns
+    /// A `Result<f64, NumberParseError>`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::stringhelpers::{StringUtils, numbers::{NumberFormat, NumberParseError}};
+    /// 
+    /// assert_eq!(Ok(1.5), "1.5".parse_f64(&NumberFormat::PLAIN));
+    /// assert_eq!(Ok(-1234.5), "-1.234,5".parse_f64(&NumberFormat::GERMAN));
+    /// assert_eq!(Ok(1234.5), "1\u{202f}234,5".parse_f64(&NumberFormat::FRENCH));
+    /// assert_eq!(Ok(6.022e23), "6.022E+23".parse_f64(&NumberFormat::ENGLISH));
+    /// assert_eq!(Ok(0.25), ".25".parse_f64(&NumberFormat::PLAIN));
+    /// 
+    /// assert_eq!(Err(NumberParseError::InvalidDigit(3, ',')), "1.5,0".parse_f64(&NumberFormat::ENGLISH));
+    /// assert_eq!(Err(NumberParseError::MissingDigits(2)), "1e".parse_f64(&NumberFormat::PLAIN));
+    /// assert_eq!(Err(NumberParseError::Overflow(0)), "1e400".parse_f64(&NumberFormat::PLAIN));
+    /// ```
+    #[must_use = "This returns the result of the operation, without modifying the original."]
+    fn parse_f64(&self, format: &NumberFormat) -> Result<f64, NumberParseError>;
     /// Converts a given String into an `isize`. This function
     /// ignores whitespaces, commas and full stops.
+    /// For strict parsing with error positions, see `parse_isize`.
     /// # Examples
     /// ```
     /// use lib_rapid::compsci::stringhelpers::StringUtils;
@@ -192,6 +415,58 @@ impl StringUtils for String {
         backend_levenshtein(self, other)
     }
     #[inline]
+    fn damerau_levenshtein_dist_with(&self, other: &str) -> usize {
+        fuzzy::damerau_levenshtein(self, other)
+    }
+    #[inline]
+    fn normalized_levenshtein_with(&self, other: &str) -> f64 {
+        fuzzy::normalize(backend_levenshtein(self, other), self, other)
+    }
+    #[inline]
+    fn normalized_damerau_levenshtein_with(&self, other: &str) -> f64 {
+        fuzzy::normalize(fuzzy::damerau_levenshtein(self, other), self, other)
+    }
+    #[inline]
+    fn jaro_with(&self, other: &str) -> f64 {
+        fuzzy::jaro(self, other)
+    }
+    #[inline]
+    fn jaro_winkler_with(&self, other: &str) -> f64 {
+        fuzzy::jaro_winkler(self, other)
+    }
+    #[inline]
+    fn longest_common_subsequence_with(&self, other: &str) -> String {
+        fuzzy::longest_common_subsequence(self, other)
+    }
+    #[inline]
+    fn longest_common_substring_with(&self, other: &str) -> String {
+        fuzzy::longest_common_substring(self, other)
+    }
+    #[inline]
+    fn hamming_dist_with(&self, other: &str) -> Option<usize> {
+        fuzzy::hamming(self, other)
+    }
+    #[inline]
+    fn dice_coefficient_with(&self, other: &str, n: usize) -> f64 {
+        fuzzy::dice(self, other, n)
+    }
+    #[inline]
+    fn jaccard_index_with(&self, other: &str, n: usize) -> f64 {
+        fuzzy::jaccard(self, other, n)
+    }
+    #[inline]
+    fn parse_isize(&self, format: &NumberFormat) -> Result<isize, NumberParseError> {
+        numbers::parse_int(self, 10, format.thousands_separator)
+    }
+    #[inline]
+    fn parse_isize_radix(&self, radix: u32) -> Result<isize, NumberParseError> {
+        numbers::parse_int(self, radix, None)
+    }
+    #[inline]
+    fn parse_f64(&self, format: &NumberFormat) -> Result<f64, NumberParseError> {
+        numbers::parse_float(self, format)
+    }
+    #[inline]
     fn to_isize(&self) -> Option<isize> {
         backend_to_isize(self)
     }
@@ -227,6 +502,58 @@ impl StringUtils for str {
         backend_levenshtein(self, other)
     }
     #[inline]
+    fn damerau_levenshtein_dist_with(&self, other: &str) -> usize {
+        fuzzy::damerau_levenshtein(self, other)
+    }
+    #[inline]
+    fn normalized_levenshtein_with(&self, other: &str) -> f64 {
+        fuzzy::normalize(backend_levenshtein(self, other), self, other)
+    }
+    #[inline]
+    fn normalized_damerau_levenshtein_with(&self, other: &str) -> f64 {
+        fuzzy::normalize(fuzzy::damerau_levenshtein(self, other), self, other)
+    }
+    #[inline]
+    fn jaro_with(&self, other: &str) -> f64 {
+        fuzzy::jaro(self, other)
+    }
+    #[inline]
+    fn jaro_winkler_with(&self, other: &str) -> f64 {
+        fuzzy::jaro_winkler(self, other)
+    }
+    #[inline]
+    fn longest_common_subsequence_with(&self, other: &str) -> String {
+        fuzzy::longest_common_subsequence(self, other)
+    }
+    #[inline]
+    fn longest_common_substring_with(&self, other: &str) -> String {
+        fuzzy::longest_common_substring(self, other)
+    }
+    #[inline]
+    fn hamming_dist_with(&self, other: &str) -> Option<usize> {
+        fuzzy::hamming(self, other)
+    }
+    #[inline]
+    fn dice_coefficient_with(&self, other: &str, n: usize) -> f64 {
+        fuzzy::dice(self, other, n)
+    }
+    #[inline]
+    fn jaccard_index_with(&self, other: &str, n: usize) -> f64 {
+        fuzzy::jaccard(self, other, n)
+    }
+    #[inline]
+    fn parse_isize(&self, format: &NumberFormat) -> Result<isize, NumberParseError> {
+        numbers::parse_int(self, 10, format.thousands_separator)
+    }
+    #[inline]
+    fn parse_isize_radix(&self, radix: u32) -> Result<isize, NumberParseError> {
+        numbers::parse_int(self, radix, None)
+    }
+    #[inline]
+    fn parse_f64(&self, format: &NumberFormat) -> Result<f64, NumberParseError> {
+        numbers::parse_float(self, format)
+    }
+    #[inline]
     fn to_isize(&self) -> Option<isize> {
         backend_to_isize(self)
     }
@@ -0,0 +1,270 @@
+//! In here you'll find indexes of word lists for approximate lookups ("did you mean ...?") by Levenshtein-distance, without scanning the whole list.
+use std::{collections::{HashMap, HashSet}, iter::FromIterator};
+use super::edit_distance::{levenshtein_bounded, levenshtein_myers};
+
+/// A word found by an approximate lookup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    /// The word as stored in the dictionary.
+    pub word:      String,
+    /// The Levenshtein-distance to the query.
+    pub distance:  usize,
+    /// The frequency of the word, as inserted.
+    pub frequency: u64,
+}
+
+/// A Burkhard-Keller tree, which finds all words within any distance of a query.
+/// \
+/// Every child is keyed by its distance to its parent, so the triangle inequality rules out most subtrees.
+#[derive(Debug, Clone, Default)]
+pub struct BkTree {
+    nodes: Vec<BkNode>,
+}
+
+#[derive(Debug, Clone)]
+struct BkNode {
+    word:      String,
+    frequency: u64,
+    children:  HashMap<usize, usize>,
+}
+
+/// A SymSpell deletion dictionary, which finds all words within a distance fixed when creating it.
+/// \
+/// Every word is stored under all Strings reachable by deleting up to `max_distance` characters,
+/// so a lookup only generates the deletions of the query instead of comparing against every word.
+/// This is faster than a `BkTree` for small distances, at the expense of memory.
+#[derive(Debug, Clone)]
+pub struct SymSpell {
+    max_distance: usize,
+    words:        HashMap<String, u64>,
+    deletes:      HashMap<String, Vec<String>>,
+}
+
+/// Sorts suggestions by distance, then by descending frequency, then alphabetically.
+fn rank(suggestions: &mut [Suggestion]) {
+    suggestions.sort_by(|a, b| a.distance.cmp(&b.distance)
+                                         .then(b.frequency.cmp(&a.frequency))
+                                         .then_with(|| a.word.cmp(&b.word)));
+}
+
+impl BkTree {
+    /// Create a new, empty `BkTree`.
+    /// # Returns
+    /// A new `BkTree`.
+    #[inline]
+    #[must_use]
+    pub fn new() -> BkTree {
+        BkTree { nodes: Vec::new() }
+    }
+    /// Get the number of words in `self`.
+    /// # Returns
+    /// A `usize`.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+    /// Determines whether `self` contains no words.
+    /// # Returns
+    /// A `bool`.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+    /// Inserts a word. If it is already contained, its frequency is increased.
+    /// # Arguments
+    /// * `word: &str` - The word.
+    /// * `frequency: u64` - How often the word occurs, used for ranking.
+    pub fn insert(&mut self, word: &str, frequency: u64) {
+        if self.nodes.is_empty() {
+            self.nodes.push(BkNode { word: word.to_owned(), frequency, children: HashMap::new() });
+            return;
+        }
+
+        let mut current = 0;
+        loop {
+            let d = levenshtein_myers(&self.nodes[current].word, word);
+            if d == 0 {
+                self.nodes[current].frequency += frequency;
+                return;
+            }
+            match self.nodes[current].children.get(&d) {
+                Some(&child) => { current = child; },
+                None         => {
+                    let index = self.nodes.len();
+                    self.nodes[current].children.insert(d, index);
+                    self.nodes.push(BkNode { word: word.to_owned(), frequency, children: HashMap::new() });
+                    return;
+                },
+            }
+        }
+    }
+    /// Finds all words within a Levenshtein-distance of a query.
+    /// # Arguments
+    /// * `query: &str` - The word to look up.
+    /// * `max_distance: usize` - The maximum distance.
+    /// # Returns
+    /// A `Vec<Suggestion>`, sorted by distance, then by descending frequency, then alphabetically.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::stringhelpers::dictionary::BkTree;
+    ///
+    /// let mut tree = BkTree::new();
+    /// for (word, frequency) in [("book", 120), ("books", 40), ("cake", 30), ("boo", 5), ("cape", 8), ("cart", 12)] {
+    ///     tree.insert(word, frequency);
+    /// }
+    ///
+    /// let found: Vec<_> = tree.lookup("bok", 1).into_iter().map(|s| (s.word, s.distance)).collect();
+    /// assert_eq!(vec![("book".to_owned(), 1), ("boo".to_owned(), 1)], found);
+    /// assert_eq!("cake", tree.lookup("cakr", 2)[0].word);
+    /// assert!(tree.lookup("xylophone", 2).is_empty());
+    /// ```
+    #[must_use]
+    pub fn lookup(&self, query: &str, max_distance: usize) -> Vec<Suggestion> {
+        let mut res = Vec::new();
+        if self.nodes.is_empty()
+        { return res; }
+
+        let mut stack = vec![0];
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            let d    = levenshtein_myers(&node.word, query);
+            if d <= max_distance
+            { res.push(Suggestion { word: node.word.clone(), distance: d, frequency: node.frequency }); }
+
+            // By the triangle inequality, matches can only be below edges in [d - max; d + max].
+            let (lo, hi) = (d.saturating_sub(max_distance), d + max_distance);
+            stack.extend(node.children.iter().filter(|(k, _)| (lo..=hi).contains(*k)).map(|(_, v)| *v));
+        }
+        rank(&mut res);
+        res
+    }
+}
+
+impl<'a> FromIterator<(&'a str, u64)> for BkTree {
+    fn from_iter<I: IntoIterator<Item = (&'a str, u64)>>(iter: I) -> Self {
+        let mut res = BkTree::new();
+        for (word, frequency) in iter {
+            res.insert(word, frequency);
+        }
+        res
+    }
+}
+
+impl SymSpell {
+    /// Create a new, empty `SymSpell` dictionary.
+    /// # Arguments
+    /// * `max_distance: usize` - The greatest distance lookups can use. The memory needed grows quickly with it; `2` is a common choice.
+    /// # Returns
+    /// A new `SymSpell`.
+    #[inline]
+    #[must_use]
+    pub fn new(max_distance: usize) -> SymSpell {
+        SymSpell { max_distance, words: HashMap::new(), deletes: HashMap::new() }
+    }
+    /// Get the `max_distance` field.
+    /// # Returns
+    /// A `usize`.
+    #[inline]
+    #[must_use]
+    pub const fn max_distance(&self) -> usize {
+        self.max_distance
+    }
+    /// Get the number of words in `self`.
+    /// # Returns
+    /// A `usize`.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+    /// Determines whether `self` contains no words.
+    /// # Returns
+    /// A `bool`.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+    /// Inserts a word. If it is already contained, its frequency is increased.
+    /// # Arguments
+    /// * `word: &str` - The word.
+    /// * `frequency: u64` - How often the word occurs, used for ranking.
+    pub fn insert(&mut self, word: &str, frequency: u64) {
+        if let Some(f) = self.words.get_mut(word) {
+            *f += frequency;
+            return;
+        }
+
+        self.words.insert(word.to_owned(), frequency);
+        for delete in deletes(word, self.max_distance) {
+            self.deletes.entry(delete).or_default().push(word.to_owned());
+        }
+    }
+    /// Finds all words within a Levenshtein-distance of a query.
+    /// # Arguments
+    /// * `query: &str` - The word to look up.
+    /// * `max_distance: usize` - The maximum distance. It is limited to the one `self` was created with.
+    /// # Returns
+    /// A `Vec<Suggestion>`, sorted by distance, then by descending frequency, then alphabetically.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::stringhelpers::dictionary::SymSpell;
+    ///
+    /// let names = [("Müller", 310), ("Mueller", 95), ("Miller", 120), ("Möller", 44), ("Meier", 280)];
+    /// let dict: SymSpell = names.iter().map(|(w, f)| (*w, *f)).collect();
+    ///
+    /// let found: Vec<_> = dict.lookup("Muller", 1).into_iter().map(|s| s.word).collect();
+    /// assert_eq!(vec!["Müller", "Miller", "Mueller", "Möller"], found);
+    /// assert_eq!(1, dict.lookup("Meier", 0).len());
+    /// ```
+    #[must_use]
+    pub fn lookup(&self, query: &str, max_distance: usize) -> Vec<Suggestion> {
+        let max_distance = max_distance.min(self.max_distance);
+        let mut seen     = HashSet::new();
+        let mut res      = Vec::new();
+        for delete in deletes(query, max_distance) {
+            for word in self.deletes.get(&delete).into_iter().flatten() {
+                if !seen.insert(word.as_str())
+                { continue; }
+                if let Some(distance) = levenshtein_bounded(query, word, max_distance)
+                { res.push(Suggestion { word: word.clone(), distance, frequency: self.words[word] }); }
+            }
+        }
+        rank(&mut res);
+        res
+    }
+}
+
+impl<'a> FromIterator<(&'a str, u64)> for SymSpell {
+    /// Collects a `SymSpell` with a maximum distance of `2`.
+    fn from_iter<I: IntoIterator<Item = (&'a str, u64)>>(iter: I) -> Self {
+        let mut res = SymSpell::new(2);
+        for (word, frequency) in iter {
+            res.insert(word, frequency);
+        }
+        res
+    }
+}
+
+/// Gets all Strings reachable by deleting up to `depth` characters from `word`, including `word` itself.
+fn deletes(word: &str, depth: usize) -> HashSet<String> {
+    let mut res      = HashSet::new();
+    let mut frontier = vec![word.to_owned()];
+    res.insert(word.to_owned());
+    for _ in 0..depth {
+        let mut next = Vec::new();
+        for w in &frontier {
+            for (i, c) in w.char_indices() {
+                let mut shorter = String::with_capacity(w.len());
+                shorter.push_str(&w[..i]);
+                shorter.push_str(&w[i + c.len_utf8()..]);
+                if res.insert(shorter.clone())
+                { next.push(shorter); }
+            }
+        }
+        frontier = next;
+    }
+    res
+}
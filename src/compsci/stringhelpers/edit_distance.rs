@@ -0,0 +1,276 @@
+//! In here you'll find faster variants of the Levenshtein-distance and the edit script turning one String into another.
+//! All functions work on `char`s, not on bytes.
+use std::collections::HashMap;
+
+/// A single step of an edit script, turning a source String into a target String.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditOp {
+    /// The character is kept.
+    Keep(char),
+    /// The character of the target is inserted.
+    Insert(char),
+    /// The character of the source is deleted.
+    Delete(char),
+    /// The character of the source, first, is replaced by the one of the target, second.
+    Substitute(char, char),
+}
+
+impl EditOp {
+    /// Get the cost of `self`, i.e. `0` for `Keep`, otherwise `1`.
+    /// # Returns
+    /// A `usize`.
+    #[inline]
+    #[must_use]
+    pub const fn cost(&self) -> usize {
+        match self {
+            EditOp::Keep(_) => { 0 },
+            _               => { 1 },
+        }
+    }
+}
+
+/// Calculate the Levenshtein-distance between two Strings, giving up as soon as it exceeds `max`.
+/// \
+/// Only a band of `2 * max + 1` diagonals of the matrix is computed, so this is much faster than
+/// `StringUtils::levenshtein_dist_with` when looking for close matches only.
+/// # Arguments
+/// * `a: &str` - The first String.
+/// * `b: &str` - The second String.
+/// * `max: usize` - The maximum distance of interest.
+/// # Returns
+/// An `Option<usize>`, `None` if the distance is greater than `max`.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::stringhelpers::edit_distance::levenshtein_bounded;
+///
+/// assert_eq!(Some(3), levenshtein_bounded("kitten", "sitting", 3));
+/// assert_eq!(None, levenshtein_bounded("kitten", "sitting", 2));
+/// // The length difference alone exceeds the bound.
+/// assert_eq!(None, levenshtein_bounded("a", "abcd", 2));
+/// ```
+#[must_use]
+pub fn levenshtein_bounded(a: &str, b: &str, max: usize) -> Option<usize> {
+    let mut a: Vec<char> = a.chars().collect();
+    let mut b: Vec<char> = b.chars().collect();
+    if a.len() > b.len()
+    { std::mem::swap(&mut a, &mut b); }
+    let (n, m) = (a.len(), b.len());
+    if m - n > max
+    { return None; }
+    if n == 0
+    { return Some(m); }
+
+    // Cells outside of the band hold `over`, which is just out of bounds.
+    let over                 = max + 1;
+    let mut prev: Vec<usize> = (0..=m).map(|j| j.min(over)).collect();
+    let mut curr: Vec<usize> = vec![over; m + 1];
+    for i in 1..=n {
+        let lo = i.saturating_sub(max).max(1);
+        let hi = (i + max).min(m);
+        curr[lo - 1] = match lo {
+            1 => { i.min(over) },
+            _ => { over },
+        };
+        let mut row_min = curr[lo - 1];
+        for j in lo..=hi {
+            let cost = match a[i - 1] == b[j - 1] {
+                true  => { 0 },
+                false => { 1 },
+            };
+            curr[j]  = (prev[j - 1] + cost).min(prev[j] + 1)
+                                           .min(curr[j - 1] + 1)
+                                           .min(over);
+            row_min  = row_min.min(curr[j]);
+        }
+        if hi < m
+        { curr[hi + 1] = over; }
+        if row_min > max
+        { return None; }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    match prev[m] <= max {
+        true  => Some(prev[m]),
+        false => None,
+    }
+}
+
+/// Calculate the Levenshtein-distance between two Strings with the bit-parallel algorithm by Myers (1999),
+/// in the block-based form by Hyyrö (2003).
+/// \
+/// The columns of the matrix are computed 64 cells at a time, which is fastest if the shorter String has at most 64 characters.
+/// # Arguments
+/// * `a: &str` - The first String.
+/// * `b: &str` - The second String.
+/// # Returns
+/// A `usize`.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::stringhelpers::{edit_distance::levenshtein_myers, StringUtils};
+///
+/// assert_eq!(3, levenshtein_myers("kitten", "sitting"));
+/// assert_eq!(1, levenshtein_myers("Köln", "Koln"));
+///
+/// let long_a = "The quick brown fox jumps over the lazy dog. ".repeat(4);
+/// let long_b = "The quick brown cat jumped over the lazy dog! ".repeat(4);
+/// assert_eq!(long_a.levenshtein_dist_with(&long_b), levenshtein_myers(&long_a, &long_b));
+/// ```
+#[must_use]
+pub fn levenshtein_myers(a: &str, b: &str) -> usize {
+    let (pattern, text) = match a.chars().count() <= b.chars().count() {
+        true  => (a, b),
+        false => (b, a),
+    };
+    let m = pattern.chars().count();
+    if m == 0
+    { return text.chars().count(); }
+
+    let blocks = m.div_ceil(64);
+    let mut peq: HashMap<char, Vec<u64>> = HashMap::new();
+    for (i, c) in pattern.chars().enumerate() {
+        peq.entry(c).or_insert_with(|| vec![0; blocks])[i / 64] |= 1 << (i % 64);
+    }
+    let none     = vec![0u64; blocks];
+    let last_bit = 1u64 << ((m - 1) % 64);
+
+    let mut pv: Vec<u64> = vec![!0; blocks];
+    let mut mv: Vec<u64> = vec![0; blocks];
+    let mut score        = m;
+    for c in text.chars() {
+        let eqs = peq.get(&c).unwrap_or(&none);
+        // The first row of the matrix grows by one per column.
+        let mut h_in: i8 = 1;
+        for k in 0..blocks {
+            let high   = match k == blocks - 1 {
+                true  => last_bit,
+                false => 1 << 63,
+            };
+            let mut eq = eqs[k];
+            let xv     = eq | mv[k];
+            if h_in < 0
+            { eq |= 1; }
+            let xh     = ((eq & pv[k]).wrapping_add(pv[k]) ^ pv[k]) | eq;
+            let mut ph = mv[k] | !(xh | pv[k]);
+            let mut mh = pv[k] & xh;
+            let h_out  = match (ph & high != 0, mh & high != 0) {
+                (true, _) => { 1 },
+                (_, true) => { -1 },
+                _         => { 0 },
+            };
+            ph <<= 1;
+            mh <<= 1;
+            match h_in {
+                1  => { ph |= 1; },
+                -1 => { mh |= 1; },
+                _  => { },
+            }
+            pv[k] = mh | !(xv | ph);
+            mv[k] = ph & xv;
+            h_in  = h_out;
+        }
+        match h_in {
+            1  => { score += 1; },
+            -1 => { score -= 1; },
+            _  => { },
+        }
+    }
+    score
+}
+
+/// Gets a shortest edit script turning `source` into `target`.
+/// Where several scripts are equally short, substitutions are preferred over deletions, and deletions over insertions.
+/// # Arguments
+/// * `source: &str` - The String to be edited.
+/// * `target: &str` - The String to be reached.
+/// # Returns
+/// A `Vec<EditOp>`, whose total cost is the Levenshtein-distance.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::stringhelpers::edit_distance::{edit_script, EditOp};
+///
+/// let script = edit_script("kitten", "sitting");
+/// assert_eq!(vec![EditOp::Substitute('k', 's'), EditOp::Keep('i'), EditOp::Keep('t'), EditOp::Keep('t'),
+///                 EditOp::Substitute('e', 'i'), EditOp::Keep('n'), EditOp::Insert('g')], script);
+/// assert_eq!(3, script.iter().map(EditOp::cost).sum::<usize>());
+/// ```
+#[must_use]
+pub fn edit_script(source: &str, target: &str) -> Vec<EditOp> {
+    let a: Vec<char> = source.chars().collect();
+    let b: Vec<char> = target.chars().collect();
+    let width        = b.len() + 1;
+    let mut d        = vec![0usize; (a.len() + 1) * width];
+    for i in 0..=a.len() {
+        d[i * width] = i;
+    }
+    for (j, cell) in d.iter_mut().take(width).enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = match a[i - 1] == b[j - 1] {
+                true  => { 0 },
+                false => { 1 },
+            };
+            d[i * width + j] = (d[(i - 1) * width + j - 1] + cost).min(d[(i - 1) * width + j] + 1)
+                                                                  .min(d[i * width + j - 1] + 1);
+        }
+    }
+
+    let mut res        = Vec::with_capacity(a.len().max(b.len()));
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 || j > 0 {
+        let here = d[i * width + j];
+        if i > 0 && j > 0 && a[i - 1] == b[j - 1] && here == d[(i - 1) * width + j - 1] {
+            res.push(EditOp::Keep(a[i - 1]));
+            i -= 1;
+            j -= 1;
+        }
+        else if i > 0 && j > 0 && here == d[(i - 1) * width + j - 1] + 1 {
+            res.push(EditOp::Substitute(a[i - 1], b[j - 1]));
+            i -= 1;
+            j -= 1;
+        }
+        else if i > 0 && here == d[(i - 1) * width + j] + 1 {
+            res.push(EditOp::Delete(a[i - 1]));
+            i -= 1;
+        }
+        else {
+            res.push(EditOp::Insert(b[j - 1]));
+            j -= 1;
+        }
+    }
+    res.reverse();
+    res
+}
+
+/// Aligns two Strings along a shortest edit script, so that equal positions hold kept or substituted characters.
+/// # Arguments
+/// * `source: &str` - The String to be edited.
+/// * `target: &str` - The String to be reached.
+/// * `gap: char` - The character filling in for insertions and deletions.
+/// # Returns
+/// A `(String, String)` of equal length in characters, the aligned `source` first.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::stringhelpers::edit_distance::align;
+///
+/// assert_eq!(("kitten-".to_owned(), "sitting".to_owned()), align("kitten", "sitting", '-'));
+/// assert_eq!(("Grüße".to_owned(), "Gr_ße".to_owned()), align("Grüße", "Grße", '_'));
+/// ```
+#[must_use]
+pub fn align(source: &str, target: &str, gap: char) -> (String, String) {
+    let script = edit_script(source, target);
+    let mut aligned_source = String::with_capacity(source.len() + script.len());
+    let mut aligned_target = String::with_capacity(target.len() + script.len());
+    for op in script {
+        let (s, t) = match op {
+            EditOp::Keep(c)          => { (c, c) },
+            EditOp::Insert(c)        => { (gap, c) },
+            EditOp::Delete(c)        => { (c, gap) },
+            EditOp::Substitute(x, y) => { (x, y) },
+        };
+        aligned_source.push(s);
+        aligned_target.push(t);
+    }
+    (aligned_source, aligned_target)
+}
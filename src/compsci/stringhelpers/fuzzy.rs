@@ -0,0 +1,208 @@
+//! The backends of the fuzzy string matching in `StringUtils`. All of them work on `char`s, not on bytes.
+use std::collections::HashMap;
+
+pub(super) fn damerau_levenshtein(s1: &str, s2: &str) -> usize {
+    let a: Vec<char> = s1.chars().collect();
+    let b: Vec<char> = s2.chars().collect();
+    let (n, m)       = (a.len(), b.len());
+    if n == 0
+    { return m; }
+    if m == 0
+    { return n; }
+
+    // The matrix has an extra first row and column holding the maximum distance.
+    let max                                = n + m;
+    let width                              = m + 2;
+    let mut d:        Vec<usize>           = vec![0; (n + 2) * width];
+    let mut last_row: HashMap<char, usize> = HashMap::new();
+    d[0] = max;
+    for i in 0..=n {
+        d[(i + 1) * width]     = max;
+        d[(i + 1) * width + 1] = i;
+    }
+    for j in 0..=m {
+        d[j + 1]         = max;
+        d[width + j + 1] = j;
+    }
+
+    for i in 1..=n {
+        let mut last_col = 0;
+        for j in 1..=m {
+            let i1   = *last_row.get(&b[j - 1]).unwrap_or(&0);
+            let j1   = last_col;
+            let cost = match a[i - 1] == b[j - 1] {
+                true  => { last_col = j; 0 },
+                false => { 1 },
+            };
+            d[(i + 1) * width + j + 1] = (d[i * width + j] + cost).min(d[(i + 1) * width + j] + 1)
+                                                                  .min(d[i * width + j + 1] + 1)
+                                                                  .min(d[i1 * width + j1] + (i - i1 - 1) + 1 + (j - j1 - 1));
+        }
+        last_row.insert(a[i - 1], i);
+    }
+
+    d[(n + 1) * width + m + 1]
+}
+
+pub(super) fn jaro(s1: &str, s2: &str) -> f64 {
+    let a: Vec<char> = s1.chars().collect();
+    let b: Vec<char> = s2.chars().collect();
+    if a.is_empty() && b.is_empty()
+    { return 1.0; }
+    if a.is_empty() || b.is_empty()
+    { return 0.0; }
+
+    let window        = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+    let mut matches   = 0;
+    for (i, c) in a.iter().enumerate() {
+        let end = (i + window + 1).min(b.len());
+        for j in i.saturating_sub(window)..end {
+            if !b_matched[j] && b[j] == *c {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches     += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0
+    { return 0.0; }
+
+    // Matched characters which appear in a different order are transpositions.
+    let a_order = a.iter().zip(&a_matched).filter(|(_, m)| **m).map(|(c, _)| c);
+    let b_order = b.iter().zip(&b_matched).filter(|(_, m)| **m).map(|(c, _)| c);
+    let transpositions = a_order.zip(b_order).filter(|(x, y)| x != y).count() / 2;
+
+    let m = matches as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0
+}
+
+pub(super) fn jaro_winkler(s1: &str, s2: &str) -> f64 {
+    const PREFIX_SCALE: f64   = 0.1;
+    const MAX_PREFIX:   usize = 4;
+
+    let j      = jaro(s1, s2);
+    let prefix = s1.chars().zip(s2.chars()).take(MAX_PREFIX).take_while(|(x, y)| x == y).count();
+    j + prefix as f64 * PREFIX_SCALE * (1.0 - j)
+}
+
+pub(super) fn longest_common_subsequence(s1: &str, s2: &str) -> String {
+    let a: Vec<char> = s1.chars().collect();
+    let b: Vec<char> = s2.chars().collect();
+    let width        = b.len() + 1;
+    let mut table    = vec![0usize; (a.len() + 1) * width];
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            table[i * width + j] = match a[i - 1] == b[j - 1] {
+                true  => { table[(i - 1) * width + j - 1] + 1 },
+                false => { table[(i - 1) * width + j].max(table[i * width + j - 1]) },
+            };
+        }
+    }
+
+    let mut res        = Vec::with_capacity(table[a.len() * width + b.len()]);
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            res.push(a[i - 1]);
+            i -= 1;
+            j -= 1;
+        }
+        else if table[(i - 1) * width + j] >= table[i * width + j - 1]
+        { i -= 1; }
+        else
+        { j -= 1; }
+    }
+    res.iter().rev().collect()
+}
+
+pub(super) fn longest_common_substring(s1: &str, s2: &str) -> String {
+    let a: Vec<char> = s1.chars().collect();
+    let b: Vec<char> = s2.chars().collect();
+    let mut prev     = vec![0usize; b.len() + 1];
+    let mut curr     = vec![0usize; b.len() + 1];
+    let mut best_len = 0;
+    let mut best_end = 0;
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            curr[j] = match a[i - 1] == b[j - 1] {
+                true  => { prev[j - 1] + 1 },
+                false => { 0 },
+            };
+            if curr[j] > best_len {
+                best_len = curr[j];
+                best_end = i;
+            }
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    a[best_end - best_len..best_end].iter().collect()
+}
+
+pub(super) fn hamming(s1: &str, s2: &str) -> Option<usize> {
+    let mut a   = s1.chars();
+    let mut b   = s2.chars();
+    let mut res = 0;
+    loop {
+        match (a.next(), b.next()) {
+            (Some(x), Some(y)) => { if x != y { res += 1; } },
+            (None, None)       => { return Some(res); },
+            _                  => { return None; },
+        }
+    }
+}
+
+/// Counts the n-grams of a `char` slice.
+fn ngrams(chars: &[char], n: usize) -> HashMap<&[char], usize> {
+    if n == 0
+    { panic!("The n-gram size must be at least 1."); }
+
+    let mut res: HashMap<&[char], usize> = HashMap::new();
+    for gram in chars.windows(n) {
+        *res.entry(gram).or_insert(0) += 1;
+    }
+    res
+}
+
+/// Gets the sizes of the intersection and of both multisets of n-grams.
+fn ngram_overlap(s1: &str, s2: &str, n: usize) -> (usize, usize, usize) {
+    let a: Vec<char> = s1.chars().collect();
+    let b: Vec<char> = s2.chars().collect();
+    let grams_a      = ngrams(&a, n);
+    let grams_b      = ngrams(&b, n);
+    let shared       = grams_a.iter()
+                              .map(|(g, count)| (*count).min(*grams_b.get(g).unwrap_or(&0)))
+                              .sum();
+    (shared, a.len().saturating_sub(n - 1), b.len().saturating_sub(n - 1))
+}
+
+pub(super) fn dice(s1: &str, s2: &str, n: usize) -> f64 {
+    let (shared, len_a, len_b) = ngram_overlap(s1, s2, n);
+    if s1 == s2
+    { return 1.0; }
+    if len_a + len_b == 0
+    { return 0.0; }
+
+    2.0 * shared as f64 / (len_a + len_b) as f64
+}
+
+pub(super) fn jaccard(s1: &str, s2: &str, n: usize) -> f64 {
+    let (shared, len_a, len_b) = ngram_overlap(s1, s2, n);
+    if s1 == s2
+    { return 1.0; }
+    if len_a + len_b == 0
+    { return 0.0; }
+
+    shared as f64 / (len_a + len_b - shared) as f64
+}
+
+/// Turns a distance into a similarity in `[0; 1]`, relative to the longer of both strings.
+pub(super) fn normalize(distance: usize, s1: &str, s2: &str) -> f64 {
+    let len = s1.chars().count().max(s2.chars().count());
+    match len {
+        0 => { 1.0 },
+        _ => { 1.0 - distance as f64 / len as f64 },
+    }
+}
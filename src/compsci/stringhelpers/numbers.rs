@@ -0,0 +1,234 @@
+//! In here you'll find the number formats and errors used by the numeric parsing in `StringUtils`.
+use std::fmt::Display;
+
+/// The separators used when writing numbers, which differ between locales.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// The character grouping the integer digits by three, if any.
+    pub thousands_separator: Option<char>,
+    /// The character separating the integer from the fractional digits.
+    pub decimal_separator:   char,
+}
+
+/// The error returned when a String is not a valid number.
+/// Indices count `char`s, not bytes, and refer to the String as passed, including leading whitespace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberParseError {
+    /// The String was empty or only whitespace.
+    Empty,
+    /// The character at the index is not valid there.
+    InvalidDigit(usize, char),
+    /// The thousands separator at the index does not separate groups of three digits.
+    MisplacedSeparator(usize),
+    /// Digits were expected at the index, but the String ended.
+    MissingDigits(usize),
+    /// The number does not fit the target type. The index is that of the first digit which made it overflow,
+    /// or of the start of the number for floats.
+    Overflow(usize),
+    /// The radix is not in `[2; 36]`.
+    InvalidRadix(u32),
+}
+
+impl Display for NumberParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NumberParseError::Empty                 => write!(f, "Empty string."),
+            NumberParseError::InvalidDigit(i, c)    => write!(f, "Invalid character {:?} at index {}.", c, i),
+            NumberParseError::MisplacedSeparator(i) => write!(f, "Misplaced thousands separator at index {}.", i),
+            NumberParseError::MissingDigits(i)      => write!(f, "Missing digits at index {}.", i),
+            NumberParseError::Overflow(i)           => write!(f, "Number too large at index {}.", i),
+            NumberParseError::InvalidRadix(r)       => write!(f, "Invalid radix {}.", r),
+        }
+    }
+}
+
+impl std::error::Error for NumberParseError { }
+
+impl NumberParseError {
+    /// Get the index the error points at.
+    /// # Returns
+    /// An `Option<usize>`, `None` for `Empty` and `InvalidRadix`.
+    #[must_use]
+    pub const fn index(&self) -> Option<usize> {
+        match self {
+            NumberParseError::InvalidDigit(i, _)    |
+            NumberParseError::MisplacedSeparator(i) |
+            NumberParseError::MissingDigits(i)      |
+            NumberParseError::Overflow(i)           => { Some(*i) },
+            _                                       => { None },
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Digits only, with a full stop as decimal separator, as Rust writes numbers.
+    pub const PLAIN:   NumberFormat = NumberFormat { thousands_separator: None,            decimal_separator: '.' };
+    /// English, e.g. `1,234.5`.
+    pub const ENGLISH: NumberFormat = NumberFormat { thousands_separator: Some(','),       decimal_separator: '.' };
+    /// German, e.g. `1.234,5`.
+    pub const GERMAN:  NumberFormat = NumberFormat { thousands_separator: Some('.'),       decimal_separator: ',' };
+    /// French, e.g. `1 234,5` with a narrow no-break space.
+    pub const FRENCH:  NumberFormat = NumberFormat { thousands_separator: Some('\u{202f}'), decimal_separator: ',' };
+    /// Swiss, e.g. `1'234.5`.
+    pub const SWISS:   NumberFormat = NumberFormat { thousands_separator: Some('\''),      decimal_separator: '.' };
+
+    /// Create a new `NumberFormat`.
+    /// # Arguments
+    /// * `thousands_separator: Option<char>` - The character grouping the integer digits, if any.
+    /// * `decimal_separator: char` - The character before the fractional digits.
+    /// # Returns
+    /// A new `NumberFormat`.
+    /// # Panics
+    /// Panics if both separators are the same or if one of them is a digit, a sign or `e`/`E`.
+    #[must_use]
+    pub fn new(thousands_separator: Option<char>, decimal_separator: char) -> NumberFormat {
+        let invalid = |c: char| c.is_ascii_digit() || matches!(c, '+' | '-' | 'e' | 'E');
+        if thousands_separator == Some(decimal_separator)
+           || invalid(decimal_separator)
+           || thousands_separator.is_some_and(invalid)
+        { panic!("Invalid separators {:?} and {:?}.", thousands_separator, decimal_separator); }
+
+        NumberFormat { thousands_separator, decimal_separator }
+    }
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat::PLAIN
+    }
+}
+
+/// Gets the bounds of `chars` without surrounding whitespace.
+fn trimmed(chars: &[char]) -> Result<(usize, usize), NumberParseError> {
+    let start = chars.iter().position(|c| !c.is_whitespace()).ok_or(NumberParseError::Empty)?;
+    let end   = chars.iter().rposition(|c| !c.is_whitespace()).map_or(start, |i| i + 1);
+    Ok((start, end))
+}
+
+/// Skips an optional sign.
+/// # Returns
+/// `true` if the sign is negative.
+fn sign(chars: &[char], pos: &mut usize, end: usize) -> bool {
+    match chars.get(*pos) {
+        Some('-') if *pos < end => { *pos += 1; true },
+        Some('+') if *pos < end => { *pos += 1; false },
+        _                       => { false },
+    }
+}
+
+/// Gets the error for a position where digits were expected.
+fn expected_digits(chars: &[char], pos: usize, end: usize) -> NumberParseError {
+    match pos < end {
+        true  => NumberParseError::InvalidDigit(pos, chars[pos]),
+        false => NumberParseError::MissingDigits(pos),
+    }
+}
+
+/// Reads digits in a radix, which may be grouped by three with a separator, passing each digit and its index to `on_digit`.
+/// # Returns
+/// The number of digits read.
+fn digits<F>(chars: &[char], pos: &mut usize, end: usize, radix: u32, separator: Option<char>, mut on_digit: F) -> Result<usize, NumberParseError>
+where F: FnMut(u32, usize) -> Result<(), NumberParseError> {
+    let mut count          = 0;
+    let mut group          = 0;
+    let mut last_separator = None;
+    while *pos < end {
+        let c = chars[*pos];
+        if Some(c) == separator {
+            // The first group has one to three digits, all following have three.
+            let valid = match last_separator {
+                None    => (1..=3).contains(&group),
+                Some(_) => group == 3,
+            };
+            if !valid
+            { return Err(NumberParseError::MisplacedSeparator(*pos)); }
+            last_separator = Some(*pos);
+            group          = 0;
+        }
+        else {
+            match c.to_digit(radix) {
+                Some(d) => { on_digit(d, *pos)?; },
+                None    => { break; },
+            }
+            count += 1;
+            group += 1;
+        }
+        *pos += 1;
+    }
+    match last_separator {
+        Some(i) if group != 3 => { Err(NumberParseError::MisplacedSeparator(i)) },
+        _                     => { Ok(count) },
+    }
+}
+
+/// Parses an integer in a radix, with an optional thousands separator.
+pub(super) fn parse_int(s: &str, radix: u32, separator: Option<char>) -> Result<isize, NumberParseError> {
+    if !(2..=36).contains(&radix)
+    { return Err(NumberParseError::InvalidRadix(radix)); }
+
+    let chars              = s.chars().collect::<Vec<char>>();
+    let (mut pos, end)     = trimmed(&chars)?;
+    let negative           = sign(&chars, &mut pos, end);
+    let mut res:     isize = 0;
+    // Negative numbers are accumulated downwards, so `isize::MIN` does not overflow.
+    let count = digits(&chars, &mut pos, end, radix, separator, |d, i| {
+        res = res.checked_mul(radix as isize)
+                 .and_then(|r| match negative {
+                     true  => r.checked_sub(d as isize),
+                     false => r.checked_add(d as isize),
+                 })
+                 .ok_or(NumberParseError::Overflow(i))?;
+        Ok(())
+    })?;
+    if count == 0 || pos < end
+    { return Err(expected_digits(&chars, pos, end)); }
+
+    Ok(res)
+}
+
+/// Parses a decimal float in a format, with an optional exponent.
+pub(super) fn parse_float(s: &str, format: &NumberFormat) -> Result<f64, NumberParseError> {
+    let chars          = s.chars().collect::<Vec<char>>();
+    let (mut pos, end) = trimmed(&chars)?;
+    let start          = pos;
+    // The number is rewritten the way `f64::from_str` expects it, which rounds correctly.
+    let mut plain      = String::with_capacity(end - start);
+    if sign(&chars, &mut pos, end)
+    { plain.push('-'); }
+
+    let mut mantissa = digits(&chars, &mut pos, end, 10, format.thousands_separator, |d, _| {
+        plain.push(std::char::from_digit(d, 10).unwrap_or('0'));
+        Ok(())
+    })?;
+    if pos < end && chars[pos] == format.decimal_separator {
+        pos += 1;
+        plain.push('.');
+        mantissa += digits(&chars, &mut pos, end, 10, None, |d, _| {
+            plain.push(std::char::from_digit(d, 10).unwrap_or('0'));
+            Ok(())
+        })?;
+    }
+    if mantissa == 0
+    { return Err(expected_digits(&chars, pos, end)); }
+
+    if pos < end && matches!(chars[pos], 'e' | 'E') {
+        pos += 1;
+        plain.push('e');
+        if sign(&chars, &mut pos, end)
+        { plain.push('-'); }
+        let exponent = digits(&chars, &mut pos, end, 10, None, |d, _| {
+            plain.push(std::char::from_digit(d, 10).unwrap_or('0'));
+            Ok(())
+        })?;
+        if exponent == 0
+        { return Err(expected_digits(&chars, pos, end)); }
+    }
+    if pos < end
+    { return Err(NumberParseError::InvalidDigit(pos, chars[pos])); }
+
+    let res: f64 = plain.parse().map_err(|_| NumberParseError::InvalidDigit(start, chars[start]))?;
+    match res.is_finite() {
+        true  => Ok(res),
+        false => Err(NumberParseError::Overflow(start)),
+    }
+}
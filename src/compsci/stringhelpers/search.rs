@@ -0,0 +1,338 @@
+//! In here you'll find substring search algorithms for single and multiple patterns.
+//! \
+//! All of them work on bytes and return byte offsets, so they can scan text which is not valid UTF-8, such as log files.
+//! Matches may overlap. Empty patterns never match.
+use std::collections::{HashMap, VecDeque};
+
+/// A match of one of the patterns of an `AhoCorasick` automaton.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    /// The index of the pattern which matched.
+    pub pattern: usize,
+    /// The byte offset at which the match starts.
+    pub start:   usize,
+    /// The byte offset right after the match.
+    pub end:     usize,
+}
+
+/// A single pattern prepared for the Knuth-Morris-Pratt algorithm, which never looks at a byte of the haystack twice.
+#[derive(Debug, Clone)]
+pub struct Kmp {
+    pattern:     Vec<u8>,
+    failure:     Vec<usize>,
+    ignore_case: bool,
+}
+
+/// A single pattern prepared for the Boyer-Moore-Horspool algorithm, which skips over parts of the haystack
+/// and is usually the fastest choice for longer patterns.
+#[derive(Debug, Clone)]
+pub struct Horspool {
+    pattern:     Vec<u8>,
+    shift:       [usize; 256],
+    ignore_case: bool,
+}
+
+/// An Aho-Corasick automaton, finding any number of patterns in a single pass over the haystack.
+#[derive(Debug, Clone)]
+pub struct AhoCorasick {
+    nodes:       Vec<AcNode>,
+    lengths:     Vec<usize>,
+    ignore_case: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+struct AcNode {
+    next:    HashMap<u8, usize>,
+    fail:    usize,
+    outputs: Vec<usize>,
+}
+
+/// Lowers a byte if the search ignores ASCII case.
+#[inline]
+fn fold(b: u8, ignore_case: bool) -> u8 {
+    match ignore_case {
+        true  => b.to_ascii_lowercase(),
+        false => b,
+    }
+}
+
+/// Prepares a pattern, lowering it if the search ignores ASCII case.
+fn prepare(pattern: &[u8], ignore_case: bool) -> Vec<u8> {
+    pattern.iter().map(|b| fold(*b, ignore_case)).collect()
+}
+
+impl Kmp {
+    /// Create a new `Kmp` searcher.
+    /// # Arguments
+    /// * `pattern: P` - The pattern, e.g. a `&str` or `&[u8]`.
+    /// * `ignore_case: bool` - Whether to ignore ASCII case.
+    /// # Returns
+    /// A new `Kmp`.
+    #[must_use]
+    pub fn new<P: AsRef<[u8]>>(pattern: P, ignore_case: bool) -> Kmp {
+        let pattern     = prepare(pattern.as_ref(), ignore_case);
+        let mut failure = vec![0; pattern.len()];
+        let mut k       = 0;
+        for i in 1..pattern.len() {
+            while k > 0 && pattern[i] != pattern[k]
+            { k = failure[k - 1]; }
+            if pattern[i] == pattern[k]
+            { k += 1; }
+            failure[i] = k;
+        }
+        Kmp { pattern, failure, ignore_case }
+    }
+    /// Finds the first occurence of the pattern.
+    /// # Arguments
+    /// * `haystack: H` - The text to search in, e.g. a `&str` or `&[u8]`.
+    /// # Returns
+    /// An `Option<usize>` holding the byte offset of the match.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::stringhelpers::search::Kmp;
+    ///
+    /// let kmp = Kmp::new("error", true);
+    /// assert_eq!(Some(20), kmp.find("12:00:01 [main] ... ERROR: disk full"));
+    /// assert_eq!(None, kmp.find("all good"));
+    /// ```
+    #[must_use]
+    pub fn find<H: AsRef<[u8]>>(&self, haystack: H) -> Option<usize> {
+        let mut res = None;
+        self.search(haystack.as_ref(), |start| { res = Some(start); false });
+        res
+    }
+    /// Finds all occurences of the pattern, including overlapping ones.
+    /// # Arguments
+    /// * `haystack: H` - The text to search in, e.g. a `&str` or `&[u8]`.
+    /// # Returns
+    /// A `Vec<usize>` holding the byte offsets of the matches.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::stringhelpers::search::Kmp;
+    ///
+    /// assert_eq!(vec![0, 2, 4], Kmp::new("aba", false).find_all("abababa"));
+    /// // Offsets count bytes, not characters.
+    /// assert_eq!(vec![2, 9], Kmp::new("ß", false).find_all("Maße, Fuß"));
+    /// ```
+    #[must_use]
+    pub fn find_all<H: AsRef<[u8]>>(&self, haystack: H) -> Vec<usize> {
+        let mut res = Vec::new();
+        self.search(haystack.as_ref(), |start| { res.push(start); true });
+        res
+    }
+
+    /// Calls `found` with the start of every match until it returns `false`.
+    fn search<F: FnMut(usize) -> bool>(&self, haystack: &[u8], mut found: F) {
+        let m = self.pattern.len();
+        if m == 0
+        { return; }
+
+        let mut k = 0;
+        for (i, b) in haystack.iter().enumerate() {
+            let b = fold(*b, self.ignore_case);
+            while k > 0 && self.pattern[k] != b
+            { k = self.failure[k - 1]; }
+            if self.pattern[k] == b
+            { k += 1; }
+            if k == m {
+                if !found(i + 1 - m)
+                { return; }
+                k = self.failure[k - 1];
+            }
+        }
+    }
+}
+
+impl Horspool {
+    /// Create a new `Horspool` searcher.
+    /// # Arguments
+    /// * `pattern: P` - The pattern, e.g. a `&str` or `&[u8]`.
+    /// * `ignore_case: bool` - Whether to ignore ASCII case.
+    /// # Returns
+    /// A new `Horspool`.
+    #[must_use]
+    pub fn new<P: AsRef<[u8]>>(pattern: P, ignore_case: bool) -> Horspool {
+        let pattern   = prepare(pattern.as_ref(), ignore_case);
+        let m         = pattern.len();
+        let mut shift = [m.max(1); 256];
+        for (i, b) in pattern.iter().enumerate().take(m.saturating_sub(1)) {
+            shift[*b as usize] = m - 1 - i;
+            if ignore_case
+            { shift[b.to_ascii_uppercase() as usize] = m - 1 - i; }
+        }
+        Horspool { pattern, shift, ignore_case }
+    }
+    /// Finds the first occurence of the pattern.
+    /// # Arguments
+    /// * `haystack: H` - The text to search in, e.g. a `&str` or `&[u8]`.
+    /// # Returns
+    /// An `Option<usize>` holding the byte offset of the match.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::stringhelpers::search::Horspool;
+    ///
+    /// let needle = Horspool::new("Connection refused", false);
+    /// assert_eq!(Some(6), needle.find("WARN: Connection refused (retry 3/5)"));
+    /// assert_eq!(None, needle.find("WARN: connection refused"));
+    /// ```
+    #[must_use]
+    pub fn find<H: AsRef<[u8]>>(&self, haystack: H) -> Option<usize> {
+        let mut res = None;
+        self.search(haystack.as_ref(), |start| { res = Some(start); false });
+        res
+    }
+    /// Finds all occurences of the pattern, including overlapping ones.
+    /// # Arguments
+    /// * `haystack: H` - The text to search in, e.g. a `&str` or `&[u8]`.
+    /// # Returns
+    /// A `Vec<usize>` holding the byte offsets of the matches.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::stringhelpers::search::Horspool;
+    ///
+    /// assert_eq!(vec![0, 4, 12], Horspool::new("GET ", true).find_all("get GET /a; Get /b"));
+    /// assert_eq!(vec![0, 1, 2], Horspool::new("aa", false).find_all("aaaa"));
+    /// ```
+    #[must_use]
+    pub fn find_all<H: AsRef<[u8]>>(&self, haystack: H) -> Vec<usize> {
+        let mut res = Vec::new();
+        self.search(haystack.as_ref(), |start| { res.push(start); true });
+        res
+    }
+
+    /// Calls `found` with the start of every match until it returns `false`.
+    fn search<F: FnMut(usize) -> bool>(&self, haystack: &[u8], mut found: F) {
+        let m = self.pattern.len();
+        if m == 0
+        { return; }
+
+        let mut pos = 0;
+        while pos + m <= haystack.len() {
+            let window = &haystack[pos..pos + m];
+            if window.iter().zip(&self.pattern).all(|(h, p)| fold(*h, self.ignore_case) == *p) && !found(pos)
+            { return; }
+            pos += self.shift[window[m - 1] as usize];
+        }
+    }
+}
+
+impl AhoCorasick {
+    /// Create a new `AhoCorasick` automaton.
+    /// # Arguments
+    /// * `patterns: &[P]` - The patterns, e.g. `&str`s or `&[u8]`s.
+    /// * `ignore_case: bool` - Whether to ignore ASCII case.
+    /// # Returns
+    /// A new `AhoCorasick`.
+    #[must_use]
+    pub fn new<P: AsRef<[u8]>>(patterns: &[P], ignore_case: bool) -> AhoCorasick {
+        let mut nodes   = vec![AcNode::default()];
+        let mut lengths = Vec::with_capacity(patterns.len());
+        for (index, pattern) in patterns.iter().enumerate() {
+            let pattern = prepare(pattern.as_ref(), ignore_case);
+            lengths.push(pattern.len());
+            if pattern.is_empty()
+            { continue; }
+
+            let mut current = 0;
+            for b in pattern {
+                current = match nodes[current].next.get(&b) {
+                    Some(&n) => { n },
+                    None     => {
+                        nodes.push(AcNode::default());
+                        let n = nodes.len() - 1;
+                        nodes[current].next.insert(b, n);
+                        n
+                    },
+                };
+            }
+            nodes[current].outputs.push(index);
+        }
+
+        // Breadth-first, so the failure link of every node is complete before its children are visited.
+        let mut queue: VecDeque<usize> = nodes[0].next.values().copied().collect();
+        while let Some(current) = queue.pop_front() {
+            let children: Vec<(u8, usize)> = nodes[current].next.iter().map(|(b, n)| (*b, *n)).collect();
+            for (b, child) in children {
+                let mut f = nodes[current].fail;
+                let fail  = loop {
+                    match nodes[f].next.get(&b) {
+                        Some(&n)       => { break n; },
+                        None if f == 0 => { break 0; },
+                        None           => { f = nodes[f].fail; },
+                    }
+                };
+                nodes[child].fail = fail;
+                let inherited     = nodes[fail].outputs.clone();
+                nodes[child].outputs.extend(inherited);
+                queue.push_back(child);
+            }
+        }
+        AhoCorasick { nodes, lengths, ignore_case }
+    }
+    /// Get the number of patterns.
+    /// # Returns
+    /// A `usize`.
+    #[inline]
+    #[must_use]
+    pub fn patterns_len(&self) -> usize {
+        self.lengths.len()
+    }
+    /// Finds all occurences of all patterns, including overlapping ones.
+    /// # Arguments
+    /// * `haystack: H` - The text to search in, e.g. a `&str` or `&[u8]`.
+    /// # Returns
+    /// A `Vec<Match>`, sorted by start, then by pattern index.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::stringhelpers::search::{AhoCorasick, Match};
+    ///
+    /// let ac = AhoCorasick::new(&["he", "she", "his", "hers"], false);
+    /// let found: Vec<(usize, usize)> = ac.find_all("ushers").iter().map(|m| (m.pattern, m.start)).collect();
+    /// assert_eq!(vec![(1, 1), (0, 2), (3, 2)], found);
+    ///
+    /// let levels = AhoCorasick::new(&["warn", "error", "fatal"], true);
+    /// let log    = "INFO ok\nWARN slow\nERROR failed\nFatal: giving up";
+    /// assert_eq!(vec![Match { pattern: 0, start: 8, end: 12 },
+    ///                 Match { pattern: 1, start: 18, end: 23 },
+    ///                 Match { pattern: 2, start: 31, end: 36 }], levels.find_all(log));
+    /// ```
+    #[must_use]
+    pub fn find_all<H: AsRef<[u8]>>(&self, haystack: H) -> Vec<Match> {
+        let mut res     = Vec::new();
+        let mut current = 0;
+        for (i, b) in haystack.as_ref().iter().enumerate() {
+            current = self.step(current, fold(*b, self.ignore_case));
+            for pattern in &self.nodes[current].outputs {
+                res.push(Match { pattern: *pattern, start: i + 1 - self.lengths[*pattern], end: i + 1 });
+            }
+        }
+        res.sort_by_key(|m| (m.start, m.pattern));
+        res
+    }
+    /// Determines whether any pattern occurs in the haystack.
+    /// # Arguments
+    /// * `haystack: H` - The text to search in, e.g. a `&str` or `&[u8]`.
+    /// # Returns
+    /// A `bool`.
+    #[must_use]
+    pub fn is_match<H: AsRef<[u8]>>(&self, haystack: H) -> bool {
+        let mut current = 0;
+        for b in haystack.as_ref() {
+            current = self.step(current, fold(*b, self.ignore_case));
+            if !self.nodes[current].outputs.is_empty()
+            { return true; }
+        }
+        false
+    }
+
+    /// Follows the transition for a byte, falling back along the failure links.
+    fn step(&self, mut current: usize, b: u8) -> usize {
+        loop {
+            match self.nodes[current].next.get(&b) {
+                Some(&n)             => { return n; },
+                None if current == 0 => { return 0; },
+                None                 => { current = self.nodes[current].fail; },
+            }
+        }
+    }
+}
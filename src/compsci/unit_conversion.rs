@@ -1,5 +1,6 @@
 //! Unit conversions for computer science.
-use std::ops::*;
+use std::{fmt::Display, ops::*, str::FromStr};
+use crate::math::unit_conversion::{UnitParseError, split_number, trim_number, join_unit};
 /// Binary Prefixes (e.g. kibi, mebi).
 #[derive(PartialEq, Clone, Copy)]
 pub enum BinaryPrefix<T> {
@@ -49,6 +50,187 @@ impl<T: Copy + Shl<Output = T> + Into<u128>> BinaryPrefix<T> {
         }
     }
 }
+impl<T: Copy> BinaryPrefix<T> {
+    /// Gets the value of `self` without the prefix.
+    /// # Returns
+    /// A `T`.
+    #[must_use]
+    pub fn value(&self) -> T {
+        match self {
+            BinaryPrefix::Unity(x) | BinaryPrefix::Kibi(x) | BinaryPrefix::Mebi(x) |
+            BinaryPrefix::Gibi(x)  | BinaryPrefix::Tebi(x) | BinaryPrefix::Pebi(x) |
+            BinaryPrefix::Exbi(x)  | BinaryPrefix::Zebi(x) | BinaryPrefix::Yobi(x) => { *x }
+        }
+    }
+    /// Gets the symbol of the prefix of `self`, e.g. `Ki` for `Kibi`. `Unity` has an empty symbol.
+    /// # Returns
+    /// A `&'static str`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::unit_conversion::BinaryPrefix;
+    /// assert_eq!("Mi", BinaryPrefix::Mebi(3).symbol());
+    /// ```
+    #[must_use]
+    pub fn symbol(&self) -> &'static str {
+        BINARYSYMBOLS[self.index()]
+    }
+
+    pub(crate) fn index(&self) -> usize {
+        match self {
+            BinaryPrefix::Unity(_) => { 0 },
+            BinaryPrefix::Kibi(_)  => { 1 },
+            BinaryPrefix::Mebi(_)  => { 2 },
+            BinaryPrefix::Gibi(_)  => { 3 },
+            BinaryPrefix::Tebi(_)  => { 4 },
+            BinaryPrefix::Pebi(_)  => { 5 },
+            BinaryPrefix::Exbi(_)  => { 6 },
+            BinaryPrefix::Zebi(_)  => { 7 },
+            BinaryPrefix::Yobi(_)  => { 8 },
+        }
+    }
+
+    pub(crate) fn from_index(i: usize, x: T) -> BinaryPrefix<T> {
+        match i {
+            1 => { BinaryPrefix::Kibi(x) },
+            2 => { BinaryPrefix::Mebi(x) },
+            3 => { BinaryPrefix::Gibi(x) },
+            4 => { BinaryPrefix::Tebi(x) },
+            5 => { BinaryPrefix::Pebi(x) },
+            6 => { BinaryPrefix::Exbi(x) },
+            7 => { BinaryPrefix::Zebi(x) },
+            8 => { BinaryPrefix::Yobi(x) },
+            _ => { BinaryPrefix::Unity(x) },
+        }
+    }
+}
+
+impl<T: Copy + Into<f64>> BinaryPrefix<T> {
+    /// Converts `self` to another binary prefix.
+    /// # Arguments
+    /// * `to: fn(f64) -> BinaryPrefix<f64>` - The target prefix, given as its variant, e.g. `BinaryPrefix::Kibi`.
+    /// # Returns
+    /// A `BinaryPrefix<f64>` with the same value as `self`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::unit_conversion::BinaryPrefix;
+    /// assert!(BinaryPrefix::Kibi(3072.0) == BinaryPrefix::Mebi(3).convert_to(BinaryPrefix::Kibi));
+    /// ```
+    #[must_use = "This returns the result of the operation, without modifying the original."]
+    pub fn convert_to(&self, to: fn(f64) -> BinaryPrefix<f64>) -> BinaryPrefix<f64> {
+        let i = to(1.0).index();
+        BinaryPrefix::from_index(i, self.as_f64() / binary_rate(i))
+    }
+    /// Converts `self` to a decimal prefix.
+    /// # Arguments
+    /// * `to: fn(f64) -> DecimalPrefix<f64>` - The target prefix, given as its variant, e.g. `DecimalPrefix::Mega`.
+    /// # Returns
+    /// A `DecimalPrefix<f64>` with the same value as `self`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::unit_conversion::{BinaryPrefix, DecimalPrefix};
+    /// assert!(DecimalPrefix::Kilo(1.024) == BinaryPrefix::Kibi(1).to_decimal_prefix(DecimalPrefix::Kilo));
+    /// ```
+    #[must_use = "This returns the result of the operation, without modifying the original."]
+    pub fn to_decimal_prefix(&self, to: fn(f64) -> DecimalPrefix<f64>) -> DecimalPrefix<f64> {
+        DecimalPrefix::Unity(self.as_f64()).convert_to(to)
+    }
+
+    fn as_f64(&self) -> f64 {
+        self.value().into() * binary_rate(self.index())
+    }
+}
+
+impl BinaryPrefix<f64> {
+    /// Chooses the prefix for `value` which keeps the mantissa in `[1; 1024)`.
+    /// Values beyond the range of the prefixes get yobi.
+    /// # Arguments
+    /// * `value: f64` - The value.
+    /// # Returns
+    /// A `BinaryPrefix<f64>`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::unit_conversion::BinaryPrefix;
+    /// assert!(BinaryPrefix::Kibi(1.5) == BinaryPrefix::from_f64(1536.0));
+    /// assert!(BinaryPrefix::Unity(1000.0) == BinaryPrefix::from_f64(1000.0));
+    /// assert!(BinaryPrefix::Mebi(1.0) == BinaryPrefix::from_f64(1_048_576.0));
+    /// ```
+    #[must_use]
+    pub fn from_f64(value: f64) -> BinaryPrefix<f64> {
+        let mut i = 0;
+        while i < 8 && value.abs() >= binary_rate(i + 1)
+        { i += 1; }
+        BinaryPrefix::from_index(i, value / binary_rate(i))
+    }
+}
+
+/// Formats the value, followed by the prefix symbol. The precision of the formatter is applied to the value.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::unit_conversion::BinaryPrefix;
+/// assert_eq!("3.2 Mi", BinaryPrefix::Mebi(3.2).to_string());
+/// assert_eq!("1.50 Ki", format!("{:.2}", BinaryPrefix::Kibi(1.5)));
+/// ```
+impl<T: Copy + Display> Display for BinaryPrefix<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match f.precision() {
+            Some(p) => write!(f, "{:.*}", p, self.value())?,
+            None    => write!(f, "{}", self.value())?,
+        }
+        match self.symbol() {
+            ""  => Ok(()),
+            sym => write!(f, " {}", sym),
+        }
+    }
+}
+
+/// Parses a number followed by an optional binary prefix symbol and an optional `B` for bytes, e.g. `3.2 MiB` or `512Ki`.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::unit_conversion::BinaryPrefix;
+/// assert!(BinaryPrefix::Mebi(3.2) == "3.2 MiB".parse().unwrap());
+/// assert!(BinaryPrefix::Kibi(512.0) == "512Ki".parse().unwrap());
+/// assert!(BinaryPrefix::Unity(100.0) == "100 B".parse().unwrap());
+/// assert!("3.2 MB".parse::<BinaryPrefix<f64>>().is_err());
+/// ```
+impl FromStr for BinaryPrefix<f64> {
+    type Err = UnitParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, rest) = split_number(s)?;
+        let prefix    = rest.strip_suffix('B').unwrap_or(rest);
+        match BINARYSYMBOLS.iter().position(|s| *s == prefix) {
+            Some(i) => Ok(BinaryPrefix::from_index(i, x)),
+            None    => Err(UnitParseError::UnknownUnit(rest.to_owned())),
+        }
+    }
+}
+
+/// Formats a value with the binary prefix which keeps the mantissa in `[1; 1024)`.
+/// # Arguments
+/// * `value: f64` - The value.
+/// * `unit: &str` - The unit symbol written after the prefix, e.g. `B`. May be empty.
+/// * `precision: usize` - The maximum number of decimal places. Trailing zeros are removed.
+/// # Returns
+/// A `String`.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::unit_conversion::format_binary;
+/// assert_eq!("1.5 KiB", format_binary(1536.0, "B", 2));
+/// assert_eq!("3.2 GiB", format_binary(3_435_973_837.0, "B", 1));
+/// assert_eq!("1 MiB",   format_binary(1_048_575.0, "B", 1));
+/// assert_eq!("512 B",   format_binary(512.0, "B", 2));
+/// ```
+#[must_use]
+pub fn format_binary(value: f64, unit: &str, precision: usize) -> String {
+    let mut i = 0;
+    let mut m = value;
+    // Rounding may carry the mantissa to the next prefix, e.g. 1023.99 → 1024.0.
+    while i < 8 && trim_number(m.abs(), precision).parse::<f64>().unwrap_or(0.0) >= 1024.0 {
+        m /= 1024.0;
+        i += 1;
+    }
+    join_unit(&trim_number(m, precision), BINARYSYMBOLS[i], unit)
+}
 /// # Tests
 /// ```
 /// use lib_rapid::compsci::unit_conversion::BinaryPrefix;
@@ -111,4 +293,244 @@ pub(crate) const BINARYCONVRATE: [f64; 8] = [1024.0,
                                              1125899906842624.0,
                                              1152921504606846976.0,
                                              1180591620717411303424.0,
-                                             1208925819614629174706176.0];
\ No newline at end of file
+                                             1208925819614629174706176.0];
+/// Gets the factor of the binary prefix at index `i` of `BINARYSYMBOLS`.
+fn binary_rate(i: usize) -> f64 {
+    match i {
+        0 => { 1.0 },
+        _ => { BINARYCONVRATE[i - 1] },
+    }
+}
+/// The symbols of the binary prefixes, in the order of the `BinaryPrefix` variants.
+pub(crate) const BINARYSYMBOLS: [&str; 9] = ["", "Ki", "Mi", "Gi", "Ti", "Pi", "Ei", "Zi", "Yi"];
+
+/// Decimal prefixes as used for bytes (e.g. kilo, mega), as opposed to `BinaryPrefix`.
+#[derive(PartialEq, Clone, Copy)]
+pub enum DecimalPrefix<T> {
+    /// x · 10⁰.
+    Unity(T),
+    /// x · 10³.
+    Kilo(T),
+    /// x · 10⁶.
+    Mega(T),
+    /// x · 10⁹.
+    Giga(T),
+    /// x · 10¹².
+    Tera(T),
+    /// x · 10¹⁵.
+    Peta(T),
+    /// x · 10¹⁸.
+    Exa(T),
+    /// x · 10²¹.
+    Zetta(T),
+    /// x · 10²⁴.
+    Yotta(T),
+}
+
+impl<T: Copy + Into<u128>> DecimalPrefix<T> {
+    /// Converts a Decimal-Prefix value into a regular `u128`.
+    /// # Returns
+    /// A `u128`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::unit_conversion::DecimalPrefix;
+    /// assert_eq!(5000u128, DecimalPrefix::Kilo(5u8).to_decimal_u128());
+    /// assert_eq!(1_000_000_000u128, DecimalPrefix::Giga(1u8).to_decimal_u128());
+    /// ```
+    #[must_use = "This returns the result of the operation, without modifying the original."]
+    pub fn to_decimal_u128(&self) -> u128 {
+        self.value().into() * 1000u128.pow(self.index() as u32)
+    }
+}
+
+impl<T: Copy> DecimalPrefix<T> {
+    /// Gets the value of `self` without the prefix.
+    /// # Returns
+    /// A `T`.
+    #[must_use]
+    pub fn value(&self) -> T {
+        match self {
+            DecimalPrefix::Unity(x) | DecimalPrefix::Kilo(x) | DecimalPrefix::Mega(x) |
+            DecimalPrefix::Giga(x)  | DecimalPrefix::Tera(x) | DecimalPrefix::Peta(x) |
+            DecimalPrefix::Exa(x)   | DecimalPrefix::Zetta(x) | DecimalPrefix::Yotta(x) => { *x }
+        }
+    }
+    /// Gets the symbol of the prefix of `self`, e.g. `k` for `Kilo`. `Unity` has an empty symbol.
+    /// # Returns
+    /// A `&'static str`.
+    #[must_use]
+    pub fn symbol(&self) -> &'static str {
+        DECIMALSYMBOLS[self.index()]
+    }
+
+    pub(crate) fn index(&self) -> usize {
+        match self {
+            DecimalPrefix::Unity(_) => { 0 },
+            DecimalPrefix::Kilo(_)  => { 1 },
+            DecimalPrefix::Mega(_)  => { 2 },
+            DecimalPrefix::Giga(_)  => { 3 },
+            DecimalPrefix::Tera(_)  => { 4 },
+            DecimalPrefix::Peta(_)  => { 5 },
+            DecimalPrefix::Exa(_)   => { 6 },
+            DecimalPrefix::Zetta(_) => { 7 },
+            DecimalPrefix::Yotta(_) => { 8 },
+        }
+    }
+
+    pub(crate) fn from_index(i: usize, x: T) -> DecimalPrefix<T> {
+        match i {
+            1 => { DecimalPrefix::Kilo(x) },
+            2 => { DecimalPrefix::Mega(x) },
+            3 => { DecimalPrefix::Giga(x) },
+            4 => { DecimalPrefix::Tera(x) },
+            5 => { DecimalPrefix::Peta(x) },
+            6 => { DecimalPrefix::Exa(x) },
+            7 => { DecimalPrefix::Zetta(x) },
+            8 => { DecimalPrefix::Yotta(x) },
+            _ => { DecimalPrefix::Unity(x) },
+        }
+    }
+}
+
+impl<T: Copy + Into<f64>> DecimalPrefix<T> {
+    /// Converts `self` to another decimal prefix.
+    /// # Arguments
+    /// * `to: fn(f64) -> DecimalPrefix<f64>` - The target prefix, given as its variant, e.g. `DecimalPrefix::Kilo`.
+    /// # Returns
+    /// A `DecimalPrefix<f64>` with the same value as `self`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::unit_conversion::DecimalPrefix;
+    /// assert!(DecimalPrefix::Kilo(2500.0) == DecimalPrefix::Mega(2.5).convert_to(DecimalPrefix::Kilo));
+    /// ```
+    #[must_use = "This returns the result of the operation, without modifying the original."]
+    pub fn convert_to(&self, to: fn(f64) -> DecimalPrefix<f64>) -> DecimalPrefix<f64> {
+        let i = to(1.0).index();
+        DecimalPrefix::from_index(i, self.as_f64() / decimal_rate(i))
+    }
+    /// Converts `self` to a binary prefix.
+    /// # Arguments
+    /// * `to: fn(f64) -> BinaryPrefix<f64>` - The target prefix, given as its variant, e.g. `BinaryPrefix::Gibi`.
+    /// # Returns
+    /// A `BinaryPrefix<f64>` with the same value as `self`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::unit_conversion::{BinaryPrefix, DecimalPrefix};
+    /// // A "500 GB" disk.
+    /// let disk = DecimalPrefix::Giga(500.0).to_binary_prefix(BinaryPrefix::Gibi);
+    /// assert_eq!(465.66, (disk.value() * 100.0).round() / 100.0);
+    /// ```
+    #[must_use = "This returns the result of the operation, without modifying the original."]
+    pub fn to_binary_prefix(&self, to: fn(f64) -> BinaryPrefix<f64>) -> BinaryPrefix<f64> {
+        BinaryPrefix::Unity(self.as_f64()).convert_to(to)
+    }
+
+    fn as_f64(&self) -> f64 {
+        self.value().into() * decimal_rate(self.index())
+    }
+}
+
+impl DecimalPrefix<f64> {
+    /// Chooses the prefix for `value` which keeps the mantissa in `[1; 1000)`.
+    /// Values beyond the range of the prefixes get yotta.
+    /// # Arguments
+    /// * `value: f64` - The value.
+    /// # Returns
+    /// A `DecimalPrefix<f64>`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::compsci::unit_conversion::DecimalPrefix;
+    /// assert!(DecimalPrefix::Kilo(1.536) == DecimalPrefix::from_f64(1536.0));
+    /// assert!(DecimalPrefix::Unity(999.0) == DecimalPrefix::from_f64(999.0));
+    /// ```
+    #[must_use]
+    pub fn from_f64(value: f64) -> DecimalPrefix<f64> {
+        let mut i = 0;
+        while i < 8 && value.abs() >= decimal_rate(i + 1)
+        { i += 1; }
+        DecimalPrefix::from_index(i, value / decimal_rate(i))
+    }
+}
+
+/// Formats the value, followed by the prefix symbol. The precision of the formatter is applied to the value.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::unit_conversion::DecimalPrefix;
+/// assert_eq!("3.2 M", DecimalPrefix::Mega(3.2).to_string());
+/// ```
+impl<T: Copy + Display> Display for DecimalPrefix<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match f.precision() {
+            Some(p) => write!(f, "{:.*}", p, self.value())?,
+            None    => write!(f, "{}", self.value())?,
+        }
+        match self.symbol() {
+            ""  => Ok(()),
+            sym => write!(f, " {}", sym),
+        }
+    }
+}
+
+/// Parses a number followed by an optional decimal prefix symbol and an optional `B` for bytes, e.g. `3.2 MB` or `500k`.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::unit_conversion::DecimalPrefix;
+/// assert!(DecimalPrefix::Mega(3.2) == "3.2 MB".parse().unwrap());
+/// assert!(DecimalPrefix::Kilo(500.0) == "500kB".parse().unwrap());
+/// assert!("3.2 MiB".parse::<DecimalPrefix<f64>>().is_err());
+/// ```
+impl FromStr for DecimalPrefix<f64> {
+    type Err = UnitParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, rest) = split_number(s)?;
+        let prefix    = rest.strip_suffix('B').unwrap_or(rest);
+        match DECIMALSYMBOLS.iter().position(|s| *s == prefix) {
+            Some(i) => Ok(DecimalPrefix::from_index(i, x)),
+            None    => Err(UnitParseError::UnknownUnit(rest.to_owned())),
+        }
+    }
+}
+
+/// # Tests
+/// ```
+/// use lib_rapid::compsci::unit_conversion::DecimalPrefix;
+/// assert_eq!(3_200_000f64, DecimalPrefix::Mega(3.2).into());
+/// ```
+impl<T: Copy + Into<f64>> From<DecimalPrefix<T>> for f64 {
+    fn from(p: DecimalPrefix<T>) -> f64 {
+        p.as_f64()
+    }
+}
+
+/// Formats a value with the decimal prefix which keeps the mantissa in `[1; 1000)`.
+/// # Arguments
+/// * `value: f64` - The value.
+/// * `unit: &str` - The unit symbol written after the prefix, e.g. `B`. May be empty.
+/// * `precision: usize` - The maximum number of decimal places. Trailing zeros are removed.
+/// # Returns
+/// A `String`.
+/// # Examples
+/// ```
+/// use lib_rapid::compsci::unit_conversion::format_decimal;
+/// assert_eq!("1.54 kB", format_decimal(1536.0, "B", 2));
+/// assert_eq!("1 MB",    format_decimal(999_999.0, "B", 1));
+/// ```
+#[must_use]
+pub fn format_decimal(value: f64, unit: &str, precision: usize) -> String {
+    let mut i = 0;
+    let mut m = value;
+    // Rounding may carry the mantissa to the next prefix, e.g. 999.99 → 1000.0.
+    while i < 8 && trim_number(m.abs(), precision).parse::<f64>().unwrap_or(0.0) >= 1000.0 {
+        m /= 1000.0;
+        i += 1;
+    }
+    join_unit(&trim_number(m, precision), DECIMALSYMBOLS[i], unit)
+}
+
+/// Gets the factor of the decimal prefix at index `i` of `DECIMALSYMBOLS`.
+fn decimal_rate(i: usize) -> f64 {
+    1000f64.powi(i as i32)
+}
+/// The symbols of the decimal prefixes, in the order of the `DecimalPrefix` variants.
+pub(crate) const DECIMALSYMBOLS: [&str; 9] = ["", "k", "M", "G", "T", "P", "E", "Z", "Y"];
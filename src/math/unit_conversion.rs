@@ -1,5 +1,10 @@
 //! Traits and functions for converting between units.
+use std::{fmt::Display, str::FromStr};
 use super::constants;
+/// Physical quantities with dimensions, unit-aware arithmetic and conversion.
+pub mod quantity;
+/// Angle types with conversion, normalization and degree-minute-second notation.
+pub mod angle;
 /// SI-Prefixes as used in several fields.
 #[derive(PartialEq, Clone, Copy)]
 pub enum SIPrefix<T> {
@@ -57,6 +62,26 @@ pub enum TempConversion {
     CelsiusToKelvin,
     KelvinToCelsius,
 }
+/// Temperature scales. Used by `convert_temp` and `convert_temp_diff`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TempScale {
+    /// Degrees Celsius, °C.
+    Celsius,
+    /// Degrees Fahrenheit, °F.
+    Fahrenheit,
+    /// Kelvin, K.
+    Kelvin,
+    /// Degrees Rankine, °Ra.
+    Rankine,
+    /// Degrees Réaumur, °Ré.
+    Reaumur,
+    /// Degrees Delisle, °De. The scale runs backwards: higher temperatures have lower values.
+    Delisle,
+    /// Degrees Newton, °N.
+    Newton,
+    /// Degrees Rømer, °Rø.
+    Romer,
+}
 /// The conversion algorithm to be chosen. Used by `angle_conversion`.
 pub enum AngleConversion {
     DegreesToRadians,
@@ -85,6 +110,101 @@ pub trait TempConversionTrait {
     #[must_use = "This returns the result of the operation, without modifying the original."]
     fn temp_conversion(&self, mode: TempConversion) -> Self;
 }
+/// Trait for temperature conversion between any two `TempScale`s.
+pub trait TempScaleConversion {
+    /// Converts a temperature between any two scales. Returns `self` unchanged if `from` and `to` are the same scale.
+    /// # Arguments
+    /// * `&self` - The temperature to be converted.
+    /// * `from: TempScale` - The scale of `self`.
+    /// * `to: TempScale` - The target scale.
+    /// # Returns
+    /// A `Self` containing the result.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::math::unit_conversion::{TempScaleConversion, TempScale};
+    /// use lib_rapid::math::general::delta;
+    ///
+    /// assert!(delta(671.67, 212.0.convert_temp(TempScale::Fahrenheit, TempScale::Rankine)) < 1e-9);
+    /// assert!(delta(0.0, 100.0_f64.convert_temp(TempScale::Celsius, TempScale::Delisle)) < 1e-9);
+    /// assert!(delta(33.0, 100.0_f64.convert_temp(TempScale::Celsius, TempScale::Newton)) < 1e-9);
+    /// assert!(delta(60.0, 100.0_f64.convert_temp(TempScale::Celsius, TempScale::Romer)) < 1e-9);
+    /// assert!(delta(80.0, 373.15_f64.convert_temp(TempScale::Kelvin, TempScale::Reaumur)) < 1e-9);
+    /// assert!(((-40.0_f32).convert_temp(TempScale::Celsius, TempScale::Fahrenheit) + 40.0).abs() < 1e-4);
+    /// assert_eq!(0.1, 0.1_f64.convert_temp(TempScale::Celsius, TempScale::Celsius));
+    /// ```
+    #[must_use = "This returns the result of the operation, without modifying the original."]
+    fn convert_temp(&self, from: TempScale, to: TempScale) -> Self;
+    /// Converts a temperature difference between any two scales. Unlike `convert_temp`, the offsets of the scales are ignored.
+    /// As the Delisle scale runs backwards, differences in Delisle have the opposite sign.
+    /// # Arguments
+    /// * `&self` - The temperature difference to be converted.
+    /// * `from: TempScale` - The scale of `self`.
+    /// * `to: TempScale` - The target scale.
+    /// # Returns
+    /// A `Self` containing the result.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::math::unit_conversion::{TempScaleConversion, TempScale};
+    /// use lib_rapid::math::general::delta;
+    ///
+    /// assert!(delta(18.0, 10.0.convert_temp_diff(TempScale::Kelvin, TempScale::Fahrenheit)) < 1e-9);
+    /// assert!(delta(-15.0, 10.0_f64.convert_temp_diff(TempScale::Celsius, TempScale::Delisle)) < 1e-9);
+    /// assert_eq!(0.1, 0.1_f64.convert_temp_diff(TempScale::Fahrenheit, TempScale::Fahrenheit));
+    /// ```
+    #[must_use = "This returns the result of the operation, without modifying the original."]
+    fn convert_temp_diff(&self, from: TempScale, to: TempScale) -> Self;
+}
+
+impl TempScale {
+    /// Gets the symbol of `self`, e.g. `°C`.
+    /// # Returns
+    /// A `&'static str`.
+    #[must_use]
+    pub const fn symbol(&self) -> &'static str {
+        match self {
+            TempScale::Celsius    => { "°C" },
+            TempScale::Fahrenheit => { "°F" },
+            TempScale::Kelvin     => { "K" },
+            TempScale::Rankine    => { "°Ra" },
+            TempScale::Reaumur    => { "°Ré" },
+            TempScale::Delisle    => { "°De" },
+            TempScale::Newton     => { "°N" },
+            TempScale::Romer      => { "°Rø" },
+        }
+    }
+    /// Gets the size of one degree of `self` in kelvin.
+    /// # Returns
+    /// A `f64`.
+    #[must_use]
+    pub fn factor(&self) -> f64 {
+        match self {
+            TempScale::Celsius    => { 1.0 },
+            TempScale::Fahrenheit => { 5.0 / 9.0 },
+            TempScale::Kelvin     => { 1.0 },
+            TempScale::Rankine    => { 5.0 / 9.0 },
+            TempScale::Reaumur    => { 5.0 / 4.0 },
+            TempScale::Delisle    => { -2.0 / 3.0 },
+            TempScale::Newton     => { 100.0 / 33.0 },
+            TempScale::Romer      => { 40.0 / 21.0 },
+        }
+    }
+    /// Gets the temperature in kelvin at which `self` reads zero.
+    /// # Returns
+    /// A `f64`.
+    #[must_use]
+    pub fn offset(&self) -> f64 {
+        match self {
+            TempScale::Celsius    => { 273.15 },
+            TempScale::Fahrenheit => { 459.67 * 5.0 / 9.0 },
+            TempScale::Kelvin     => { 0.0 },
+            TempScale::Rankine    => { 0.0 },
+            TempScale::Reaumur    => { 273.15 },
+            TempScale::Delisle    => { 373.15 },
+            TempScale::Newton     => { 273.15 },
+            TempScale::Romer      => { 273.15 - 7.5 * 40.0 / 21.0 },
+        }
+    }
+}
 
 impl TempConversionTrait for f64 {
     fn temp_conversion(&self, mode: TempConversion) -> Self {
@@ -99,6 +219,21 @@ impl TempConversionTrait for f64 {
     }
 }
 
+impl TempScaleConversion for f64 {
+    fn convert_temp(&self, from: TempScale, to: TempScale) -> Self {
+        if from == to
+        { return *self; }
+        let kelvin = self * from.factor() + from.offset();
+        (kelvin - to.offset()) / to.factor()
+    }
+
+    fn convert_temp_diff(&self, from: TempScale, to: TempScale) -> Self {
+        if from == to
+        { return *self; }
+        self * from.factor() / to.factor()
+    }
+}
+
 impl TempConversionTrait for f32 {
     fn temp_conversion(&self, mode: TempConversion) -> Self {
         match mode {
@@ -112,6 +247,16 @@ impl TempConversionTrait for f32 {
     }
 }
 
+impl TempScaleConversion for f32 {
+    fn convert_temp(&self, from: TempScale, to: TempScale) -> Self {
+        (*self as f64).convert_temp(from, to) as f32
+    }
+
+    fn convert_temp_diff(&self, from: TempScale, to: TempScale) -> Self {
+        (*self as f64).convert_temp_diff(from, to) as f32
+    }
+}
+
 impl AngleConversionTrait for f64 {
     fn angle_conversion(&self, mode: AngleConversion) -> Self {
         match mode {
@@ -185,6 +330,180 @@ impl<T: Into<f64> + Copy> SIPrefix<T> {
     }
 }
 
+impl<T: Copy> SIPrefix<T> {
+    /// Gets the value of `self` without the prefix.
+    /// # Returns
+    /// A `T`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::math::unit_conversion::SIPrefix;
+    /// assert_eq!(4.7, SIPrefix::Kilo(4.7).value());
+    /// ```
+    #[must_use]
+    pub fn value(&self) -> T {
+        match self {
+            SIPrefix::Yocto(x) | SIPrefix::Zepto(x) | SIPrefix::Atto(x)  | SIPrefix::Femto(x) |
+            SIPrefix::Pico(x)  | SIPrefix::Nano(x)  | SIPrefix::Micro(x) | SIPrefix::Milli(x) |
+            SIPrefix::Centi(x) | SIPrefix::Deci(x)  | SIPrefix::Unity(x) | SIPrefix::Deca(x)  |
+            SIPrefix::Hecto(x) | SIPrefix::Kilo(x)  | SIPrefix::Mega(x)  | SIPrefix::Giga(x)  |
+            SIPrefix::Tera(x)  | SIPrefix::Peta(x)  | SIPrefix::Exa(x)   | SIPrefix::Zetta(x) |
+            SIPrefix::Yotta(x) => { *x }
+        }
+    }
+    /// Gets the symbol of the prefix of `self`, e.g. `k` for `Kilo`. `Unity` has an empty symbol.
+    /// # Returns
+    /// A `&'static str`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::math::unit_conversion::SIPrefix;
+    /// assert_eq!("µ", SIPrefix::Micro(12).symbol());
+    /// assert_eq!("", SIPrefix::Unity(12).symbol());
+    /// ```
+    #[must_use]
+    pub fn symbol(&self) -> &'static str {
+        SISYMBOLS[self.index()]
+    }
+
+    pub(crate) fn index(&self) -> usize {
+        match self {
+            SIPrefix::Yocto(_) => { 0  },
+            SIPrefix::Zepto(_) => { 1  },
+            SIPrefix::Atto(_)  => { 2  },
+            SIPrefix::Femto(_) => { 3  },
+            SIPrefix::Pico(_)  => { 4  },
+            SIPrefix::Nano(_)  => { 5  },
+            SIPrefix::Micro(_) => { 6  },
+            SIPrefix::Milli(_) => { 7  },
+            SIPrefix::Centi(_) => { 8  },
+            SIPrefix::Deci(_)  => { 9  },
+            SIPrefix::Unity(_) => { 10 },
+            SIPrefix::Deca(_)  => { 11 },
+            SIPrefix::Hecto(_) => { 12 },
+            SIPrefix::Kilo(_)  => { 13 },
+            SIPrefix::Mega(_)  => { 14 },
+            SIPrefix::Giga(_)  => { 15 },
+            SIPrefix::Tera(_)  => { 16 },
+            SIPrefix::Peta(_)  => { 17 },
+            SIPrefix::Exa(_)   => { 18 },
+            SIPrefix::Zetta(_) => { 19 },
+            SIPrefix::Yotta(_) => { 20 },
+        }
+    }
+
+    pub(crate) fn from_index(i: usize, x: T) -> SIPrefix<T> {
+        match i {
+            0  => { SIPrefix::Yocto(x) },
+            1  => { SIPrefix::Zepto(x) },
+            2  => { SIPrefix::Atto(x)  },
+            3  => { SIPrefix::Femto(x) },
+            4  => { SIPrefix::Pico(x)  },
+            5  => { SIPrefix::Nano(x)  },
+            6  => { SIPrefix::Micro(x) },
+            7  => { SIPrefix::Milli(x) },
+            8  => { SIPrefix::Centi(x) },
+            9  => { SIPrefix::Deci(x)  },
+            11 => { SIPrefix::Deca(x)  },
+            12 => { SIPrefix::Hecto(x) },
+            13 => { SIPrefix::Kilo(x)  },
+            14 => { SIPrefix::Mega(x)  },
+            15 => { SIPrefix::Giga(x)  },
+            16 => { SIPrefix::Tera(x)  },
+            17 => { SIPrefix::Peta(x)  },
+            18 => { SIPrefix::Exa(x)   },
+            19 => { SIPrefix::Zetta(x) },
+            20 => { SIPrefix::Yotta(x) },
+            _  => { SIPrefix::Unity(x) },
+        }
+    }
+}
+
+impl<T: Into<f64> + Copy> SIPrefix<T> {
+    /// Converts `self` to another prefix.
+    /// # Arguments
+    /// * `to: fn(f64) -> SIPrefix<f64>` - The target prefix, given as its variant, e.g. `SIPrefix::Milli`.
+    /// # Returns
+    /// A `SIPrefix<f64>` with the same value as `self`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::math::unit_conversion::SIPrefix;
+    /// let p = SIPrefix::Kilo(4.7).convert_to(SIPrefix::Milli);
+    /// assert_eq!("m", p.symbol());
+    /// assert!((p.value() - 4_700_000.0).abs() < 1e-6);
+    /// assert!(SIPrefix::Unity(0.5) == SIPrefix::Deci(5).convert_to(SIPrefix::Unity));
+    /// ```
+    #[must_use = "This returns the result of the operation, without modifying the original."]
+    pub fn convert_to(&self, to: fn(f64) -> SIPrefix<f64>) -> SIPrefix<f64> {
+        let i = to(1.0).index();
+        SIPrefix::from_index(i, self.to_decimal_f64() / si_rate(i))
+    }
+}
+
+impl SIPrefix<f64> {
+    /// Chooses the prefix for `value` which keeps the mantissa in `[1; 1000)`.
+    /// Only the prefixes for powers of 1000 are used, i.e. no centi, deci, deca or hecto.
+    /// Values beyond the range of the prefixes get yocto or yotta.
+    /// # Arguments
+    /// * `value: f64` - The value.
+    /// # Returns
+    /// A `SIPrefix<f64>`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::math::unit_conversion::SIPrefix;
+    /// assert!(SIPrefix::Micro(470.0) == SIPrefix::from_f64(0.00047));
+    /// assert!(SIPrefix::Kilo(1.0) == SIPrefix::from_f64(1000.0));
+    /// assert!(SIPrefix::Mega(-2.5) == SIPrefix::from_f64(-2_500_000.0));
+    /// assert!(SIPrefix::Unity(0.0) == SIPrefix::from_f64(0.0));
+    /// ```
+    #[must_use]
+    pub fn from_f64(value: f64) -> SIPrefix<f64> {
+        let i = engineering_index(engineering_exponent(value));
+        SIPrefix::from_index(i, value / si_rate(i))
+    }
+}
+
+/// Formats the value, followed by the prefix symbol. The precision of the formatter is applied to the value.
+/// # Examples
+/// ```
+/// use lib_rapid::math::unit_conversion::SIPrefix;
+/// assert_eq!("4.7 k", SIPrefix::Kilo(4.7).to_string());
+/// assert_eq!("470.00 µ", format!("{:.2}", SIPrefix::Micro(470.0)));
+/// assert_eq!("5", SIPrefix::Unity(5).to_string());
+/// ```
+impl<T: Copy + Display> Display for SIPrefix<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match f.precision() {
+            Some(p) => write!(f, "{:.*}", p, self.value())?,
+            None    => write!(f, "{}", self.value())?,
+        }
+        match self.symbol() {
+            ""  => Ok(()),
+            sym => write!(f, " {}", sym),
+        }
+    }
+}
+
+/// Parses a number followed by an optional SI prefix symbol, e.g. `4.7 k` or `12µ`.
+/// Micro may also be written as `u` or `μ`.
+/// # Examples
+/// ```
+/// use lib_rapid::math::unit_conversion::{SIPrefix, UnitParseError};
+/// assert!(SIPrefix::Kilo(4.7) == "4.7 k".parse().unwrap());
+/// assert!(SIPrefix::Micro(12.0) == "12u".parse().unwrap());
+/// assert!(SIPrefix::Deca(1.0) == "1 da".parse().unwrap());
+/// assert_eq!(Err(UnitParseError::UnknownUnit("q".to_owned())), "3 q".parse::<SIPrefix<f64>>().map(|p| p.value()));
+/// ```
+impl FromStr for SIPrefix<f64> {
+    type Err = UnitParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, rest) = split_number(s)?;
+        match si_prefix_index(rest) {
+            Some(i) => Ok(SIPrefix::from_index(i, x)),
+            None    => Err(UnitParseError::UnknownUnit(rest.to_owned())),
+        }
+    }
+}
+
 impl<T: Into<f64> + Copy> Into<f64> for SIPrefix<T> {
     fn into(self) -> f64 {
         self.to_decimal_f64()
@@ -216,4 +535,144 @@ pub(crate) const SIRATES: [f64; 20] = [1e-24,
                                        1e15,
                                        1e18,
                                        1e21,
-                                       1e24];
\ No newline at end of file
+                                       1e24];
+/// The symbols of the SI prefixes, in the order of the `SIPrefix` variants.
+pub(crate) const SISYMBOLS: [&str; 21] = ["y", "z", "a", "f", "p", "n", "µ", "m", "c", "d", "",
+                                          "da", "h", "k", "M", "G", "T", "P", "E", "Z", "Y"];
+
+/// The error returned when a value with a unit could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnitParseError {
+    /// The string was empty.
+    Empty,
+    /// The string did not start with a number.
+    InvalidNumber(String),
+    /// The number was followed by an unknown prefix or unit.
+    UnknownUnit(String),
+}
+
+impl Display for UnitParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnitParseError::Empty            => write!(f, "Empty string."),
+            UnitParseError::InvalidNumber(s) => write!(f, "Invalid number: {}", s),
+            UnitParseError::UnknownUnit(s)   => write!(f, "Unknown unit: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for UnitParseError { }
+
+/// Formats a value with the SI prefix which keeps the mantissa in `[1; 1000)`.
+/// Only the prefixes for powers of 1000 are used, i.e. no centi, deci, deca or hecto.
+/// # Arguments
+/// * `value: f64` - The value.
+/// * `unit: &str` - The unit symbol written after the prefix. May be empty.
+/// * `precision: usize` - The maximum number of decimal places. Trailing zeros are removed.
+/// # Returns
+/// A `String`.
+/// # Examples
+/// ```
+/// use lib_rapid::math::unit_conversion::format_si;
+/// assert_eq!("470 µ",   format_si(0.00047, "", 2));
+/// assert_eq!("4.7 kΩ",  format_si(4700.0, "Ω", 2));
+/// assert_eq!("1.23 MW", format_si(1_234_567.0, "W", 2));
+/// assert_eq!("1 kV",    format_si(999.96, "V", 1));
+/// assert_eq!("0 s",     format_si(0.0, "s", 3));
+/// ```
+#[must_use]
+pub fn format_si(value: f64, unit: &str, precision: usize) -> String {
+    format_si_powi(value, unit, 1, precision)
+}
+
+/// Formats `value` in a unit raised to `power`, e.g. `m²`, whose prefix is raised to the same power.
+pub(crate) fn format_si_powi(value: f64, unit: &str, power: i32, precision: usize) -> String {
+    let rate  = |i: usize| si_rate(i).powi(power);
+    let mut e = engineering_exponent(value.abs().powf(1.0 / power as f64));
+    // Rounding may carry the mantissa to the next prefix, e.g. 999.96 → 1000.0.
+    if e < 8 && round_to(value / rate(engineering_index(e)), precision).abs() >= 1000f64.powi(power)
+    { e += 1; }
+    let i = engineering_index(e);
+    join_unit(&trim_number(value / rate(i), precision), SISYMBOLS[i], unit)
+}
+
+/// Gets the power of 1000 (-8 to 8) which keeps the mantissa of `value` in `[1; 1000)`.
+fn engineering_exponent(value: f64) -> i32 {
+    if value == 0.0 || !value.is_finite()
+    { return 0; }
+    let mut e = ((value.abs().log10() / 3.0).floor() as i32).clamp(-8, 8);
+    // `log10` is not exact, so the exponent may be off by one near powers of 1000.
+    let m = value.abs() / si_rate(engineering_index(e));
+    if m >= 1000.0 && e < 8
+    { e += 1; }
+    else if m < 1.0 && e > -8
+    { e -= 1; }
+    e
+}
+
+/// Gets the factor of the prefix at index `i` of `SISYMBOLS`.
+pub(crate) fn si_rate(i: usize) -> f64 {
+    match i {
+        0..=9   => { SIRATES[i] },
+        11..=20 => { SIRATES[i - 1] },
+        _       => { 1.0 },
+    }
+}
+
+/// Maps a power of 1000 (-8 to 8) to an index into `SISYMBOLS`.
+fn engineering_index(e: i32) -> usize {
+    match e {
+        -8..=-1 => { (e + 8) as usize },
+        1..=8   => { (e + 12) as usize },
+        _       => { 10 },
+    }
+}
+
+/// Gets the index into `SISYMBOLS` of a prefix symbol.
+pub(crate) fn si_prefix_index(symbol: &str) -> Option<usize> {
+    match symbol {
+        "u" | "μ" => Some(6),
+        _         => SISYMBOLS.iter().position(|s| *s == symbol),
+    }
+}
+
+/// Splits a string into the leading number and the trimmed rest.
+pub(crate) fn split_number(s: &str) -> Result<(f64, &str), UnitParseError> {
+    let s = s.trim();
+    if s.is_empty()
+    { return Err(UnitParseError::Empty); }
+
+    let end = s.find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '+' | '-' | 'e' | 'E')))
+               .unwrap_or(s.len());
+    // The longest prefix which is a number wins, so `3EiB` is read as `3` followed by `EiB`.
+    for i in (1..=end).rev() {
+        if let Ok(x) = s[..i].parse::<f64>()
+        { return Ok((x, s[i..].trim_start())); }
+    }
+    Err(UnitParseError::InvalidNumber(s.to_owned()))
+}
+
+/// Formats `x` with at most `precision` decimal places and removes trailing zeros.
+pub(crate) fn trim_number(x: f64, precision: usize) -> String {
+    let mut res = format!("{:.*}", precision, x);
+    if res.contains('.')
+    { res.truncate(res.trim_end_matches('0').trim_end_matches('.').len()); }
+    match res.as_str() {
+        "-0" => "0".to_owned(),
+        _    => res,
+    }
+}
+
+fn round_to(x: f64, precision: usize) -> f64 {
+    let f = 10f64.powi(precision as i32);
+    (x * f).round() / f
+}
+
+/// Joins a formatted number, a prefix symbol and a unit symbol.
+pub(crate) fn join_unit(number: &str, prefix: &str, unit: &str) -> String {
+    match (prefix.is_empty() && unit.is_empty(), unit) {
+        (true, _)  => number.to_owned(),
+        (_, "°")   => format!("{}{}°", number, prefix),
+        _          => format!("{} {}{}", number, prefix, unit),
+    }
+}
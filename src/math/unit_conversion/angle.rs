@@ -0,0 +1,370 @@
+//! Angle types with conversion, normalization, arithmetic and degree-minute-second notation.
+use std::{fmt::Display, ops::*, str::FromStr};
+use crate::math::constants::{DEGRADRATE, RADDEGRATE, TAU};
+use super::UnitParseError;
+
+/// An angle in degrees. A full turn is 360°.
+/// # Examples
+/// ```
+/// use lib_rapid::math::unit_conversion::angle::{Degrees, Radians, Turns};
+///
+/// let a = Degrees(350.0) + Degrees(20.0);
+/// assert_eq!(Degrees(10.0), a.normalized());
+/// assert_eq!(Degrees(-90.0), Degrees(270.0).normalized_signed());
+/// assert_eq!(Degrees(0.0), Degrees(-1e-20).normalized());
+/// assert_eq!(Turns(0.25), Degrees(90.0).into());
+/// assert!((Radians::from(Degrees(180.0)).0 - std::f64::consts::PI).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Degrees(pub f64);
+
+/// An angle in radians. A full turn is 2π rad.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Radians(pub f64);
+
+/// An angle in gradians (gon). A full turn is 400 gon.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Gradians(pub f64);
+
+/// An angle in turns. A full turn is 1.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Turns(pub f64);
+
+macro_rules! angle_type {
+    ($t:ident, $full:expr, $symbol:expr, $to_deg:expr, $from_deg:expr) => {
+        impl $t {
+            /// The size of a full turn.
+            pub const FULL_TURN: $t = $t($full);
+
+            /// Normalizes `self` into `[0; full turn)`.
+            /// # Returns
+            /// A new angle.
+            #[must_use = "This returns the result of the operation, without modifying the original."]
+            pub fn normalized(&self) -> $t {
+                // Tiny negative angles round up to a full turn, which is not in the range.
+                match self.0.rem_euclid($full) {
+                    x if x == $full => $t(0.0),
+                    x               => $t(x),
+                }
+            }
+            /// Normalizes `self` into `(-half turn; half turn]`.
+            /// # Returns
+            /// A new angle.
+            #[must_use = "This returns the result of the operation, without modifying the original."]
+            pub fn normalized_signed(&self) -> $t {
+                let x = self.0.rem_euclid($full);
+                match x > $full / 2.0 {
+                    true  => $t(x - $full),
+                    false => $t(x),
+                }
+            }
+            /// Converts `self` to degrees.
+            /// # Returns
+            /// A `Degrees`.
+            #[must_use]
+            pub fn to_degrees(&self) -> Degrees {
+                Degrees::from(*self)
+            }
+            /// Converts `self` to radians.
+            /// # Returns
+            /// A `Radians`.
+            #[must_use]
+            pub fn to_radians(&self) -> Radians {
+                Radians::from(*self)
+            }
+            /// Converts `self` to gradians.
+            /// # Returns
+            /// A `Gradians`.
+            #[must_use]
+            pub fn to_gradians(&self) -> Gradians {
+                Gradians::from(*self)
+            }
+            /// Converts `self` to turns.
+            /// # Returns
+            /// A `Turns`.
+            #[must_use]
+            pub fn to_turns(&self) -> Turns {
+                Turns::from(*self)
+            }
+            /// Computes the sine of `self`.
+            /// # Returns
+            /// A `f64`.
+            #[must_use]
+            pub fn sin(&self) -> f64 {
+                self.to_radians().0.sin()
+            }
+            /// Computes the cosine of `self`.
+            /// # Returns
+            /// A `f64`.
+            #[must_use]
+            pub fn cos(&self) -> f64 {
+                self.to_radians().0.cos()
+            }
+            /// Computes the tangent of `self`.
+            /// # Returns
+            /// A `f64`.
+            #[must_use]
+            pub fn tan(&self) -> f64 {
+                self.to_radians().0.tan()
+            }
+
+            fn degrees(&self) -> f64 {
+                $to_deg(self.0)
+            }
+
+            fn from_degrees(deg: f64) -> $t {
+                $t($from_deg(deg))
+            }
+        }
+
+        impl Add for $t {
+            type Output = $t;
+
+            fn add(self, rhs: $t) -> $t {
+                $t(self.0 + rhs.0)
+            }
+        }
+
+        impl Sub for $t {
+            type Output = $t;
+
+            fn sub(self, rhs: $t) -> $t {
+                $t(self.0 - rhs.0)
+            }
+        }
+
+        impl Mul<f64> for $t {
+            type Output = $t;
+
+            fn mul(self, rhs: f64) -> $t {
+                $t(self.0 * rhs)
+            }
+        }
+
+        impl Div<f64> for $t {
+            type Output = $t;
+
+            fn div(self, rhs: f64) -> $t {
+                $t(self.0 / rhs)
+            }
+        }
+
+        impl Div for $t {
+            type Output = f64;
+
+            fn div(self, rhs: $t) -> f64 {
+                self.0 / rhs.0
+            }
+        }
+
+        impl Neg for $t {
+            type Output = $t;
+
+            fn neg(self) -> $t {
+                $t(-self.0)
+            }
+        }
+
+        impl AddAssign for $t {
+            fn add_assign(&mut self, rhs: $t) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl SubAssign for $t {
+            fn sub_assign(&mut self, rhs: $t) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl Display for $t {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match f.precision() {
+                    Some(p) => write!(f, "{:.*}{}", p, self.0, $symbol),
+                    None    => write!(f, "{}{}", self.0, $symbol),
+                }
+            }
+        }
+    };
+}
+
+angle_type!(Degrees,  360.0, "°",     |x: f64| x,              |x: f64| x);
+angle_type!(Radians,  TAU,   " rad",  |x: f64| x * RADDEGRATE, |x: f64| x * DEGRADRATE);
+angle_type!(Gradians, 400.0, " gon",  |x: f64| x * 0.9,        |x: f64| x / 0.9);
+angle_type!(Turns,    1.0,   " tr",   |x: f64| x * 360.0,      |x: f64| x / 360.0);
+
+macro_rules! angle_from {
+    ($($from:ident => $to:ident),*) => {
+        $(impl From<$from> for $to {
+            fn from(a: $from) -> $to {
+                $to::from_degrees(a.degrees())
+            }
+        })*
+    };
+}
+
+angle_from!(Degrees  => Radians,  Degrees  => Gradians, Degrees  => Turns,
+            Radians  => Degrees,  Radians  => Gradians, Radians  => Turns,
+            Gradians => Degrees,  Gradians => Radians,  Gradians => Turns,
+            Turns    => Degrees,  Turns    => Radians,  Turns    => Gradians);
+
+impl Degrees {
+    /// Creates an angle from degrees, arc-minutes and arc-seconds. The sign of `deg` applies to the whole angle.
+    /// # Arguments
+    /// * `deg: f64` - The degrees.
+    /// * `min: f64` - The arc-minutes.
+    /// * `sec: f64` - The arc-seconds.
+    /// # Returns
+    /// A new `Degrees`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::math::unit_conversion::angle::Degrees;
+    /// assert_eq!(Degrees(-33.5), Degrees::from_dms(-33.0, 30.0, 0.0));
+    /// ```
+    #[must_use]
+    pub fn from_dms(deg: f64, min: f64, sec: f64) -> Degrees {
+        let abs = deg.abs() + min / 60.0 + sec / 3600.0;
+        match deg.is_sign_negative() {
+            true  => Degrees(-abs),
+            false => Degrees(abs),
+        }
+    }
+    /// Splits `self` into degrees, arc-minutes and arc-seconds. The sign is carried by the degrees.
+    /// # Returns
+    /// A `(f64, f64, f64)`.
+    #[must_use]
+    pub fn to_dms(&self) -> (f64, f64, f64) {
+        let total = self.0.abs() * 3600.0;
+        let deg   = (total / 3600.0).floor();
+        let min   = ((total - deg * 3600.0) / 60.0).floor();
+        let sec   = total - deg * 3600.0 - min * 60.0;
+        (deg.copysign(self.0), min, sec)
+    }
+    /// Gets `self` in arc-minutes.
+    /// # Returns
+    /// A `f64`.
+    #[must_use]
+    pub fn to_arcminutes(&self) -> f64 {
+        self.0 * 60.0
+    }
+    /// Gets `self` in arc-seconds.
+    /// # Returns
+    /// A `f64`.
+    #[must_use]
+    pub fn to_arcseconds(&self) -> f64 {
+        self.0 * 3600.0
+    }
+    /// Formats `self` in degree-minute-second notation, e.g. `-33°51'2.0"`.
+    /// # Arguments
+    /// * `precision: usize` - The number of decimal places of the arc-seconds.
+    /// # Returns
+    /// A `String`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::math::unit_conversion::angle::Degrees;
+    /// assert_eq!("12°30'0.0\"", Degrees(12.5).format_dms(1));
+    /// assert_eq!("-1°0'0\"", Degrees(-0.99999).format_dms(0));
+    /// ```
+    #[must_use]
+    pub fn format_dms(&self, precision: usize) -> String {
+        let (d, m, s) = self.dms_parts(precision);
+        let sign = if self.0 < 0.0 && (d, m, s) != (0, 0, 0.0) { "-" } else { "" };
+        format!("{}{}°{}'{:.*}\"", sign, d, m, precision, s)
+    }
+    /// Formats `self` as a latitude in degree-minute-second notation, e.g. `48°51'29.6"N`.
+    /// # Arguments
+    /// * `precision: usize` - The number of decimal places of the arc-seconds.
+    /// # Returns
+    /// A `String`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::math::unit_conversion::angle::Degrees;
+    /// assert_eq!("48°51'29.6\"N", Degrees(48.858222).format_latitude(1));
+    /// assert_eq!("33°51'54.5\"S", Degrees(-33.865143).format_latitude(1));
+    /// ```
+    #[must_use]
+    pub fn format_latitude(&self, precision: usize) -> String {
+        self.format_hemisphere(precision, 'N', 'S')
+    }
+    /// Formats `self` as a longitude in degree-minute-second notation, e.g. `2°17'40.2"E`.
+    /// # Arguments
+    /// * `precision: usize` - The number of decimal places of the arc-seconds.
+    /// # Returns
+    /// A `String`.
+    #[must_use]
+    pub fn format_longitude(&self, precision: usize) -> String {
+        self.format_hemisphere(precision, 'E', 'W')
+    }
+
+    fn format_hemisphere(&self, precision: usize, pos: char, neg: char) -> String {
+        let (d, m, s) = self.dms_parts(precision);
+        format!("{}°{}'{:.*}\"{}", d, m, precision, s, if self.0 < 0.0 { neg } else { pos })
+    }
+
+    /// Splits the absolute value into whole degrees, whole minutes and seconds rounded to `precision`,
+    /// carrying over if the seconds round up to 60.
+    fn dms_parts(&self, precision: usize) -> (u64, u64, f64) {
+        let f     = 10f64.powi(precision as i32);
+        let total = (self.0.abs() * 3600.0 * f).round() / f;
+        let d     = (total / 3600.0).floor();
+        let m     = ((total - d * 3600.0) / 60.0).floor();
+        let s     = ((total - d * 3600.0 - m * 60.0) * f).round() / f;
+        (d as u64, m as u64, s)
+    }
+}
+
+/// Parses decimal degrees (`48.858`, `48.858°`) or degree-minute-second notation (`48°51'29.6"N`, `2° 17′ 40.2″ E`).
+/// The hemispheres `S` and `W` as well as a leading `-` make the angle negative.
+/// # Examples
+/// ```
+/// use lib_rapid::math::unit_conversion::angle::Degrees;
+///
+/// let lat: Degrees = "48°51'29.6\"N".parse().unwrap();
+/// assert!((lat.0 - 48.858222).abs() < 1e-6);
+/// let lon: Degrees = "2° 17′ 40.2″ W".parse().unwrap();
+/// assert!((lon.0 + 2.294500).abs() < 1e-6);
+/// assert_eq!(Degrees(-12.5), "-12°30'".parse().unwrap());
+/// assert_eq!(Degrees(48.5), "48.5".parse().unwrap());
+/// assert!("48°x'".parse::<Degrees>().is_err());
+/// ```
+impl FromStr for Degrees {
+    type Err = UnitParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().replace('′', "'").replace('″', "\"").replace("''", "\"");
+        if s.is_empty()
+        { return Err(UnitParseError::Empty); }
+
+        let (body, negative) = match s.strip_suffix(['N', 'E', 'S', 'W']) {
+            Some(b) => (b, s.ends_with(['S', 'W'])),
+            None    => (s.as_str(), false),
+        };
+        let invalid   = || UnitParseError::InvalidNumber(s.to_owned());
+        let mut parts = [0.0; 3];
+        let mut rest  = body.trim();
+        let mut found = false;
+        let mut sign  = false;
+        for (i, mark) in ['°', '\'', '"'].iter().enumerate() {
+            if let Some(end) = rest.find(*mark) {
+                let n = rest[..end].trim();
+                sign     |= n.starts_with('-');
+                parts[i]  = n.parse::<f64>().map_err(|_| invalid())?.abs();
+                rest      = rest[end + mark.len_utf8()..].trim_start();
+                found     = true;
+            }
+        }
+        if !found {
+            parts[0] = rest.parse::<f64>().map_err(|_| invalid())?;
+            sign     = parts[0] < 0.0;
+            parts[0] = parts[0].abs();
+        }
+        else if !rest.is_empty()
+        { return Err(invalid()); }
+
+        let d = Degrees::from_dms(parts[0], parts[1], parts[2]);
+        match negative || sign {
+            true  => Ok(-d),
+            false => Ok(d),
+        }
+    }
+}
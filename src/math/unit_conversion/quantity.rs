@@ -0,0 +1,795 @@
+//! Physical quantities which carry their dimension, with unit-aware arithmetic and conversion.
+use std::{cmp::Ordering, fmt::Display, ops::*, str::FromStr};
+use crate::math::constants::{EARTHGRAVITY, PI};
+use super::{UnitParseError, format_si_powi, si_prefix_index, si_rate, split_number, trim_number, join_unit};
+
+const DIM_SYMBOLS: [&str; 7] = ["m", "kg", "s", "A", "K", "mol", "cd"];
+const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+/// The dimension of a quantity, as the exponents of the seven SI base dimensions.
+/// \
+/// The order of the exponents is length, mass, time, electric current, temperature, amount of substance and
+/// luminous intensity.
+/// # Examples
+/// ```
+/// use lib_rapid::math::unit_conversion::quantity::Dimension;
+///
+/// let speed = Dimension::LENGTH / Dimension::TIME;
+/// assert_eq!(Dimension::new([1, 0, -1, 0, 0, 0, 0]), speed);
+/// assert_eq!("m·s⁻¹", speed.to_string());
+/// assert!((speed / speed).is_dimensionless());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Dimension {
+    /// The exponents of the base dimensions.
+    pub exponents: [i8; 7],
+}
+
+/// A unit: a named scale for a dimension.
+/// \
+/// A value `x` in this unit equals `x · factor + offset` in the coherent SI unit of `dimension`.
+/// The offset is only non-zero for scales like Celsius and Fahrenheit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Unit {
+    /// The symbol, e.g. `km`.
+    pub symbol:    &'static str,
+    /// The dimension measured by this unit.
+    pub dimension: Dimension,
+    /// The factor to the coherent SI unit.
+    pub factor:    f64,
+    /// The offset to the coherent SI unit.
+    pub offset:    f64,
+}
+
+/// A value together with its dimension. The value is stored in coherent SI units (metres, kilograms, seconds...).
+/// \
+/// Adding or subtracting quantities of different dimensions, or two absolute temperatures, panics; use `try_add` and `try_sub` to get an error instead.
+/// Multiplication and division combine the dimensions.
+/// \
+/// A quantity created in a unit with an offset, like `°C`, is an absolute temperature. Two of them can be subtracted,
+/// which gives a temperature difference, but not added. A difference, created by `Quantity::difference`, is converted
+/// without the offset, so `10 °C - 5 °C` is `5 °C` again. Multiplication, division and powers give plain quantities.
+/// # Examples
+/// ```
+/// use lib_rapid::math::unit_conversion::quantity::{Quantity, units, Dimension};
+///
+/// let distance = Quantity::new(42.195, &units::KILOMETRE);
+/// let time     = Quantity::new(2.0,    &units::HOUR) + Quantity::new(1.0, &units::MINUTE);
+/// let speed    = distance / time;
+///
+/// assert_eq!(Dimension::LENGTH / Dimension::TIME, speed.dimension());
+/// assert!((speed.to(&units::KILOMETRE_PER_HOUR).unwrap() - 20.923).abs() < 1e-3);
+/// assert!(speed.to(&units::SECOND).is_err());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Quantity {
+    value:     f64,
+    dimension: Dimension,
+    scale:     Scale,
+}
+
+/// Whether the value of a `Quantity` is measured from the origin of a unit with an offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scale {
+    /// Created without an offset, e.g. in kelvin.
+    Plain,
+    /// An absolute temperature on an offset scale, e.g. in °C.
+    Absolute,
+    /// A temperature difference, which is converted without the offset.
+    Difference,
+}
+
+/// The error returned when quantities or units of different dimensions are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DimensionError {
+    /// The dimension which was required.
+    pub expected: Dimension,
+    /// The dimension which was given.
+    pub found:    Dimension,
+}
+
+/// The error returned when adding or subtracting quantities fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuantityError {
+    /// The dimensions of both quantities differ.
+    Dimension(DimensionError),
+    /// Both quantities are absolute temperatures, which cannot be added.
+    AbsoluteSum,
+    /// An absolute temperature was subtracted from a temperature difference.
+    AbsoluteFromDifference,
+}
+
+impl Dimension {
+    /// No dimension, e.g. for ratios.
+    pub const DIMENSIONLESS: Dimension = Dimension::new([0, 0, 0, 0, 0, 0, 0]);
+    /// Length, measured in metres.
+    pub const LENGTH:        Dimension = Dimension::new([1, 0, 0, 0, 0, 0, 0]);
+    /// Mass, measured in kilograms.
+    pub const MASS:          Dimension = Dimension::new([0, 1, 0, 0, 0, 0, 0]);
+    /// Time, measured in seconds.
+    pub const TIME:          Dimension = Dimension::new([0, 0, 1, 0, 0, 0, 0]);
+    /// Electric current, measured in ampere.
+    pub const CURRENT:       Dimension = Dimension::new([0, 0, 0, 1, 0, 0, 0]);
+    /// Thermodynamic temperature, measured in kelvin.
+    pub const TEMPERATURE:   Dimension = Dimension::new([0, 0, 0, 0, 1, 0, 0]);
+    /// Amount of substance, measured in mole.
+    pub const AMOUNT:        Dimension = Dimension::new([0, 0, 0, 0, 0, 1, 0]);
+    /// Luminous intensity, measured in candela.
+    pub const LUMINOSITY:    Dimension = Dimension::new([0, 0, 0, 0, 0, 0, 1]);
+
+    /// Creates a new `Dimension`.
+    /// # Arguments
+    /// * `exponents: [i8; 7]` - The exponents of length, mass, time, current, temperature, amount and luminosity.
+    /// # Returns
+    /// A new `Dimension`.
+    #[must_use]
+    pub const fn new(exponents: [i8; 7]) -> Dimension {
+        Dimension { exponents }
+    }
+    /// Determines whether `self` has no dimension.
+    /// # Returns
+    /// A `bool`.
+    #[must_use]
+    pub const fn is_dimensionless(&self) -> bool {
+        let mut i = 0;
+        while i < 7 {
+            if self.exponents[i] != 0
+            { return false; }
+            i += 1;
+        }
+        true
+    }
+    /// Raises `self` to an integer power.
+    /// # Arguments
+    /// * `n: i8` - The power.
+    /// # Returns
+    /// A new `Dimension`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::math::unit_conversion::quantity::Dimension;
+    ///
+    /// assert_eq!("m³", Dimension::LENGTH.powi(3).to_string());
+    /// ```
+    #[must_use = "This returns the result of the operation, without modifying the original."]
+    pub const fn powi(&self, n: i8) -> Dimension {
+        let mut res = self.exponents;
+        let mut i   = 0;
+        while i < 7 {
+            res[i] *= n;
+            i += 1;
+        }
+        Dimension::new(res)
+    }
+
+    const fn combine(&self, other: &Dimension, sign: i8) -> Dimension {
+        let mut res = self.exponents;
+        let mut i   = 0;
+        while i < 7 {
+            res[i] += sign * other.exponents[i];
+            i += 1;
+        }
+        Dimension::new(res)
+    }
+}
+
+impl Mul for Dimension {
+    type Output = Dimension;
+
+    fn mul(self, rhs: Dimension) -> Dimension {
+        self.combine(&rhs, 1)
+    }
+}
+
+impl Div for Dimension {
+    type Output = Dimension;
+
+    fn div(self, rhs: Dimension) -> Dimension {
+        self.combine(&rhs, -1)
+    }
+}
+
+impl Display for Dimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_dimensionless()
+        { return write!(f, "1"); }
+
+        let mut parts: Vec<String> = Vec::new();
+        for (sym, e) in DIM_SYMBOLS.iter().zip(self.exponents) {
+            if e == 0
+            { continue; }
+            let mut part = (*sym).to_owned();
+            if e != 1 {
+                if e < 0
+                { part.push('⁻'); }
+                for d in e.unsigned_abs().to_string().bytes()
+                { part.push(SUPERSCRIPTS[(d - b'0') as usize]); }
+            }
+            parts.push(part);
+        }
+        write!(f, "{}", parts.join("·"))
+    }
+}
+
+impl Unit {
+    /// Creates a new `Unit` without an offset.
+    /// # Arguments
+    /// * `symbol: &'static str` - The symbol.
+    /// * `dimension: Dimension` - The dimension measured by this unit.
+    /// * `factor: f64` - The factor to the coherent SI unit.
+    /// # Returns
+    /// A new `Unit`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::math::unit_conversion::quantity::{Quantity, Unit, Dimension, units};
+    ///
+    /// const FURLONG: Unit = Unit::new("fur", Dimension::LENGTH, 201.168);
+    /// assert_eq!(8.0, Quantity::new(1.0, &units::MILE).to(&FURLONG).unwrap().round());
+    /// ```
+    #[must_use]
+    pub const fn new(symbol: &'static str, dimension: Dimension, factor: f64) -> Unit {
+        Unit { symbol,
+               dimension,
+               factor,
+               offset: 0.0 }
+    }
+    /// Sets the offset of `self`.
+    /// # Arguments
+    /// * `offset: f64` - The offset to the coherent SI unit.
+    /// # Returns
+    /// A `Unit`.
+    #[must_use]
+    pub const fn with_offset(mut self, offset: f64) -> Unit {
+        self.offset = offset;
+        self
+    }
+}
+
+impl Unit {
+    fn accepts_prefix(&self) -> bool {
+        self.offset == 0.0 && match self.symbol {
+            "g" | "L" => true,
+            "kg"      => false,
+            _         => self.factor == 1.0,
+        }
+    }
+
+    /// Gets the power a prefix is raised to, e.g. `2` for `m²`, as `1 km² = (1000 m)²`.
+    fn prefix_power(&self) -> i32 {
+        match self.symbol.chars().last() {
+            Some('²') => { 2 },
+            Some('³') => { 3 },
+            _         => { 1 },
+        }
+    }
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol)
+    }
+}
+
+impl Quantity {
+    /// Creates a new `Quantity` from a value in `unit`.
+    /// # Arguments
+    /// * `value: f64` - The value.
+    /// * `unit: &Unit` - The unit of `value`.
+    /// # Returns
+    /// A new `Quantity`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::math::unit_conversion::quantity::{Quantity, units};
+    ///
+    /// assert_eq!(0.0254, Quantity::new(1.0, &units::INCH).value());
+    /// assert_eq!(273.15, Quantity::new(0.0, &units::CELSIUS).value());
+    /// ```
+    #[must_use]
+    pub fn new(value: f64, unit: &Unit) -> Quantity {
+        Quantity { value:     value * unit.factor + unit.offset,
+                   dimension: unit.dimension,
+                   scale:     match unit.offset == 0.0 {
+                       true  => Scale::Plain,
+                       false => Scale::Absolute,
+                   } }
+    }
+    /// Creates a new difference, e.g. a change in temperature, from a value in `unit`. The offset of `unit` is ignored.
+    /// # Arguments
+    /// * `value: f64` - The value.
+    /// * `unit: &Unit` - The unit of `value`.
+    /// # Returns
+    /// A new `Quantity`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::math::unit_conversion::quantity::{Quantity, units};
+    ///
+    /// let warming = Quantity::difference(10.0, &units::CELSIUS);
+    /// assert_eq!(10.0, warming.value());
+    /// assert_eq!(18.0, warming.to(&units::FAHRENHEIT).unwrap());
+    ///
+    /// let t = Quantity::new(15.0, &units::CELSIUS) + warming;
+    /// assert_eq!(25.0, t.to(&units::CELSIUS).unwrap());
+    /// ```
+    #[must_use]
+    pub fn difference(value: f64, unit: &Unit) -> Quantity {
+        Quantity { value:     value * unit.factor,
+                   dimension: unit.dimension,
+                   scale:     Scale::Difference }
+    }
+    /// Creates a new `Quantity` from a value in coherent SI units.
+    /// # Arguments
+    /// * `value: f64` - The value.
+    /// * `dimension: Dimension` - The dimension of `value`.
+    /// # Returns
+    /// A new `Quantity`.
+    #[must_use]
+    pub const fn from_si(value: f64, dimension: Dimension) -> Quantity {
+        Quantity { value,
+                   dimension,
+                   scale: Scale::Plain }
+    }
+    /// Gets the value of `self` in coherent SI units.
+    /// # Returns
+    /// A `f64`.
+    #[must_use]
+    pub const fn value(&self) -> f64 {
+        self.value
+    }
+    /// Gets the dimension of `self`.
+    /// # Returns
+    /// A `Dimension`.
+    #[must_use]
+    pub const fn dimension(&self) -> Dimension {
+        self.dimension
+    }
+    /// Converts `self` into a value in `unit`.
+    /// # Arguments
+    /// * `unit: &Unit` - The target unit.
+    /// # Returns
+    /// A `Result<f64, DimensionError>`. The value if `unit` has the same dimension as `self`, otherwise a `DimensionError`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::math::unit_conversion::quantity::{Quantity, units};
+    ///
+    /// let t = Quantity::new(100.0, &units::CELSIUS);
+    /// assert!((t.to(&units::FAHRENHEIT).unwrap() - 212.0).abs() < 1e-9);
+    /// assert!(t.to(&units::METRE).is_err());
+    /// ```
+    pub fn to(&self, unit: &Unit) -> Result<f64, DimensionError> {
+        self.check(unit.dimension)?;
+        match self.scale {
+            Scale::Difference => Ok(self.value / unit.factor),
+            _                 => Ok((self.value - unit.offset) / unit.factor),
+        }
+    }
+    /// Adds `other` to `self`.
+    /// # Arguments
+    /// * `other: Quantity` - The summand.
+    /// # Returns
+    /// A `Result<Quantity, QuantityError>`. The sum, or a `QuantityError` if the dimensions differ or both are absolute temperatures.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::math::unit_conversion::quantity::{Quantity, QuantityError, units};
+    ///
+    /// let a = Quantity::new(1.0, &units::METRE);
+    /// assert_eq!(1.5, a.try_add(Quantity::new(50.0, &units::CENTIMETRE)).unwrap().value());
+    /// assert!(a.try_add(Quantity::new(1.0, &units::SECOND)).is_err());
+    ///
+    /// let t = Quantity::new(10.0, &units::CELSIUS);
+    /// assert_eq!(Err(QuantityError::AbsoluteSum), t.try_add(t));
+    /// assert_eq!(20.0, t.try_add(Quantity::difference(10.0, &units::CELSIUS)).unwrap().to(&units::CELSIUS).unwrap());
+    /// ```
+    pub fn try_add(&self, other: Quantity) -> Result<Quantity, QuantityError> {
+        self.check(other.dimension)?;
+        let scale = match (self.scale, other.scale) {
+            (Scale::Absolute, Scale::Absolute)          => { return Err(QuantityError::AbsoluteSum); },
+            (Scale::Absolute, _) | (_, Scale::Absolute) => { Scale::Absolute },
+            (Scale::Difference, Scale::Difference)      => { Scale::Difference },
+            _                                           => { Scale::Plain },
+        };
+        Ok(Quantity { value:     self.value + other.value,
+                      dimension: self.dimension,
+                      scale })
+    }
+    /// Subtracts `other` from `self`.
+    /// # Arguments
+    /// * `other: Quantity` - The subtrahend.
+    /// # Returns
+    /// A `Result<Quantity, QuantityError>`. The difference, or a `QuantityError` if the dimensions differ or an absolute
+    /// temperature is subtracted from a temperature difference.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::math::unit_conversion::quantity::{Quantity, QuantityError, units};
+    ///
+    /// let delta = Quantity::new(25.0, &units::CELSIUS).try_sub(Quantity::new(10.0, &units::CELSIUS)).unwrap();
+    /// assert!((delta.to(&units::CELSIUS).unwrap() - 15.0).abs() < 1e-9);
+    /// assert!((delta.to(&units::FAHRENHEIT).unwrap() - 27.0).abs() < 1e-9);
+    /// assert_eq!(Err(QuantityError::AbsoluteFromDifference), delta.try_sub(Quantity::new(1.0, &units::CELSIUS)));
+    /// ```
+    pub fn try_sub(&self, other: Quantity) -> Result<Quantity, QuantityError> {
+        self.check(other.dimension)?;
+        let scale = match (self.scale, other.scale) {
+            (Scale::Difference, Scale::Absolute)   => { return Err(QuantityError::AbsoluteFromDifference); },
+            (_, Scale::Absolute)                   => { Scale::Difference },
+            (Scale::Absolute, _)                   => { Scale::Absolute },
+            (Scale::Difference, Scale::Difference) => { Scale::Difference },
+            _                                      => { Scale::Plain },
+        };
+        Ok(Quantity { value:     self.value - other.value,
+                      dimension: self.dimension,
+                      scale })
+    }
+    /// Raises `self` to an integer power.
+    /// # Arguments
+    /// * `n: i8` - The power.
+    /// # Returns
+    /// A new `Quantity`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::math::unit_conversion::quantity::{Quantity, units};
+    ///
+    /// let side = Quantity::new(10.0, &units::CENTIMETRE);
+    /// assert!((side.powi(3).to(&units::LITRE).unwrap() - 1.0).abs() < 1e-12);
+    /// ```
+    #[must_use = "This returns the result of the operation, without modifying the original."]
+    pub fn powi(&self, n: i8) -> Quantity {
+        Quantity::from_si(self.value.powi(n as i32), self.dimension.powi(n))
+    }
+
+    /// Formats `self` in `unit`.
+    /// \
+    /// For units which accept SI prefixes (coherent SI units, grams and litres), the prefix which keeps the value in
+    /// `[1; 1000)` is chosen automatically. For squared and cubed units the prefix is squared or cubed as well.
+    /// Other units are written as they are.
+    /// # Arguments
+    /// * `unit: &Unit` - The unit.
+    /// * `precision: usize` - The maximum number of decimal places. Trailing zeros are removed.
+    /// # Returns
+    /// A `Result<String, DimensionError>`. The formatted quantity if `unit` has the same dimension as `self`, otherwise a `DimensionError`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::math::unit_conversion::quantity::{Quantity, units};
+    ///
+    /// assert_eq!("4.7 kΩ",  Quantity::new(4700.0, &units::OHM).format(&units::OHM, 2).unwrap());
+    /// assert_eq!("12 µs",   Quantity::new(0.012, &units::MILLISECOND).format(&units::SECOND, 2).unwrap());
+    /// assert_eq!("250 mg",  Quantity::new(0.25, &units::GRAM).format(&units::KILOGRAM, 2).unwrap());
+    /// assert_eq!("77 °F",   Quantity::new(25.0, &units::CELSIUS).format(&units::FAHRENHEIT, 1).unwrap());
+    /// assert_eq!("1 km²",   Quantity::new(1e6, &units::SQUARE_METRE).format(&units::SQUARE_METRE, 2).unwrap());
+    /// assert_eq!("2.5 mm³", Quantity::new(2.5e-9, &units::CUBIC_METRE).format(&units::CUBIC_METRE, 2).unwrap());
+    /// ```
+    pub fn format(&self, unit: &Unit, precision: usize) -> Result<String, DimensionError> {
+        // Prefixes go on the gram, not on the kilogram.
+        let unit = match unit.symbol {
+            "kg" => &units::GRAM,
+            _    => unit,
+        };
+        let value = self.to(unit)?;
+        match unit.accepts_prefix() {
+            true  => Ok(format_si_powi(value, unit.symbol, unit.prefix_power(), precision)),
+            false => Ok(join_unit(&trim_number(value, precision), "", unit.symbol)),
+        }
+    }
+
+    fn check(&self, dimension: Dimension) -> Result<(), DimensionError> {
+        match self.dimension == dimension {
+            true  => Ok(()),
+            false => Err(DimensionError { expected: dimension,
+                                          found:    self.dimension }),
+        }
+    }
+}
+
+/// Parses a number followed by a unit symbol, which may carry an SI prefix, e.g. `4.7 kΩ`, `12 µs` or `25 °C`.
+/// A number without a unit is dimensionless.
+/// # Examples
+/// ```
+/// use lib_rapid::math::unit_conversion::{UnitParseError, quantity::{Quantity, Dimension, units}};
+///
+/// let r: Quantity = "4.7 kΩ".parse().unwrap();
+/// assert_eq!(4700.0, r.to(&units::OHM).unwrap());
+///
+/// let t: Quantity = "25 °C".parse().unwrap();
+/// assert_eq!(298.15, t.to(&units::KELVIN).unwrap());
+///
+/// assert_eq!(Dimension::TIME, "12 µs".parse::<Quantity>().unwrap().dimension());
+/// assert_eq!(0.5, "500 mL".parse::<Quantity>().unwrap().to(&units::LITRE).unwrap());
+///
+/// // Prefixes of squared and cubed units are squared and cubed, too.
+/// assert_eq!(3e6, "3 km²".parse::<Quantity>().unwrap().to(&units::SQUARE_METRE).unwrap());
+/// assert!(("2 cm³".parse::<Quantity>().unwrap().to(&units::CUBIC_METRE).unwrap() - 2e-6).abs() < 1e-18);
+/// assert!(("7 mm³".parse::<Quantity>().unwrap().to(&units::CUBIC_METRE).unwrap() - 7e-9).abs() < 1e-21);
+/// assert_eq!(Err(UnitParseError::UnknownUnit("parsec".to_owned())), "3 parsec".parse::<Quantity>());
+/// ```
+impl FromStr for Quantity {
+    type Err = UnitParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, rest) = split_number(s)?;
+        if rest.is_empty()
+        { return Ok(Quantity::from_si(x, Dimension::DIMENSIONLESS)); }
+        if let Some(u) = units::from_symbol(rest)
+        { return Ok(Quantity::new(x, &u)); }
+
+        // Try every split into a prefix and a unit; `da` is the only prefix with two characters.
+        for (i, _) in rest.char_indices().skip(1).take(2) {
+            if let (Some(p), Some(u)) = (si_prefix_index(&rest[..i]), units::from_symbol(&rest[i..])) {
+                if u.accepts_prefix() && p != 10
+                { return Ok(Quantity::new(x * si_rate(p).powi(u.prefix_power()), &u)); }
+            }
+        }
+        Err(UnitParseError::UnknownUnit(rest.to_owned()))
+    }
+}
+
+impl Add for Quantity {
+    type Output = Quantity;
+
+    fn add(self, rhs: Quantity) -> Quantity {
+        self.try_add(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl Sub for Quantity {
+    type Output = Quantity;
+
+    fn sub(self, rhs: Quantity) -> Quantity {
+        self.try_sub(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl Mul for Quantity {
+    type Output = Quantity;
+
+    fn mul(self, rhs: Quantity) -> Quantity {
+        Quantity::from_si(self.value * rhs.value, self.dimension * rhs.dimension)
+    }
+}
+
+impl Div for Quantity {
+    type Output = Quantity;
+
+    fn div(self, rhs: Quantity) -> Quantity {
+        Quantity::from_si(self.value / rhs.value, self.dimension / rhs.dimension)
+    }
+}
+
+impl Mul<f64> for Quantity {
+    type Output = Quantity;
+
+    fn mul(self, rhs: f64) -> Quantity {
+        match self.scale {
+            Scale::Difference => Quantity { value: self.value * rhs, ..self },
+            _                 => Quantity::from_si(self.value * rhs, self.dimension),
+        }
+    }
+}
+
+impl Div<f64> for Quantity {
+    type Output = Quantity;
+
+    fn div(self, rhs: f64) -> Quantity {
+        match self.scale {
+            Scale::Difference => Quantity { value: self.value / rhs, ..self },
+            _                 => Quantity::from_si(self.value / rhs, self.dimension),
+        }
+    }
+}
+
+impl Neg for Quantity {
+    type Output = Quantity;
+
+    fn neg(self) -> Quantity {
+        match self.scale {
+            Scale::Difference => Quantity { value: -self.value, ..self },
+            _                 => Quantity::from_si(-self.value, self.dimension),
+        }
+    }
+}
+
+impl AddAssign for Quantity {
+    fn add_assign(&mut self, rhs: Quantity) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Quantity {
+    fn sub_assign(&mut self, rhs: Quantity) {
+        *self = *self - rhs;
+    }
+}
+
+impl PartialEq for Quantity {
+    /// Quantities are equal if their values in coherent SI units and their dimensions are.
+    fn eq(&self, other: &Quantity) -> bool {
+        self.value == other.value && self.dimension == other.dimension
+    }
+}
+
+impl PartialOrd for Quantity {
+    /// Quantities of different dimensions are not comparable.
+    fn partial_cmp(&self, other: &Quantity) -> Option<Ordering> {
+        match self.dimension == other.dimension {
+            true  => self.value.partial_cmp(&other.value),
+            false => None,
+        }
+    }
+}
+
+impl Display for Quantity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.dimension.is_dimensionless() {
+            true  => write!(f, "{}", self.value),
+            false => write!(f, "{} {}", self.value, self.dimension),
+        }
+    }
+}
+
+impl Display for DimensionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Mismatched dimensions: expected {}, found {}.", self.expected, self.found)
+    }
+}
+
+impl std::error::Error for DimensionError { }
+
+impl Display for QuantityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuantityError::Dimension(e)           => write!(f, "{}", e),
+            QuantityError::AbsoluteSum            => write!(f, "Cannot add two absolute temperatures."),
+            QuantityError::AbsoluteFromDifference => write!(f, "Cannot subtract an absolute temperature from a difference."),
+        }
+    }
+}
+
+impl std::error::Error for QuantityError { }
+
+impl From<DimensionError> for QuantityError {
+    fn from(e: DimensionError) -> Self {
+        QuantityError::Dimension(e)
+    }
+}
+
+/// Common units. Derived dimensions are built from the base dimensions.
+pub mod units {
+    use super::{Dimension, Unit, EARTHGRAVITY, PI};
+
+    const AREA:         Dimension = Dimension::LENGTH.powi(2);
+    const VOLUME:       Dimension = Dimension::LENGTH.powi(3);
+    const VELOCITY:     Dimension = Dimension::new([1, 0, -1, 0, 0, 0, 0]);
+    const FREQUENCY:    Dimension = Dimension::new([0, 0, -1, 0, 0, 0, 0]);
+    const FORCE:        Dimension = Dimension::new([1, 1, -2, 0, 0, 0, 0]);
+    const PRESSURE:     Dimension = Dimension::new([-1, 1, -2, 0, 0, 0, 0]);
+    const ENERGY:       Dimension = Dimension::new([2, 1, -2, 0, 0, 0, 0]);
+    const POWER:        Dimension = Dimension::new([2, 1, -3, 0, 0, 0, 0]);
+    const CHARGE:       Dimension = Dimension::new([0, 0, 1, 1, 0, 0, 0]);
+    const VOLTAGE:      Dimension = Dimension::new([2, 1, -3, -1, 0, 0, 0]);
+    const RESISTANCE:   Dimension = Dimension::new([2, 1, -3, -2, 0, 0, 0]);
+
+    /// The SI unit of length.
+    pub const METRE:              Unit = Unit::new("m",    Dimension::LENGTH, 1.0);
+    /// 1000 metres.
+    pub const KILOMETRE:          Unit = Unit::new("km",   Dimension::LENGTH, 1e3);
+    /// 0.01 metres.
+    pub const CENTIMETRE:         Unit = Unit::new("cm",   Dimension::LENGTH, 1e-2);
+    /// 0.001 metres.
+    pub const MILLIMETRE:         Unit = Unit::new("mm",   Dimension::LENGTH, 1e-3);
+    /// The international inch, 25.4 mm.
+    pub const INCH:               Unit = Unit::new("in",   Dimension::LENGTH, 0.0254);
+    /// The international foot, 12 inches.
+    pub const FOOT:               Unit = Unit::new("ft",   Dimension::LENGTH, 0.3048);
+    /// The international yard, 3 feet.
+    pub const YARD:               Unit = Unit::new("yd",   Dimension::LENGTH, 0.9144);
+    /// The international mile, 1760 yards.
+    pub const MILE:               Unit = Unit::new("mi",   Dimension::LENGTH, 1609.344);
+    /// The nautical mile, 1852 metres.
+    pub const NAUTICAL_MILE:      Unit = Unit::new("nmi",  Dimension::LENGTH, 1852.0);
+
+    /// The SI unit of mass.
+    pub const KILOGRAM:           Unit = Unit::new("kg",   Dimension::MASS, 1.0);
+    /// 0.001 kilograms.
+    pub const GRAM:               Unit = Unit::new("g",    Dimension::MASS, 1e-3);
+    /// 1000 kilograms.
+    pub const TONNE:              Unit = Unit::new("t",    Dimension::MASS, 1e3);
+    /// The international avoirdupois pound.
+    pub const POUND:              Unit = Unit::new("lb",   Dimension::MASS, 0.453_592_37);
+    /// The international avoirdupois ounce, 1/16 pound.
+    pub const OUNCE:              Unit = Unit::new("oz",   Dimension::MASS, 0.028_349_523_125);
+
+    /// The SI unit of time.
+    pub const SECOND:             Unit = Unit::new("s",    Dimension::TIME, 1.0);
+    /// 0.001 seconds.
+    pub const MILLISECOND:        Unit = Unit::new("ms",   Dimension::TIME, 1e-3);
+    /// 0.000001 seconds.
+    pub const MICROSECOND:        Unit = Unit::new("µs",   Dimension::TIME, 1e-6);
+    /// 60 seconds.
+    pub const MINUTE:             Unit = Unit::new("min",  Dimension::TIME, 60.0);
+    /// 60 minutes.
+    pub const HOUR:               Unit = Unit::new("h",    Dimension::TIME, 3600.0);
+    /// 24 hours.
+    pub const DAY:                Unit = Unit::new("d",    Dimension::TIME, 86400.0);
+
+    /// The SI unit of electric current.
+    pub const AMPERE:             Unit = Unit::new("A",    Dimension::CURRENT, 1.0);
+    /// The SI unit of temperature.
+    pub const KELVIN:             Unit = Unit::new("K",    Dimension::TEMPERATURE, 1.0);
+    /// Degrees Celsius.
+    pub const CELSIUS:            Unit = Unit::new("°C",   Dimension::TEMPERATURE, 1.0).with_offset(273.15);
+    /// Degrees Fahrenheit.
+    pub const FAHRENHEIT:         Unit = Unit::new("°F",   Dimension::TEMPERATURE, 5.0 / 9.0)
+                                              .with_offset(273.15 - 32.0 * 5.0 / 9.0);
+    /// The SI unit of amount of substance.
+    pub const MOLE:               Unit = Unit::new("mol",  Dimension::AMOUNT, 1.0);
+    /// The SI unit of luminous intensity.
+    pub const CANDELA:            Unit = Unit::new("cd",   Dimension::LUMINOSITY, 1.0);
+
+    /// The radian. Angles are dimensionless.
+    pub const RADIAN:             Unit = Unit::new("rad",  Dimension::DIMENSIONLESS, 1.0);
+    /// The degree, π/180 radians.
+    pub const DEGREE:             Unit = Unit::new("°",    Dimension::DIMENSIONLESS, PI / 180.0);
+
+    /// The SI unit of area.
+    pub const SQUARE_METRE:       Unit = Unit::new("m²",   AREA, 1.0);
+    /// 10000 square metres.
+    pub const HECTARE:            Unit = Unit::new("ha",   AREA, 1e4);
+    /// The SI unit of volume.
+    pub const CUBIC_METRE:        Unit = Unit::new("m³",   VOLUME, 1.0);
+    /// 0.001 cubic metres.
+    pub const LITRE:              Unit = Unit::new("L",    VOLUME, 1e-3);
+    /// The SI unit of velocity.
+    pub const METRE_PER_SECOND:   Unit = Unit::new("m/s",  VELOCITY, 1.0);
+    /// Kilometres per hour.
+    pub const KILOMETRE_PER_HOUR: Unit = Unit::new("km/h", VELOCITY, 1e3 / 3600.0);
+    /// Miles per hour.
+    pub const MILE_PER_HOUR:      Unit = Unit::new("mph",  VELOCITY, 1609.344 / 3600.0);
+    /// The SI unit of frequency.
+    pub const HERTZ:              Unit = Unit::new("Hz",   FREQUENCY, 1.0);
+    /// The SI unit of force.
+    pub const NEWTON:             Unit = Unit::new("N",    FORCE, 1.0);
+    /// The kilogram-force, the weight of one kilogram in standard gravity.
+    pub const KILOGRAM_FORCE:     Unit = Unit::new("kgf",  FORCE, EARTHGRAVITY);
+    /// The SI unit of pressure.
+    pub const PASCAL:             Unit = Unit::new("Pa",   PRESSURE, 1.0);
+    /// 100000 pascals.
+    pub const BAR:                Unit = Unit::new("bar",  PRESSURE, 1e5);
+    /// The SI unit of energy.
+    pub const JOULE:              Unit = Unit::new("J",    ENERGY, 1.0);
+    /// 3600000 joules.
+    pub const KILOWATT_HOUR:      Unit = Unit::new("kWh",  ENERGY, 3.6e6);
+    /// The thermochemical calorie.
+    pub const CALORIE:            Unit = Unit::new("cal",  ENERGY, 4.184);
+    /// The SI unit of power.
+    pub const WATT:               Unit = Unit::new("W",    POWER, 1.0);
+    /// The SI unit of electric charge.
+    pub const COULOMB:            Unit = Unit::new("C",    CHARGE, 1.0);
+    /// The SI unit of voltage.
+    pub const VOLT:               Unit = Unit::new("V",    VOLTAGE, 1.0);
+    /// The SI unit of electrical resistance.
+    pub const OHM:                Unit = Unit::new("Ω",    RESISTANCE, 1.0);
+
+    /// All units above.
+    pub const ALL: [Unit; 47] = [METRE, KILOMETRE, CENTIMETRE, MILLIMETRE, INCH, FOOT, YARD, MILE, NAUTICAL_MILE,
+                                 KILOGRAM, GRAM, TONNE, POUND, OUNCE,
+                                 SECOND, MILLISECOND, MICROSECOND, MINUTE, HOUR, DAY,
+                                 AMPERE, KELVIN, CELSIUS, FAHRENHEIT, MOLE, CANDELA, RADIAN, DEGREE,
+                                 SQUARE_METRE, HECTARE, CUBIC_METRE, LITRE,
+                                 METRE_PER_SECOND, KILOMETRE_PER_HOUR, MILE_PER_HOUR,
+                                 HERTZ, NEWTON, KILOGRAM_FORCE, PASCAL, BAR,
+                                 JOULE, KILOWATT_HOUR, CALORIE, WATT, COULOMB, VOLT, OHM];
+
+    /// Finds a unit in `ALL` by its symbol. `μ` (Greek mu) and `Ω` (ohm sign) are accepted as well.
+    /// # Arguments
+    /// * `symbol: &str` - The symbol, e.g. `km/h`.
+    /// # Returns
+    /// An `Option<Unit>`.
+    /// # Examples
+    /// ```
+    /// use lib_rapid::math::unit_conversion::quantity::units;
+    /// assert_eq!(Some(units::KILOMETRE_PER_HOUR), units::from_symbol("km/h"));
+    /// assert_eq!(None, units::from_symbol("furlong"));
+    /// ```
+    #[must_use]
+    pub fn from_symbol(symbol: &str) -> Option<Unit> {
+        let symbol = symbol.replace('μ', "µ").replace('Ω', "Ω");
+        ALL.iter().find(|u| u.symbol == symbol).copied()
+    }
+}